@@ -0,0 +1,84 @@
+//! Dual_EC_DRBG 백도어
+//!
+//! 생성기의 Q = eP를 고른 사람은 e를 알고, e^(-1)은 점 sQ를 sP로 바꾼다. 출력
+//! 블록 하나는 r = x(sQ)에서 위쪽 16비트를 뺀 것이므로, 공격자는 2^16개의
+//! 접두사를 모두 시도한다: 곡선 위에 있는 x마다 R = +-sQ를 얻고
+//!
+//! ```text
+//! x(e^(-1) R) = x(+-sP) = 다음 상태
+//! ```
+//!
+//! 2^16개의 추측 중 약 절반이 곡선 위에 있다. 맞는 것은 다음 블록이 뒤따르는
+//! 출력 바이트와 일치하는 것이고, 그때부터 공격자는 똑같은 생성기 사본을
+//! 돌린다.
+//!
+//! (Shumow와 Ferguson, 2007은 P = dQ로 썼다. 여기서 d = e^(-1)이다.)
+
+use crate::core::curve::Curve;
+use crate::core::ladder::{is_on_twist, ladder};
+use crate::core::point::CurvePoint;
+use crate::protocols::dual_ec_drbg::DualEcDrbg;
+
+use ark_ff::{Field as ArkField, PrimeField as ArkPrimeField, Zero};
+use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::BigUint;
+
+/// Q = eP인 Q와 비밀 e
+pub struct Backdoor<C: Curve> {
+    pub q: CurvePoint<C>,
+    pub trapdoor: C::ScalarField,
+}
+
+/// 다른 Q만큼 무작위로 보이는 Q와 그 trapdoor
+pub fn backdoored_q<C: Curve>(p: &CurvePoint<C>) -> Backdoor<C> {
+    let mut rng = thread_rng();
+    let trapdoor = loop {
+        let e = C::ScalarField::rand(&mut rng);
+        if !e.is_zero() {
+            break e;
+        }
+    };
+    Backdoor { q: p.mul_scalar(&trapdoor), trapdoor }
+}
+
+/// 출력 블록 하나 전체인 `block` 바로 뒤의 상태를 복원하고, 같은 `generate`
+/// 호출이 그 뒤에 낸 바이트 `next`와 맞춰 확인한다. 피해자의 생성기와 똑같이
+/// 이어지는 생성기를 돌려준다.
+pub fn recover_state<C: Curve>(
+    p: &CurvePoint<C>,
+    backdoor: &Backdoor<C>,
+    truncated_bits: u32,
+    block: &[u8],
+    next: &[u8],
+) -> Option<DualEcDrbg<C>> {
+    let block_len = DualEcDrbg::<C>::block_len_for(truncated_bits);
+    if block.len() != block_len {
+        return None;
+    }
+    let modulus: BigUint = C::BaseField::MODULUS.into();
+    let e_inv: BigUint = backdoor.trapdoor.inverse()?.into_bigint().into();
+    let low = BigUint::from_bytes_be(block);
+    let shift = 8 * block_len as u64;
+
+    for prefix in 0u32..(1 << truncated_bits) {
+        let x = (BigUint::from(prefix) << shift) | &low;
+        if x >= modulus {
+            break;
+        }
+        let x = C::BaseField::from(x);
+        if is_on_twist::<C>(&x) {
+            continue;
+        }
+        let Some(state) = ladder::<C>(&x, &e_inv) else {
+            continue;
+        };
+        let state: BigUint = state.into_bigint().into();
+        let candidate = || {
+            DualEcDrbg::from_state(p.clone(), backdoor.q.clone(), state.clone(), truncated_bits)
+        };
+        if candidate().generate(next.len()) == next {
+            return Some(candidate());
+        }
+    }
+    None
+}
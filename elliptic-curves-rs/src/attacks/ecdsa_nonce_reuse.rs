@@ -0,0 +1,174 @@
+//! 재사용된 nonce로부터 ECDSA 개인키 복원
+//!
+//! r = x(kG) mod n은 nonce에만 달려 있으므로, 같은 키로 만든 두 서명의 r이
+//! 같으면 k도 거의 확실히 같다.
+//!
+//! ```text
+//! s_1 k = z_1 + r d
+//! s_2 k = z_2 + r d
+//! ```
+//!
+//! 를 빼면 k = (z_1 - z_2) / (s_1 - s_2)이고, d = (s_1 k - z_1) / r이다.
+//!
+//! 아래의 스캐너는 그 결과를 키를 넘나들며 따라간다: 알아낸 nonce는 누가
+//! 만들었든 그 r을 가진 다른 서명을 모두 열고, 알아낸 키는 그 키의 모든 서명의
+//! nonce를 연다. -k도 같은 r을 주므로, s가 부호 반전된 서명 (low-S 정규화)은
+//! 두 부호를 모두 공개키에 맞춰 본다.
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::protocols::ecdsa::Signature;
+
+use ark_ff::{Field, Zero};
+use thiserror::Error;
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NonceReuseError {
+    #[error("the signatures have different r, so different nonces")]
+    DifferentR,
+    #[error("r is zero")]
+    ZeroR,
+    #[error("s_1 = s_2: the same message was signed twice")]
+    SameS,
+}
+
+/// d와 공유된 nonce k
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyAndNonce<F> {
+    pub private_key: F,
+    pub nonce: F,
+}
+
+/// 같은 nonce로 메시지 해시 z_1, z_2에 만든 두 서명 (r, s_1), (r, s_2)에서
+/// k와 d를 복원한다.
+pub fn recover_private_key<F: Field>(
+    sig1: &(F, F),
+    z1: F,
+    sig2: &(F, F),
+    z2: F,
+) -> Result<KeyAndNonce<F>, NonceReuseError> {
+    let ((r, s1), (r2, s2)) = (*sig1, *sig2);
+    if r != r2 {
+        return Err(NonceReuseError::DifferentR);
+    }
+    let r_inv = r.inverse().ok_or(NonceReuseError::ZeroR)?;
+    let nonce = (z1 - z2) * (s1 - s2).inverse().ok_or(NonceReuseError::SameS)?;
+    let private_key = (s1 * nonce - z1) * r_inv;
+    Ok(KeyAndNonce { private_key, nonce })
+}
+
+/// 관찰한 서명 하나, 예를 들어 블록체인이나 TLS 캡처에서
+pub struct SignatureRecord<C: Curve> {
+    pub public_key: CurvePoint<C>,
+    pub z: C::ScalarField,
+    pub signature: Signature<C>,
+}
+
+/// 스캐너가 복원한 키와 그 키를 드러낸 반복된 r
+pub struct CompromisedKey<C: Curve> {
+    pub public_key: CurvePoint<C>,
+    pub private_key: C::ScalarField,
+    pub r: C::ScalarField,
+    /// 이 키와 그 r로 서명된 레코드의 인덱스
+    pub records: Vec<usize>,
+}
+
+/// k 또는 -k로 s k = z + r d를 만족시키는 `public_key`의 d
+fn key_from_nonce<C: Curve>(
+    record: &SignatureRecord<C>,
+    nonce: C::ScalarField,
+) -> Option<C::ScalarField> {
+    let (r, s) = record.signature.to_scalars();
+    let r_inv = r.inverse()?;
+    [nonce, -nonce]
+        .into_iter()
+        .map(|k| (s * k - record.z) * r_inv)
+        .find(|d| C::generator().mul_scalar(d) == record.public_key)
+}
+
+/// 같은 키로 만든 두 서명의 nonce, s_2는 두 부호 모두 시도한다
+fn nonce_from_pair<C: Curve>(
+    first: &SignatureRecord<C>,
+    second: &SignatureRecord<C>,
+) -> Option<C::ScalarField> {
+    let (r, s1) = first.signature.to_scalars();
+    let s2 = *second.signature.s();
+    [s2, -s2].into_iter().find_map(|s2| {
+        let recovered = recover_private_key(&(r, s1), first.z, &(r, s2), second.z).ok()?;
+        key_from_nonce(first, recovered.nonce).map(|_| recovered.nonce)
+    })
+}
+
+/// `records`에서 반복된 r을 찾아, 직접 또는 앞서 복원한 nonce나 키를 통해
+/// 드러나는 키를 모두 돌려준다.
+pub fn scan<C: Curve>(records: &[SignatureRecord<C>]) -> Vec<CompromisedKey<C>> {
+    let mut groups: BTreeMap<C::ScalarField, Vec<usize>> = BTreeMap::new();
+    for (index, record) in records.iter().enumerate() {
+        groups.entry(*record.signature.r()).or_default().push(index);
+    }
+    groups.retain(|r, indices| !r.is_zero() && indices.len() > 1);
+
+    let mut nonces: BTreeMap<C::ScalarField, C::ScalarField> = BTreeMap::new();
+    let mut keys: Vec<CompromisedKey<C>> = Vec::new();
+    let known = |keys: &[CompromisedKey<C>], public_key: &CurvePoint<C>| {
+        keys.iter().position(|key| key.public_key == *public_key)
+    };
+
+    // every pass either learns something new or stops
+    let mut progress = true;
+    while progress {
+        progress = false;
+        for (r, indices) in &groups {
+            if !nonces.contains_key(r) {
+                let from_pair = indices.iter().enumerate().find_map(|(i, &first)| {
+                    indices[i + 1..]
+                        .iter()
+                        .filter(|&&second| records[first].public_key == records[second].public_key)
+                        .find_map(|&second| nonce_from_pair(&records[first], &records[second]))
+                });
+                // s k = z + r d for a signer whose d is already known
+                let from_key = || {
+                    indices.iter().find_map(|&index| {
+                        let record = &records[index];
+                        let key = &keys[known(&keys, &record.public_key)?];
+                        let s_inv = record.signature.s().inverse()?;
+                        Some((record.z + *r * key.private_key) * s_inv)
+                    })
+                };
+                if let Some(nonce) = from_pair.or_else(from_key) {
+                    nonces.insert(*r, nonce);
+                    progress = true;
+                }
+            }
+
+            let Some(&nonce) = nonces.get(r) else {
+                continue;
+            };
+            for &index in indices {
+                let record = &records[index];
+                match known(&keys, &record.public_key) {
+                    Some(position) => {
+                        let key = &mut keys[position];
+                        if key.r == *r && !key.records.contains(&index) {
+                            key.records.push(index);
+                        }
+                    }
+                    None => {
+                        if let Some(private_key) = key_from_nonce(record, nonce) {
+                            keys.push(CompromisedKey {
+                                public_key: record.public_key.clone(),
+                                private_key,
+                                r: *r,
+                                records: vec![index],
+                            });
+                            progress = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    keys
+}
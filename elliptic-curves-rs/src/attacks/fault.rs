@@ -0,0 +1,376 @@
+//! 점 덧셈과 두 배에 대한 fault 주입
+//!
+//! `Faulty<C>`는 `add_point`와 `double_point`에 훅을 단 곡선 `C`다.
+//! `FaultGuard`가 살아 있는 동안 그것이 설정한 fault가 현재 스레드의 덧셈과
+//! 두 배 연산에 들어간다. 프로토콜은 곡선에 대해 제네릭이므로
+//! `Ecdsa<Faulty<C>>`나 `Ecdh<Faulty<C>>`는 라이브러리 코드를 그대로, 다만
+//! 결함 있는 하드웨어 위에서 실행한다.
+//!
+//! Biehl, Meyer, Müller의 differential fault 공격은 공식이 b를 전혀 보지
+//! 않는다는 점을 이용한다. 기준점 P를 담은 레지스터의 비트 하나가 뒤집히면
+//! P는 y^2 = x^3 + ax + b' 위의 어떤 P'이 된다. 이때
+//!
+//! ```text
+//! b' = y'^2 - x'^3 - a x'
+//! ```
+//!
+//! 이고 곱셈 전체가 그 곡선 위에서 돌아간다. 출력 dP'도 같은 곡선 위에 있으므로
+//! 공격자는 b'을 알고, P의 한 비트 뒤집기를 모두 시도해 P' 자체도 안다.
+//! invalid-curve 공격처럼 P' 위수의 작은 소수 거듭제곱들을 법으로 d를 얻는다.
+//!
+//! `CurvePoint::mul_scalar_checked` (feature `fault-countermeasures`)는 여기서
+//! 흉내 내는 fault를 모두 잡아낸다.
+
+use crate::attacks::invalid_curve::{InvalidCurveError, KeyRecovery, j_zero_orders};
+use crate::core::curve::Curve;
+use crate::core::integer::{crt, factorize};
+use crate::core::point::{CurvePoint, Point};
+
+#[cfg(feature = "fault-countermeasures")]
+use crate::core::countermeasures::FaultError;
+
+use ark_ff::{PrimeField as ArkPrimeField, Zero};
+use num_bigint::BigUint;
+use num_traits::One;
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Double,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coordinate {
+    X,
+    Y,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// `call`번째 연산 (0부터 셈) 결과의 한 좌표에서 `bit`를
+    /// 뒤집는다
+    FlipResult { operation: Operation, call: usize, coordinate: Coordinate, bit: u64 },
+    /// `call`번째 연산을 실행하지 않고 첫 피연산자를 돌려준다
+    Skip { operation: Operation, call: usize },
+    /// 모든 덧셈의 두 번째 피연산자 레지스터에 고정된 비트. double-and-add에서
+    /// 이 레지스터는 기준점을 담는다
+    FlipOperand { coordinate: Coordinate, bit: u64 },
+}
+
+#[derive(Default)]
+struct Injector {
+    faults: Vec<Fault>,
+    adds: usize,
+    doubles: usize,
+    triggered: usize,
+}
+
+impl Injector {
+    /// `operation`의 다음 호출에 들어가는 fault
+    fn hit(&mut self, operation: Operation) -> Vec<Fault> {
+        let counter = match operation {
+            Operation::Add => &mut self.adds,
+            Operation::Double => &mut self.doubles,
+        };
+        let call = *counter;
+        *counter += 1;
+        let hits: Vec<Fault> = self
+            .faults
+            .iter()
+            .copied()
+            .filter(|fault| match *fault {
+                Fault::FlipResult { operation: target, call: n, .. }
+                | Fault::Skip { operation: target, call: n } => target == operation && n == call,
+                Fault::FlipOperand { .. } => operation == Operation::Add,
+            })
+            .collect();
+        self.triggered += hits.len();
+        hits
+    }
+}
+
+thread_local! {
+    static INJECTOR: RefCell<Option<Injector>> = const { RefCell::new(None) };
+}
+
+fn hit(operation: Operation) -> Vec<Fault> {
+    INJECTOR.with(|injector| {
+        injector.borrow_mut().as_mut().map_or_else(Vec::new, |injector| injector.hit(operation))
+    })
+}
+
+/// drop될 때까지 이 스레드의 `Faulty` 곡선에 fault를 설정한다. 호출은 guard를
+/// 만든 순간부터 센다. guard는 중첩된다: 안쪽 guard를 drop하면 바깥 guard가
+/// 설정한 fault가 다시 설정된다.
+pub struct FaultGuard {
+    previous: Option<Injector>,
+}
+
+impl FaultGuard {
+    pub fn arm(faults: &[Fault]) -> Self {
+        let previous = INJECTOR.with(|injector| {
+            injector.borrow_mut().replace(Injector { faults: faults.to_vec(), ..Default::default() })
+        });
+        FaultGuard { previous }
+    }
+
+    /// 지금까지 fault가 연산에 들어간 횟수
+    pub fn triggered(&self) -> usize {
+        INJECTOR.with(|injector| injector.borrow().as_ref().map_or(0, |injector| injector.triggered))
+    }
+}
+
+impl Drop for FaultGuard {
+    fn drop(&mut self) {
+        INJECTOR.with(|injector| *injector.borrow_mut() = self.previous.take());
+    }
+}
+
+/// 한 좌표의 `bit`를 뒤집고 p로 줄인 `point`
+pub fn flip_bit<C: Curve>(
+    point: &Point<C::BaseField>,
+    coordinate: Coordinate,
+    bit: u64,
+) -> Point<C::BaseField> {
+    let flip = |value: &C::BaseField| {
+        let mut value: BigUint = value.into_bigint().into();
+        value.set_bit(bit, !value.bit(bit));
+        C::BaseField::from(value)
+    };
+    match (point, coordinate) {
+        (Point::Infinity, _) => Point::Infinity,
+        (Point::Affine { x, y }, Coordinate::X) => Point::new(flip(x), *y),
+        (Point::Affine { x, y }, Coordinate::Y) => Point::new(*x, flip(y)),
+    }
+}
+
+fn flip_result<C: Curve>(mut result: Point<C::BaseField>, faults: &[Fault]) -> Point<C::BaseField> {
+    for fault in faults {
+        if let Fault::FlipResult { coordinate, bit, .. } = *fault {
+            result = flip_bit::<C>(&result, coordinate, bit);
+        }
+    }
+    result
+}
+
+fn skipped(faults: &[Fault]) -> bool {
+    faults.iter().any(|fault| matches!(fault, Fault::Skip { .. }))
+}
+
+/// 설정된 fault를 실행하는 하드웨어 위의 `C`
+pub struct Faulty<C: Curve> {
+    _curve: PhantomData<C>,
+}
+
+impl<C: Curve> Faulty<C> {
+    pub fn point(point: &CurvePoint<C>) -> CurvePoint<Self> {
+        CurvePoint { inner: point.inner.clone() }
+    }
+
+    pub fn unwrap_point(point: &CurvePoint<Self>) -> CurvePoint<C> {
+        CurvePoint { inner: point.inner.clone() }
+    }
+}
+
+impl<C: Curve> Curve for Faulty<C> {
+    type BaseField = C::BaseField;
+    type ScalarField = C::ScalarField;
+
+    fn a() -> Self::BaseField {
+        C::a()
+    }
+
+    fn b() -> Self::BaseField {
+        C::b()
+    }
+
+    fn generator() -> CurvePoint<Self> {
+        Self::point(&C::generator())
+    }
+
+    fn order() -> <Self::ScalarField as ArkPrimeField>::BigInt {
+        C::order()
+    }
+
+    fn add_point(
+        p: &Point<Self::BaseField>,
+        q: &Point<Self::BaseField>,
+    ) -> Point<Self::BaseField> {
+        let faults = hit(Operation::Add);
+        if skipped(&faults) {
+            return p.clone();
+        }
+        let mut q = q.clone();
+        for fault in &faults {
+            if let Fault::FlipOperand { coordinate, bit } = *fault {
+                q = flip_bit::<C>(&q, coordinate, bit);
+            }
+        }
+        flip_result::<C>(C::add_point(p, &q), &faults)
+    }
+
+    fn double_point(p: &Point<Self::BaseField>) -> Point<Self::BaseField> {
+        let faults = hit(Operation::Double);
+        if skipped(&faults) {
+            return p.clone();
+        }
+        flip_result::<C>(C::double_point(p), &faults)
+    }
+}
+
+/// ECDH를 하는 스마트카드처럼 점에 비밀 d를 곱해 그 곱을 돌려주는
+/// 장치
+pub struct Device<C: Curve> {
+    private_key: C::ScalarField,
+}
+
+impl<C: Curve> Device<C> {
+    pub fn new(private_key: C::ScalarField) -> Self {
+        Self { private_key }
+    }
+
+    pub fn public_key(&self) -> CurvePoint<C> {
+        C::generator().mul_scalar(&self.private_key)
+    }
+
+    /// `faults`를 설정한 채 아무 검사 없이 계산한 dP
+    pub fn multiply(&self, point: &CurvePoint<C>, faults: &[Fault]) -> CurvePoint<C> {
+        let _guard = FaultGuard::arm(faults);
+        Faulty::unwrap_point(&Faulty::point(point).mul_scalar(&self.private_key))
+    }
+
+    /// `faults`를 설정한 채 `mul_scalar_checked`로 계산한 dP
+    #[cfg(feature = "fault-countermeasures")]
+    pub fn multiply_checked(
+        &self,
+        point: &CurvePoint<C>,
+        faults: &[Fault],
+    ) -> Result<CurvePoint<C>, FaultError> {
+        let _guard = FaultGuard::arm(faults);
+        let product = Faulty::point(point).mul_scalar_checked(&self.private_key)?;
+        Ok(Faulty::unwrap_point(&product))
+    }
+}
+
+/// `point`를 지나는 곡선 y^2 = x^3 + ax + b'의 b'
+fn curve_b<C: Curve>(point: &Point<C::BaseField>) -> Option<C::BaseField> {
+    let (x, y) = (point.x()?, point.y()?);
+    Some(y * y - x * x * x - C::a() * x)
+}
+
+/// y^2 = x^3 + b' 위의 점의 위수, j = 0 위수 중 하나
+fn point_order<C: Curve>(point: &CurvePoint<C>) -> Option<BigUint> {
+    let mut order = j_zero_orders::<C>()
+        .into_iter()
+        .find(|order| point.mul_biguint(order).inner.is_infinity())?;
+    for (prime, exponent) in factorize(&order.clone()) {
+        for _ in 0..exponent {
+            let smaller = &order / &prime;
+            if !point.mul_biguint(&smaller).inner.is_infinity() {
+                break;
+            }
+            order = smaller;
+        }
+    }
+    Some(order)
+}
+
+/// 기준점 `point`와 비트 하나가 뒤집힌 곱셈 한 번의 출력으로부터, P' 위수의
+/// `max_order` 이하 소수 거듭제곱들을 법으로 한 d를 (prime, prime^e, 나머지)로
+pub fn fault_residues<C: Curve>(
+    point: &CurvePoint<C>,
+    faulty_output: &CurvePoint<C>,
+    max_order: u64,
+) -> Vec<(BigUint, BigUint, BigUint)> {
+    let Some(b) = curve_b::<C>(&faulty_output.inner) else {
+        return Vec::new();
+    };
+    if b == C::b() {
+        return Vec::new();
+    }
+    let bits = <C::BaseField as ArkPrimeField>::MODULUS_BIT_SIZE as u64;
+    let flipped = [Coordinate::X, Coordinate::Y]
+        .into_iter()
+        .flat_map(|coordinate| (0..bits).map(move |bit| (coordinate, bit)))
+        .map(|(coordinate, bit)| flip_bit::<C>(&point.inner, coordinate, bit))
+        .find(|candidate| curve_b::<C>(candidate) == Some(b));
+    let Some(flipped) = flipped.map(|inner| CurvePoint::<C> { inner }) else {
+        return Vec::new();
+    };
+    let Some(order) = point_order(&flipped) else {
+        return Vec::new();
+    };
+
+    let mut residues = Vec::new();
+    for (prime, exponent) in factorize(&order) {
+        let mut modulus = BigUint::one();
+        for _ in 0..exponent {
+            if &modulus * &prime > BigUint::from(max_order) {
+                break;
+            }
+            modulus *= &prime;
+        }
+        if modulus.is_one() {
+            continue;
+        }
+        // d (order / m) P' = (order / m) dP', both of order dividing m
+        let cofactor = &order / &modulus;
+        let base = flipped.mul_biguint(&cofactor);
+        let target = faulty_output.mul_biguint(&cofactor);
+        let mut multiple = CurvePoint::<C> { inner: Point::Infinity };
+        let mut k = BigUint::zero();
+        while k < modulus {
+            if multiple == target {
+                residues.push((prime.clone(), modulus.clone(), k));
+                break;
+            }
+            multiple = multiple.add(&base);
+            k += 1u32;
+        }
+    }
+    residues
+}
+
+/// `device`의 기준점 레지스터를 한 비트씩 뒤집고, 나머지들이 군의 위수를 덮을
+/// 때까지 합친다
+pub fn recover_private_key<C: Curve>(
+    device: &Device<C>,
+    max_order: u64,
+) -> Result<KeyRecovery, InvalidCurveError> {
+    if !C::a().is_zero() {
+        return Err(InvalidCurveError::UnsupportedCurve);
+    }
+    let order: BigUint = C::order().into();
+    let point = C::generator();
+    let bits = <C::BaseField as ArkPrimeField>::MODULUS_BIT_SIZE as u64;
+
+    // prime -> (prime^e, residue) with the largest e seen
+    let mut best: BTreeMap<BigUint, (BigUint, BigUint)> = BTreeMap::new();
+    let mut queries = 0;
+    let faults = [Coordinate::X, Coordinate::Y]
+        .into_iter()
+        .flat_map(|coordinate| (0..bits).map(move |bit| Fault::FlipOperand { coordinate, bit }));
+    for fault in faults {
+        let output = device.multiply(&point, &[fault]);
+        queries += 1;
+        for (prime, modulus, residue) in fault_residues(&point, &output, max_order) {
+            let entry = best.entry(prime).or_insert((BigUint::one(), BigUint::zero()));
+            if modulus > entry.0 {
+                *entry = (modulus, residue);
+            }
+        }
+        let covered: BigUint = best.values().map(|(modulus, _)| modulus).product();
+        if covered >= order {
+            break;
+        }
+    }
+
+    let residues: Vec<(BigUint, BigUint)> =
+        best.into_values().map(|(modulus, residue)| (residue, modulus)).collect();
+    let (residue, modulus) = crt(&residues).expect("coprime orders");
+    Ok(KeyRecovery { residue, modulus, queries })
+}
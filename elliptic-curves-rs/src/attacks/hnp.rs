@@ -0,0 +1,282 @@
+//! 몇 비트가 새는 ECDSA nonce에 대한 격자 공격
+//!
+//! 서명마다 k = s^(-1) z + s^(-1) r d (mod n)이다. k의 l비트를 알아서,
+//! N = log2 n일 때 k = 2^(N-l) h + k'이고 k' < 2^(N-l)만 비밀이라면,
+//! t = r / s, u = z / s - 2^(N-l) h로
+//!
+//! ```text
+//! k' = u + t d (mod n),   0 <= k' < K = 2^(N-l)
+//! ```
+//!
+//! 이고, 이는 Boneh와 Venkatesan의 Hidden Number Problem이다. 아래 비트가
+//! 알려진 경우도 2^l로 나누면 같다. w_i = k'_i - K/2, c_i = u_i - K/2로 두고
+//! 첫 서명으로 d를 소거하면
+//!
+//! ```text
+//! w_i = a_i w_1 + b_i (mod n),   a_i = t_i / t_1,   b_i = c_i - a_i c_1
+//! ```
+//!
+//! 이 남으므로, 성분이 모두 K/2 이하인 (w_1, ..., w_m, K/2)는 다음 행들이
+//! 생성하는 격자의 짧은 벡터다.
+//!
+//! ```text
+//! | 1  a_2 ... a_m      |
+//! |    n                |
+//! |        ...          |
+//! |             n       |
+//! |    b_2 ... b_m  K/2 |
+//! ```
+//!
+//! m l이 N보다 조금 크면 LLL이나 BKZ가 이를 찾고, d = (k'_1 - u_1) / t_1이다.
+//! 후보는 dG가 공개키와 맞을 때만 받아들인다.
+
+use crate::core::curve::Curve;
+use crate::core::lattice::{Basis, LatticeError, bkz, lll};
+use crate::core::point::CurvePoint;
+use crate::protocols::ecdsa::{Ecdsa, Signature};
+
+use ark_ff::{Field, PrimeField};
+use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HnpError {
+    #[error("need 0 < leaked bits < {n_bits}, got {bits}")]
+    InvalidLeak { bits: u32, n_bits: u64 },
+    #[error("{have} signatures leak {leaked} bits in total, fewer than the {needed} bits of n")]
+    TooFewSignatures { have: usize, leaked: u64, needed: u64 },
+    #[error("a signature has s = 0")]
+    ZeroS,
+    #[error("lattice reduction failed: {0}")]
+    Lattice(#[from] LatticeError),
+    #[error("no reduced vector gave the private key")]
+    NotFound,
+}
+
+/// nonce의 알려진 비트가 있는 곳
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakPosition {
+    /// n과 같은 비트 수로 쓴 k의 위쪽 비트
+    Msb,
+    Lsb,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Leak {
+    pub position: LeakPosition,
+    pub bits: u32,
+}
+
+/// nonce의 알려진 비트를 함께 가진 서명
+pub struct LeakySignature<C: Curve> {
+    pub z: C::ScalarField,
+    pub signature: Signature<C>,
+    pub leaked: BigUint,
+}
+
+/// HNP 기저에 쓰는 격자 축소
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reduction {
+    Lll,
+    Bkz { block_size: usize },
+}
+
+fn order<C: Curve>() -> BigUint {
+    C::order().into()
+}
+
+fn to_biguint<F: PrimeField>(value: &F) -> BigUint {
+    value.into_bigint().into()
+}
+
+/// 샌 비트가 없으면 풀 것이 없고, N비트가 모두 새면 K = 2^(N-l)에 K/2가 들어갈
+/// 자리가 없다
+fn check_leak<C: Curve>(bits: u32) -> Result<(), HnpError> {
+    let n_bits = order::<C>().bits();
+    if bits == 0 || u64::from(bits) >= n_bits {
+        return Err(HnpError::InvalidLeak { bits, n_bits });
+    }
+    Ok(())
+}
+
+/// `k`의 샌 비트
+pub fn leaked_bits<C: Curve>(k: &BigUint, leak: Leak) -> Result<BigUint, HnpError> {
+    check_leak::<C>(leak.bits)?;
+    Ok(match leak.position {
+        LeakPosition::Msb => k >> (order::<C>().bits() as u32 - leak.bits),
+        LeakPosition::Lsb => k % (BigUint::one() << leak.bits),
+    })
+}
+
+/// 샌 비트가 0으로 고정된 nonce의 서명 `count`개. 고장 난 생성기가 만드는
+/// 것처럼 (예: `Msb`이면 k < 2^(N-l))
+pub fn biased_signatures<C: Curve>(
+    private_key: &C::ScalarField,
+    count: usize,
+    leak: Leak,
+) -> Result<Vec<LeakySignature<C>>, HnpError> {
+    check_leak::<C>(leak.bits)?;
+    let mut rng = thread_rng();
+    let mut signatures = Vec::with_capacity(count);
+    while signatures.len() < count {
+        let k = to_biguint(&C::ScalarField::rand(&mut rng));
+        let k = match leak.position {
+            LeakPosition::Msb => k % (BigUint::one() << (order::<C>().bits() as u32 - leak.bits)),
+            LeakPosition::Lsb => (k >> leak.bits) << leak.bits,
+        };
+        let z = C::ScalarField::rand(&mut rng);
+        let nonce = C::ScalarField::from(k);
+        if let Some(signature) = Ecdsa::<C>::signing_message_with_nonce(private_key, z, &nonce) {
+            signatures.push(LeakySignature { z, signature, leaked: BigUint::zero() });
+        }
+    }
+    Ok(signatures)
+}
+
+/// nonce는 균등하지만 부채널로 샌 비트가 드러난 서명 `count`개
+pub fn leaky_signatures<C: Curve>(
+    private_key: &C::ScalarField,
+    count: usize,
+    leak: Leak,
+) -> Result<Vec<LeakySignature<C>>, HnpError> {
+    check_leak::<C>(leak.bits)?;
+    let mut rng = thread_rng();
+    let mut signatures = Vec::with_capacity(count);
+    while signatures.len() < count {
+        let nonce = C::ScalarField::rand(&mut rng);
+        let z = C::ScalarField::rand(&mut rng);
+        if let Some(signature) = Ecdsa::<C>::signing_message_with_nonce(private_key, z, &nonce) {
+            let leaked = leaked_bits::<C>(&to_biguint(&nonce), leak)?;
+            signatures.push(LeakySignature { z, signature, leaked });
+        }
+    }
+    Ok(signatures)
+}
+
+/// k' = u + t d (mod n), 0 <= k' < K인 쌍 (t, u)
+type Instance<F> = Vec<(F, F)>;
+
+fn hnp_instance<C: Curve>(
+    signatures: &[LeakySignature<C>],
+    leak: Leak,
+) -> Result<Instance<C::ScalarField>, HnpError> {
+    check_leak::<C>(leak.bits)?;
+    let n_bits = order::<C>().bits() as u32;
+    signatures
+        .iter()
+        .map(|signature| {
+            let (r, s) = signature.signature.to_scalars();
+            let s_inv = s.inverse().ok_or(HnpError::ZeroS)?;
+            let leaked = C::ScalarField::from(signature.leaked.clone());
+            Ok(match leak.position {
+                LeakPosition::Msb => {
+                    let shift = C::ScalarField::from(BigUint::one() << (n_bits - leak.bits));
+                    (r * s_inv, signature.z * s_inv - shift * leaked)
+                }
+                LeakPosition::Lsb => {
+                    // k = leaked + 2^l k'
+                    let scale = C::ScalarField::from(BigUint::one() << leak.bits)
+                        .inverse()
+                        .expect("n is odd");
+                    (r * s_inv * scale, (signature.z * s_inv - leaked) * scale)
+                }
+            })
+        })
+        .collect()
+}
+
+/// `bits`비트가 샐 때의 K/2
+fn half_range<C: Curve>(bits: u32) -> BigUint {
+    BigUint::one() << (order::<C>().bits() as u32 - bits - 1)
+}
+
+fn to_bigint<F: PrimeField>(value: &F) -> BigInt {
+    BigInt::from(to_biguint(value))
+}
+
+/// 모듈 문서의 HNP 격자
+pub fn hnp_lattice<C: Curve>(
+    signatures: &[LeakySignature<C>],
+    leak: Leak,
+) -> Result<Basis, HnpError> {
+    let pairs = hnp_instance(signatures, leak)?;
+    let half = C::ScalarField::from(half_range::<C>(leak.bits));
+    let n = BigInt::from(order::<C>());
+    let m = pairs.len();
+    let Some(&(t_1, u_1)) = pairs.first() else {
+        return Ok(Vec::new());
+    };
+    let t_1_inv = t_1.inverse().ok_or(HnpError::ZeroS)?;
+    let c_1 = u_1 - half;
+
+    let mut basis = vec![vec![BigInt::zero(); m + 1]; m + 1];
+    basis[0][0] = BigInt::one();
+    for (i, &(t, u)) in pairs.iter().enumerate().skip(1) {
+        let a = t * t_1_inv;
+        basis[0][i] = to_bigint(&a);
+        basis[i][i] = n.clone();
+        basis[m][i] = to_bigint(&(u - half - a * c_1));
+    }
+    basis[m][m] = to_bigint(&half);
+    Ok(basis)
+}
+
+/// 축소된 행 (w_1, ..., +-K/2)에서 w_1을 읽고 그로부터 얻은 키를
+/// 확인한다
+fn key_from_row<C: Curve>(
+    row: &[BigInt],
+    half: &BigInt,
+    &(t_1, u_1): &(C::ScalarField, C::ScalarField),
+    public_key: &CurvePoint<C>,
+) -> Option<C::ScalarField> {
+    let marker = row.last()?;
+    if marker.abs() != *half {
+        return None;
+    }
+    let w_1 = if marker.is_negative() { -&row[0] } else { row[0].clone() };
+    let k_1 = (w_1 + half).mod_floor(&BigInt::from(order::<C>()));
+    let k_1 = C::ScalarField::from(k_1.to_biguint().expect("reduced mod n"));
+    let d = (k_1 - u_1) * t_1.inverse()?;
+    (C::generator().mul_scalar(&d) == *public_key).then_some(d)
+}
+
+/// `leak`만큼 새는 서명들로 `public_key`의 키를 복원한다.
+pub fn recover_private_key<C: Curve>(
+    public_key: &CurvePoint<C>,
+    signatures: &[LeakySignature<C>],
+    leak: Leak,
+    reduction: Reduction,
+) -> Result<C::ScalarField, HnpError> {
+    check_leak::<C>(leak.bits)?;
+    let needed = order::<C>().bits();
+    // an overflow leaks more than enough
+    if let Some(leaked) = (signatures.len() as u64).checked_mul(u64::from(leak.bits))
+        && leaked <= needed
+    {
+        return Err(HnpError::TooFewSignatures { have: signatures.len(), leaked, needed });
+    }
+
+    let mut basis = hnp_lattice(signatures, leak)?;
+    match reduction {
+        Reduction::Lll => lll(&mut basis)?,
+        Reduction::Bkz { block_size } => bkz(&mut basis, block_size)?,
+    }
+
+    let first = hnp_instance(&signatures[..1], leak)?[0];
+    let half = BigInt::from(half_range::<C>(leak.bits));
+    basis
+        .iter()
+        .find_map(|row| key_from_row(row, &half, &first, public_key))
+        .ok_or(HnpError::NotFound)
+}
+
+/// nonce마다 `bits`비트가 샐 때 필요한 서명 수, 관례대로 몇 개의 여유를 둔다.
+/// 몇 개로도 안 되면 None
+pub fn required_signatures<C: Curve>(bits: u32) -> Option<usize> {
+    check_leak::<C>(bits).ok()?;
+    let n_bits = order::<C>().bits() as usize;
+    Some(n_bits.div_ceil(bits as usize) + n_bits / (4 * bits as usize) + 2)
+}
@@ -0,0 +1,299 @@
+//! 공개키를 검증하지 않는 ECDH에 대한 invalid-curve 공격
+//!
+//! y^2 = x^3 + ax + b의 덧셈과 두 배 공식은 a만 쓰므로, 검사하지 않은 점에
+//! 개인키 d를 곱하는 피해자는 그 점이 만족하는 b'의 곡선
+//! y^2 = x^3 + ax + b' 위에서 조용히 계산한다. 그 곡선에 작은 위수 m의 점
+//! P가 있으면 피해자의 답 dP는 d mod m에만 달려 있고, 공격자는 k < m을 모두
+//! 시도해 이를 찾는다. 서로소인 위수들에서 얻은 나머지를 곱이 군의 위수를
+//! 넘을 때까지 CRT로 합친다.
+//!
+//! a = 0이면 (secp256k1과 toy 곡선) j-invariant가 0이고 모든 b'은 많아야
+//! 여섯 개의 동형류 중 하나에 속한다. 그 위수 p + 1 - t는 4p = t^2 + 3v^2
+//! 만으로 정해진다:
+//!
+//! ```text
+//! t in { +-t_0, +-(t_0 + 3 v_0) / 2, +-(t_0 - 3 v_0) / 2 }
+//! ```
+//!
+//! 따라서 점의 개수를 셀 필요가 없다. 공격에 쓸 수 있는 것은 이 위수들의
+//! 작은 인수다: secp256k1에서는 2^29 아래로 약 130비트로 256비트 키에는
+//! 모자라지만, 40비트 toy 곡선에는 충분하고도 남는다.
+//! `Ecdh::compute_shared_secret_checked`는 이런 점을 모두 거부한다.
+
+use crate::attacks::torsion::{bounded_exponent, element_of_order};
+use crate::core::curve::Curve;
+use crate::core::group::Group;
+use crate::core::integer::crt;
+use crate::core::point::{CurvePoint, Point};
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+
+use ark_ff::{Field as ArkField, PrimeField as ArkPrimeField, Zero};
+use num_bigint::{BigInt, BigUint, RandBigInt};
+use rand::thread_rng;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use std::collections::BTreeMap;
+
+/// 여섯 후보 위수를 구별할 때 시도하는 임의의 점 수
+const MAX_ORDER_ATTEMPTS: usize = 8;
+/// 동형류를 찾을 때 시도하는 가장 큰 b'
+const MAX_B: u64 = 256;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum InvalidCurveError {
+    #[error("the j = 0 order formula needs a = 0")]
+    UnsupportedCurve,
+    #[error("could not determine the order of y^2 = x^3 + b'")]
+    UnknownOrder,
+    #[error("the victim rejected the point: {0}")]
+    Rejected(#[from] EcdhError),
+    #[error("no k below {order} reproduces the victim's response")]
+    NoMatch { order: u64 },
+}
+
+/// `C`의 a를 쓰는 y^2 = x^3 + ax + b'과 그 점의 개수
+pub struct InvalidCurve<C: Curve> {
+    pub b: C::BaseField,
+    pub order: BigUint,
+}
+
+/// 계수 `b`인 invalid curve 위의 위수 `order`인 점
+pub struct LowOrderPoint<C: Curve> {
+    pub point: CurvePoint<C>,
+    pub b: C::BaseField,
+    pub order: u64,
+}
+
+/// `modulus`를 법으로 한 개인키와 그에 든 질의 수
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRecovery {
+    pub residue: BigUint,
+    pub modulus: BigUint,
+    pub queries: usize,
+}
+
+impl KeyRecovery {
+    /// modulus가 군의 위수를 덮으면 키 전체
+    pub fn private_key(&self, order: &BigUint) -> Option<BigUint> {
+        (self.modulus >= *order).then(|| self.residue.clone())
+    }
+}
+
+/// 키 확인 메시지처럼 공유점의 태그로 핸드셰이크에 답하는 서버. hardened
+/// 서버만 검증한다.
+pub struct Victim<C: Curve> {
+    private_key: C::ScalarField,
+    hardened: bool,
+}
+
+impl<C: Curve> Victim<C> {
+    pub fn new(private_key: C::ScalarField) -> Self {
+        Self { private_key, hardened: false }
+    }
+
+    pub fn hardened(private_key: C::ScalarField) -> Self {
+        Self { private_key, hardened: true }
+    }
+
+    pub fn public_key(&self) -> CurvePoint<C> {
+        C::generator().mul_scalar(&self.private_key)
+    }
+
+    pub fn respond(&self, point: &CurvePoint<C>) -> Result<[u8; 32], EcdhError> {
+        let shared = if self.hardened {
+            Ecdh::<C>::compute_shared_secret_checked(&self.private_key, point)?
+        } else {
+            Ecdh::<C>::compute_shared_secret(&self.private_key, point)
+        };
+        Ok(tag(&shared))
+    }
+}
+
+fn tag<C: Curve>(point: &CurvePoint<C>) -> [u8; 32] {
+    Sha256::digest(point.encode()).into()
+}
+
+fn modulus<C: Curve>() -> BigUint {
+    <C::BaseField as ArkPrimeField>::MODULUS.into()
+}
+
+/// `C`의 체 위에서 y^2 = x^3 + b의 가능한 #E(F_p) 전부
+pub fn j_zero_orders<C: Curve>() -> Vec<BigUint> {
+    let p = modulus::<C>();
+    let p_plus_one = BigInt::from(&p + 1u32);
+    // p = 2 mod 3: every such curve is supersingular
+    let Some((t, v)) = cornacchia::<C>(&p) else {
+        return vec![p + 1u32];
+    };
+    let (t, v) = (BigInt::from(t), BigInt::from(v));
+    let traces = [t.clone(), (&t + 3 * &v) / 2, (&t - 3 * &v) / 2];
+    let mut orders: Vec<BigUint> = traces
+        .iter()
+        .flat_map(|trace| [&p_plus_one - trace, &p_plus_one + trace])
+        .filter_map(|order| order.to_biguint())
+        .collect();
+    orders.sort();
+    orders.dedup();
+    orders
+}
+
+/// 4p = t^2 + 3v^2인 (t, v). sqrt(-3) mod p를 구하고 (2p, sqrt(-3))에
+/// 유클리드 호제법을 sqrt(4p) 아래에서 멈춘다
+fn cornacchia<C: Curve>(p: &BigUint) -> Option<(BigUint, BigUint)> {
+    let root: BigUint = (-C::BaseField::from(3u64)).sqrt()?.into_bigint().into();
+    // t and v have the same parity, and so must the root and t
+    let root = if root.bit(0) { root } else { p - root };
+    let four_p: BigUint = p << 2;
+    let limit = four_p.sqrt();
+    let (mut a, mut b): (BigUint, BigUint) = (p << 1, root);
+    while b > limit {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    let rest = &four_p - &b * &b;
+    if !(&rest % 3u32).is_zero() {
+        return None;
+    }
+    let v = (&rest / 3u32).sqrt();
+    (&v * &v * 3u32 == rest).then_some((b, v))
+}
+
+fn random_point<C: Curve>(b: C::BaseField) -> CurvePoint<C> {
+    let p = modulus::<C>();
+    let mut rng = thread_rng();
+    loop {
+        let x = C::BaseField::from(rng.gen_biguint_below(&p));
+        let rhs = x.square() * x + C::a() * x + b;
+        if let Some(y) = rhs.sqrt()
+            && !y.is_zero()
+        {
+            return CurvePoint::new(x, y);
+        }
+    }
+}
+
+/// `C` 자신의 동형류를 뺀 동형류마다 곡선 y^2 = x^3 + b' 하나, 즉
+/// `j_zero_orders`의 위수마다 하나
+pub fn invalid_curves<C: Curve>() -> Result<Vec<InvalidCurve<C>>, InvalidCurveError> {
+    if !C::a().is_zero() {
+        return Err(InvalidCurveError::UnsupportedCurve);
+    }
+    let orders = j_zero_orders::<C>();
+    let own_order: BigUint = C::order().into();
+    let mut curves: Vec<InvalidCurve<C>> = Vec::new();
+
+    for b in (1..=MAX_B).map(C::BaseField::from) {
+        if b == C::b() {
+            continue;
+        }
+        // the order must kill a few random points
+        let mut candidates = orders.clone();
+        for _ in 0..MAX_ORDER_ATTEMPTS {
+            let point = random_point::<C>(b);
+            candidates.retain(|order| point.mul_biguint(order).inner.is_infinity());
+            if candidates.len() <= 1 {
+                break;
+            }
+        }
+        let [order] = candidates.as_slice() else {
+            return Err(InvalidCurveError::UnknownOrder);
+        };
+        if *order != own_order && curves.iter().all(|curve| curve.order != *order) {
+            curves.push(InvalidCurve { b, order: order.clone() });
+        }
+        if curves.len() + 1 >= orders.len() {
+            break;
+        }
+    }
+    Ok(curves)
+}
+
+/// `bound` 이하인 n의 소인수와 그 지수
+fn small_factors(n: &BigUint, bound: u64) -> Vec<(u64, u32)> {
+    let mut rest = n.clone();
+    let mut factors = Vec::new();
+    for d in 2..=bound {
+        let mut exponent = 0;
+        while (&rest % d).is_zero() {
+            rest /= d;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((d, exponent));
+        }
+    }
+    factors
+}
+
+/// `curve` 위의 위수 prime^e인 점, `element_of_order` 참고
+fn point_of_order<C: Curve>(
+    curve: &InvalidCurve<C>,
+    prime: u64,
+    exponent: u32,
+    target: u32,
+) -> Option<(CurvePoint<C>, u32)> {
+    let random = || random_point::<C>(curve.b);
+    let mul = |point: &CurvePoint<C>, k: &BigUint| {
+        let multiple = point.mul_biguint(k);
+        (!multiple.inner.is_infinity()).then_some(multiple)
+    };
+    element_of_order(&curve.order, prime, exponent, target, random, mul)
+}
+
+/// `max_order` 아래의 소수마다, invalid curve들 중 어디서든 찾은 가장 큰 위수
+/// prime^e <= `max_order`의 점
+pub fn low_order_points<C: Curve>(
+    max_order: u64,
+) -> Result<Vec<LowOrderPoint<C>>, InvalidCurveError> {
+    let curves = invalid_curves::<C>()?;
+
+    // prime -> (target, exponent, curve)
+    let mut best: BTreeMap<u64, (u32, u32, usize)> = BTreeMap::new();
+    for (index, curve) in curves.iter().enumerate() {
+        for (prime, exponent) in small_factors(&curve.order, max_order) {
+            let target = bounded_exponent(prime, exponent, max_order);
+            let entry = best.entry(prime).or_insert((target, exponent, index));
+            if target > entry.0 {
+                *entry = (target, exponent, index);
+            }
+        }
+    }
+
+    let mut points = Vec::new();
+    for (prime, (target, exponent, index)) in best {
+        let curve = &curves[index];
+        if let Some((point, e)) = point_of_order(curve, prime, exponent, target) {
+            points.push(LowOrderPoint { point, b: curve.b, order: prime.pow(e) });
+        }
+    }
+    Ok(points)
+}
+
+/// 모든 점을 피해자에게 보내고 돌아온 태그에 k < m을 하나씩 맞춰 d mod m을
+/// 찾은 뒤, 나머지들을 합친다.
+pub fn recover_private_key<C: Curve>(
+    victim: &Victim<C>,
+    points: &[LowOrderPoint<C>],
+) -> Result<KeyRecovery, InvalidCurveError> {
+    let mut residues = Vec::new();
+    for low in points {
+        let response = victim.respond(&low.point)?;
+        let mut multiple = CurvePoint::<C> { inner: Point::Infinity };
+        let mut residue = None;
+        for k in 0..low.order {
+            if tag(&multiple) == response {
+                residue = Some(k);
+                break;
+            }
+            multiple = multiple.add(&low.point);
+        }
+        let k = residue.ok_or(InvalidCurveError::NoMatch { order: low.order })?;
+        residues.push((BigUint::from(k), BigUint::from(low.order)));
+    }
+
+    // the orders are powers of distinct primes
+    let (residue, modulus) = crt(&residues).expect("coprime orders");
+    Ok(KeyRecovery { residue, modulus, queries: points.len() })
+}
@@ -0,0 +1,10 @@
+pub mod dual_ec;
+pub mod ecdsa_nonce_reuse;
+pub mod fault;
+pub mod hnp;
+pub mod invalid_curve;
+mod torsion;
+pub mod twist;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,61 @@
+use crate::attacks::dual_ec::{Backdoor, backdoored_q, recover_state};
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::protocols::dual_ec_drbg::{DualEcDrbg, TRUNCATED_BITS};
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+
+/// The victim outputs four blocks and two more calls; the attacker sees
+/// the first block and four bytes of the second, and predicts the rest.
+fn predicts_future_output<C: Curve>(truncated_bits: u32) {
+    let p = C::generator();
+    let backdoor = backdoored_q(&p);
+    let seed = thread_rng().gen_biguint(128);
+    let mut victim =
+        DualEcDrbg::with_truncation(p.clone(), backdoor.q.clone(), &seed, truncated_bits);
+    let block_len = victim.block_len();
+
+    let output = victim.generate(4 * block_len);
+    let (block, rest) = output.split_at(block_len);
+    let mut attacker = recover_state(&p, &backdoor, truncated_bits, block, &rest[..4]).unwrap();
+
+    assert_eq!(attacker.generate(rest.len()), rest);
+    assert_eq!(attacker.generate(100), victim.generate(100));
+    assert_eq!(attacker.state(), victim.state());
+}
+
+#[test]
+fn test_backdoor_on_secp256k1() {
+    predicts_future_output::<Secp256k1Curve>(8);
+}
+
+#[test]
+fn test_backdoor_on_p256() {
+    predicts_future_output::<P256Curve>(8);
+}
+
+#[test]
+#[ignore = "tries 2^16 prefixes, minutes in debug builds"]
+fn test_backdoor_with_standard_truncation() {
+    predicts_future_output::<P256Curve>(TRUNCATED_BITS);
+    predicts_future_output::<Secp256k1Curve>(TRUNCATED_BITS);
+}
+
+#[test]
+fn test_needs_the_trapdoor() {
+    let p = P256Curve::generator();
+    let backdoor = backdoored_q(&p);
+    let seed = BigUint::from(5u32);
+    let mut victim = DualEcDrbg::with_truncation(p.clone(), backdoor.q.clone(), &seed, 8);
+    let block_len = victim.block_len();
+    let output = victim.generate(2 * block_len);
+    let (block, rest) = output.split_at(block_len);
+
+    // a guessed e gives nothing
+    let wrong = backdoored_q(&p);
+    let guess = Backdoor { q: backdoor.q.clone(), trapdoor: wrong.trapdoor };
+    assert!(recover_state(&p, &guess, 8, block, &rest[..4]).is_none());
+    assert!(recover_state(&p, &backdoor, 8, &block[1..], &rest[..4]).is_none());
+}
@@ -0,0 +1,120 @@
+use crate::attacks::ecdsa_nonce_reuse::{
+    NonceReuseError,
+    SignatureRecord,
+    recover_private_key,
+    scan,
+};
+use crate::core::point::CurvePoint;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::protocols::ecdh::Ecdh;
+use crate::protocols::ecdsa::{Ecdsa, Signature};
+
+use ark_ff::PrimeField;
+use ark_std::{UniformRand, rand::thread_rng};
+use sha2::{Digest, Sha256};
+
+type Signer = Ecdsa<Secp256k1Curve>;
+
+fn z(message: &[u8]) -> FrSecp256k1 {
+    FrSecp256k1::from_be_bytes_mod_order(&Sha256::digest(message))
+}
+
+fn sign(private_key: &FrSecp256k1, message: &[u8], k: &FrSecp256k1) -> Signature<Secp256k1Curve> {
+    Signer::signing_message_with_nonce(private_key, z(message), k).unwrap()
+}
+
+fn keypair() -> (FrSecp256k1, CurvePoint<Secp256k1Curve>) {
+    let (secret_key, public_key) = Ecdh::<Secp256k1Curve>::generate_keypair();
+    (*secret_key.expose_scalar(), public_key.to_point())
+}
+
+#[test]
+fn test_recovers_key_and_nonce() {
+    let (private_key, public_key) = keypair();
+    let k = FrSecp256k1::rand(&mut thread_rng());
+    let sig1 = sign(&private_key, b"pay alice 1 BTC", &k);
+    let sig2 = sign(&private_key, b"pay bob 2 BTC", &k);
+    assert!(Signer::verifying_message(&public_key, z(b"pay alice 1 BTC"), &sig1));
+    assert_eq!(sig1.r(), sig2.r());
+
+    let recovered = recover_private_key(
+        &sig1.to_scalars(), z(b"pay alice 1 BTC"), &sig2.to_scalars(), z(b"pay bob 2 BTC"),
+    ).unwrap();
+    assert_eq!(recovered.private_key, private_key);
+    assert_eq!(recovered.nonce, k);
+}
+
+#[test]
+fn test_rejects_unrelated_signatures() {
+    let (private_key, _) = keypair();
+    let mut rng = thread_rng();
+    let sig1 = sign(&private_key, b"one", &FrSecp256k1::rand(&mut rng));
+    let sig2 = sign(&private_key, b"two", &FrSecp256k1::rand(&mut rng));
+    assert_eq!(
+        recover_private_key(&sig1.to_scalars(), z(b"one"), &sig2.to_scalars(), z(b"two")),
+        Err(NonceReuseError::DifferentR)
+    );
+    assert_eq!(
+        recover_private_key(&sig1.to_scalars(), z(b"one"), &sig1.to_scalars(), z(b"one")),
+        Err(NonceReuseError::SameS)
+    );
+}
+
+#[test]
+fn test_scanner_reports_every_compromised_key() {
+    let mut rng = thread_rng();
+    let (alice, alice_public) = keypair();
+    let (bob, bob_public) = keypair();
+    let (carol, carol_public) = keypair();
+    let (dave, dave_public) = keypair();
+    let k_alice = FrSecp256k1::rand(&mut rng);
+    let k_bob = FrSecp256k1::rand(&mut rng);
+
+    let record = |private_key: &FrSecp256k1, public_key, message: &'static [u8], k| {
+        SignatureRecord::<Secp256k1Curve> {
+            public_key,
+            z: z(message),
+            signature: sign(private_key, message, &k),
+        }
+    };
+    let mut records = vec![
+        // Alice reuses k_alice, and Carol happens to use it too
+        record(&alice, alice_public.clone(), b"a1", k_alice),
+        record(&dave, dave_public.clone(), b"d1", FrSecp256k1::rand(&mut rng)),
+        record(&alice, alice_public.clone(), b"a2", k_alice),
+        record(&carol, carol_public.clone(), b"c1", k_alice),
+        // Alice's known key reveals k_bob, which then reveals Bob's key
+        record(&alice, alice_public.clone(), b"a3", k_bob),
+        record(&bob, bob_public.clone(), b"b1", k_bob),
+        record(&dave, dave_public.clone(), b"d2", FrSecp256k1::rand(&mut rng)),
+    ];
+    // a low-S style signature with -s still belongs to k_alice
+    let (r, s) = records[2].signature.to_scalars();
+    records[2].signature = Signature::from_scalars(r, -s).unwrap();
+
+    let compromised = scan(&records);
+    let private_key = |public_key| {
+        compromised.iter()
+            .find(|key| key.public_key == public_key)
+            .map(|key| key.private_key)
+    };
+    assert_eq!(compromised.len(), 3);
+    assert_eq!(private_key(alice_public), Some(alice));
+    assert_eq!(private_key(bob_public), Some(bob));
+    assert_eq!(private_key(carol_public), Some(carol));
+    assert_eq!(private_key(dave_public), None);
+}
+
+#[test]
+fn test_scanner_ignores_distinct_nonces() {
+    let (alice, alice_public) = keypair();
+    let records: Vec<_> = [b"x".as_slice(), b"y", b"z"]
+        .into_iter()
+        .map(|message| SignatureRecord::<Secp256k1Curve> {
+            public_key: alice_public.clone(),
+            z: z(message),
+            signature: Signer::signing_message(&alice, z(message)),
+        })
+        .collect();
+    assert!(scan(&records).is_empty());
+}
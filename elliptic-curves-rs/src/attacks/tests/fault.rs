@@ -0,0 +1,199 @@
+use crate::attacks::fault::{
+    Coordinate,
+    Device,
+    Fault,
+    FaultGuard,
+    Faulty,
+    Operation,
+    fault_residues,
+    recover_private_key,
+};
+use crate::core::curve::Curve;
+use crate::curves::toy::toy::{FrToy32, FrToy40, Toy32Curve, Toy40Curve};
+use crate::protocols::ecdsa::Ecdsa;
+
+#[cfg(feature = "fault-countermeasures")]
+use crate::core::countermeasures::FaultError;
+#[cfg(feature = "fault-countermeasures")]
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+#[cfg(feature = "fault-countermeasures")]
+use crate::protocols::ecdsa::{SigningError, SigningKey};
+#[cfg(feature = "fault-countermeasures")]
+use crate::protocols::keys::SecretKey;
+#[cfg(feature = "fault-countermeasures")]
+use sha2::Sha256;
+
+use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::BigUint;
+
+// the scalars are 64-bit words: the first 32 doublings are of infinity
+const FLIP_DOUBLE: Fault = Fault::FlipResult {
+    operation: Operation::Double,
+    call: 40,
+    coordinate: Coordinate::X,
+    bit: 3,
+};
+const SKIP_ADD: Fault = Fault::Skip { operation: Operation::Add, call: 2 };
+
+fn key() -> FrToy32 {
+    // enough set bits for the third addition to exist
+    FrToy32::from(0xdead_beefu64)
+}
+
+#[test]
+fn test_no_fault_no_change() {
+    let device = Device::<Toy32Curve>::new(key());
+    let g = Toy32Curve::generator();
+    assert_eq!(device.multiply(&g, &[]), device.public_key());
+
+    let guard = FaultGuard::arm(&[SKIP_ADD]);
+    let _ = Faulty::point(&g).mul_scalar(&FrToy32::from(1u64));
+    // a single addition: the third one never happens
+    assert_eq!(guard.triggered(), 0);
+}
+
+#[test]
+fn test_nested_guard_restores_outer_faults() {
+    let device = Device::<Toy32Curve>::new(key());
+    let g = Toy32Curve::generator();
+
+    let outer = FaultGuard::arm(&[SKIP_ADD]);
+    // arms and drops a guard of its own
+    let flipped = device.multiply(&g, &[FLIP_DOUBLE]);
+    assert!(!Toy32Curve::is_on_curve(&flipped.inner));
+
+    let skipped = Faulty::point(&g).mul_scalar(&key());
+    assert_eq!(outer.triggered(), 1);
+    assert_ne!(Faulty::unwrap_point(&skipped), device.public_key());
+    drop(outer);
+
+    let product = Faulty::point(&g).mul_scalar(&key());
+    assert_eq!(Faulty::unwrap_point(&product), device.public_key());
+}
+
+#[test]
+fn test_faults_corrupt_the_product() {
+    let device = Device::<Toy32Curve>::new(key());
+    let g = Toy32Curve::generator();
+
+    // a flipped bit leaves the curve
+    let flipped = device.multiply(&g, &[FLIP_DOUBLE]);
+    assert!(!Toy32Curve::is_on_curve(&flipped.inner));
+
+    // a skipped addition stays on it, with a wrong multiple
+    let skipped = device.multiply(&g, &[SKIP_ADD]);
+    assert!(Toy32Curve::is_on_curve(&skipped.inner));
+    assert_ne!(skipped, device.public_key());
+}
+
+#[test]
+fn test_faulty_signature_does_not_verify() {
+    type Signer = Ecdsa<Faulty<Toy32Curve>>;
+    let private_key = key();
+    let public_key = Faulty::point(&Toy32Curve::generator().mul_scalar(&private_key));
+    let z = FrToy32::from(42u64);
+    let k = FrToy32::from(0x1234_5678u64);
+
+    let signature = {
+        let _guard = FaultGuard::arm(&[SKIP_ADD]);
+        Signer::signing_message_with_nonce(&private_key, z, &k).unwrap()
+    };
+    assert!(!Signer::verifying_message(&public_key, z, &signature));
+}
+
+#[test]
+fn test_operand_fault_moves_to_weaker_curve() {
+    let private_key = FrToy40::rand(&mut thread_rng());
+    let device = Device::<Toy40Curve>::new(private_key);
+    let g = Toy40Curve::generator();
+    let d: BigUint = private_key.into();
+
+    let mut found = 0;
+    for bit in 0..8 {
+        let fault = Fault::FlipOperand { coordinate: Coordinate::Y, bit };
+        let output = device.multiply(&g, &[fault]);
+        assert!(!Toy40Curve::is_on_curve(&output.inner));
+        for (_, modulus, residue) in fault_residues(&g, &output, 1 << 16) {
+            assert_eq!(residue, &d % modulus);
+            found += 1;
+        }
+    }
+    assert!(found > 0);
+}
+
+#[test]
+fn test_differential_fault_attack_recovers_key() {
+    let private_key = FrToy40::rand(&mut thread_rng());
+    let device = Device::<Toy40Curve>::new(private_key);
+    let recovery = recover_private_key(&device, 1 << 16).unwrap();
+    let order: BigUint = Toy40Curve::order().into();
+    assert_eq!(recovery.private_key(&order), Some(private_key.into()));
+    assert!(recovery.queries <= 80);
+}
+
+#[cfg(feature = "fault-countermeasures")]
+#[test]
+fn test_countermeasures_detect_faults() {
+    let device = Device::<Toy32Curve>::new(key());
+    let g = Toy32Curve::generator();
+
+    assert_eq!(device.multiply_checked(&g, &[]), Ok(device.public_key()));
+    assert_eq!(device.multiply_checked(&g, &[FLIP_DOUBLE]), Err(FaultError::NotOnCurve));
+    assert_eq!(device.multiply_checked(&g, &[SKIP_ADD]), Err(FaultError::Incoherent));
+    let operand = Fault::FlipOperand { coordinate: Coordinate::X, bit: 7 };
+    assert_eq!(device.multiply_checked(&g, &[operand]), Err(FaultError::NotOnCurve));
+}
+
+#[cfg(feature = "fault-countermeasures")]
+#[test]
+fn test_checked_protocols_reject_faults() {
+    type Faulty32 = Faulty<Toy32Curve>;
+    let private_key = key();
+    let public_key = Faulty32::generator().mul_scalar(&private_key);
+    let z = FrToy32::from(42u64);
+
+    let signature = Ecdsa::<Faulty32>::signing_message_checked(&private_key, &public_key, z);
+    assert!(Ecdsa::<Faulty32>::verifying_message(&public_key, z, &signature.unwrap()));
+    {
+        let _guard = FaultGuard::arm(&[SKIP_ADD]);
+        let signature = Ecdsa::<Faulty32>::signing_message_checked(&private_key, &public_key, z);
+        assert_eq!(signature, Err(FaultError::Incoherent));
+    }
+    // a flipped bit in the stored private key
+    let corrupted = private_key + FrToy32::from(1u64 << 8);
+    let signature = Ecdsa::<Faulty32>::signing_message_checked(&corrupted, &public_key, z);
+    assert_eq!(signature, Err(FaultError::InvalidSignature));
+
+    // past the doublings of the subgroup check n P
+    let other = Faulty32::generator().mul_scalar(&FrToy32::from(7u64));
+    let n_bits = BigUint::from(Toy32Curve::order()).bits() as usize;
+    let fault = Fault::FlipResult {
+        operation: Operation::Double,
+        call: n_bits + 40,
+        coordinate: Coordinate::Y,
+        bit: 1,
+    };
+    let _guard = FaultGuard::arm(&[fault]);
+    assert_eq!(
+        Ecdh::<Faulty32>::compute_shared_secret_checked(&private_key, &other),
+        Err(EcdhError::Fault(FaultError::NotOnCurve))
+    );
+}
+
+#[cfg(feature = "fault-countermeasures")]
+#[test]
+fn test_deterministic_signing_rejects_faults() {
+    type Faulty32 = Faulty<Toy32Curve>;
+    let key = SigningKey::<Faulty32>::new(SecretKey::from_scalar(key()).unwrap());
+
+    let signature = Ecdsa::sign::<Sha256>(&key, b"sample").unwrap();
+    assert!(Ecdsa::verify::<Sha256>(key.public_key(), b"sample", &signature));
+    {
+        let _guard = FaultGuard::arm(&[SKIP_ADD]);
+        let signature = Ecdsa::sign::<Sha256>(&key, b"sample");
+        assert_eq!(signature, Err(SigningError::Fault(FaultError::Incoherent)));
+    }
+    let _guard = FaultGuard::arm(&[FLIP_DOUBLE]);
+    let signature = Ecdsa::sign_recoverable::<Sha256>(&key, b"sample");
+    assert_eq!(signature, Err(SigningError::Fault(FaultError::NotOnCurve)));
+}
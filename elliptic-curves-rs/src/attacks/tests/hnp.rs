@@ -0,0 +1,111 @@
+use crate::attacks::hnp::{
+    HnpError,
+    Leak,
+    LeakPosition,
+    Reduction,
+    biased_signatures,
+    leaky_signatures,
+    recover_private_key,
+    required_signatures,
+};
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::curves::toy::toy::{FrToy48, Toy48Curve};
+
+use ark_std::{UniformRand, rand::thread_rng};
+
+const MSB: fn(u32) -> Leak = |bits| Leak { position: LeakPosition::Msb, bits };
+const LSB: fn(u32) -> Leak = |bits| Leak { position: LeakPosition::Lsb, bits };
+
+#[test]
+fn test_toy48_biased_msb() {
+    let private_key = FrToy48::rand(&mut thread_rng());
+    let public_key = Toy48Curve::generator().mul_scalar(&private_key);
+    let leak = MSB(6);
+    let signatures = biased_signatures::<Toy48Curve>(&private_key, 12, leak).unwrap();
+    let recovered = recover_private_key(&public_key, &signatures, leak, Reduction::Lll);
+    assert_eq!(recovered, Ok(private_key));
+}
+
+#[test]
+fn test_toy48_leaked_lsb_with_bkz() {
+    let private_key = FrToy48::rand(&mut thread_rng());
+    let public_key = Toy48Curve::generator().mul_scalar(&private_key);
+    let leak = LSB(5);
+    let signatures = leaky_signatures::<Toy48Curve>(&private_key, 14, leak).unwrap();
+    let reduction = Reduction::Bkz { block_size: 8 };
+    let recovered = recover_private_key(&public_key, &signatures, leak, reduction);
+    assert_eq!(recovered, Ok(private_key));
+}
+
+#[test]
+fn test_secp256k1_biased_nonces() {
+    let private_key = FrSecp256k1::rand(&mut thread_rng());
+    let public_key = Secp256k1Curve::generator().mul_scalar(&private_key);
+    for leak in [MSB(32), LSB(32)] {
+        let count = required_signatures::<Secp256k1Curve>(leak.bits).unwrap();
+        let signatures = biased_signatures::<Secp256k1Curve>(&private_key, count, leak).unwrap();
+        let recovered = recover_private_key(&public_key, &signatures, leak, Reduction::Lll);
+        assert_eq!(recovered, Ok(private_key));
+    }
+}
+
+#[test]
+#[ignore = "reduces a 45-dimensional lattice, minutes even in release builds"]
+fn test_secp256k1_eight_leaked_bits() {
+    let private_key = FrSecp256k1::rand(&mut thread_rng());
+    let public_key = Secp256k1Curve::generator().mul_scalar(&private_key);
+    let leak = MSB(8);
+    let signatures = leaky_signatures::<Secp256k1Curve>(&private_key, 44, leak).unwrap();
+    let reduction = Reduction::Lll;
+    let recovered = recover_private_key(&public_key, &signatures, leak, reduction);
+    assert_eq!(recovered, Ok(private_key));
+}
+
+#[test]
+fn test_rejects_too_few_signatures() {
+    let private_key = FrToy48::rand(&mut thread_rng());
+    let public_key = Toy48Curve::generator().mul_scalar(&private_key);
+    let leak = MSB(4);
+    let signatures = biased_signatures::<Toy48Curve>(&private_key, 3, leak).unwrap();
+    assert!(matches!(
+        recover_private_key(&public_key, &signatures, leak, Reduction::Lll),
+        Err(HnpError::TooFewSignatures { have: 3, leaked: 12, .. })
+    ));
+}
+
+#[test]
+fn test_unbiased_nonces_give_nothing() {
+    // the generator claims 6 zero bits, but the nonces are uniform
+    let private_key = FrToy48::rand(&mut thread_rng());
+    let public_key = Toy48Curve::generator().mul_scalar(&private_key);
+    let mut signatures = leaky_signatures::<Toy48Curve>(&private_key, 12, MSB(6)).unwrap();
+    for signature in &mut signatures {
+        signature.leaked = 0u32.into();
+    }
+    let recovered = recover_private_key(&public_key, &signatures, MSB(6), Reduction::Lll);
+    assert_eq!(recovered, Err(HnpError::NotFound));
+}
+
+#[test]
+fn test_rejects_invalid_leaks() {
+    let private_key = FrToy48::rand(&mut thread_rng());
+    let public_key = Toy48Curve::generator().mul_scalar(&private_key);
+    let signatures = biased_signatures::<Toy48Curve>(&private_key, 12, MSB(6)).unwrap();
+    for leak in [MSB(0), LSB(0), MSB(48), LSB(48), MSB(u32::MAX)] {
+        assert_eq!(
+            recover_private_key(&public_key, &signatures, leak, Reduction::Lll),
+            Err(HnpError::InvalidLeak { bits: leak.bits, n_bits: 48 })
+        );
+    }
+    assert_eq!(
+        biased_signatures::<Toy48Curve>(&private_key, 1, MSB(48)).err(),
+        Some(HnpError::InvalidLeak { bits: 48, n_bits: 48 })
+    );
+    assert_eq!(
+        leaky_signatures::<Toy48Curve>(&private_key, 1, LSB(0)).err(),
+        Some(HnpError::InvalidLeak { bits: 0, n_bits: 48 })
+    );
+    assert_eq!(required_signatures::<Toy48Curve>(0), None);
+    assert_eq!(required_signatures::<Toy48Curve>(48), None);
+}
@@ -0,0 +1,135 @@
+use crate::attacks::invalid_curve::{
+    InvalidCurveError,
+    Victim,
+    invalid_curves,
+    j_zero_orders,
+    low_order_points,
+    recover_private_key,
+};
+use crate::attacks::torsion::bounded_exponent;
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::{FqSecp256k1, Secp256k1Curve};
+use crate::curves::toy::toy::{Toy16Curve, Toy40Curve};
+use crate::curves::weak::weak::SupersingularCurve;
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+use crate::protocols::keys::SecretKey;
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+use std::str::FromStr;
+
+fn biguint(value: &str) -> BigUint {
+    BigUint::from_str(value).unwrap()
+}
+
+#[test]
+fn test_j_zero_orders_of_toy16() {
+    // the six classes of y^2 = x^3 + b over F_41479, 41233 being Toy16's
+    let orders: Vec<u64> = j_zero_orders::<Toy16Curve>()
+        .iter()
+        .map(|order| order.try_into().unwrap())
+        .collect();
+    assert_eq!(orders, vec![41076, 41233, 41323, 41637, 41727, 41884]);
+}
+
+#[test]
+fn test_j_zero_orders_of_secp256k1() {
+    let orders = j_zero_orders::<Secp256k1Curve>();
+    assert_eq!(orders.len(), 6);
+    assert!(orders.contains(&Secp256k1Curve::order().into()));
+    // y^2 = x^3 + 1, whose order is 4 * 3 * 20412485227 * (large prime)
+    assert!(orders.contains(&biguint(
+        "115792089237316195423570985008687907852598652813156864395638497411212089444244"
+    )));
+}
+
+#[test]
+fn test_invalid_curves_cover_the_other_classes() {
+    let curves = invalid_curves::<Toy16Curve>().unwrap();
+    assert_eq!(curves.len(), 5);
+    for curve in &curves {
+        assert_ne!(curve.b, Toy16Curve::b());
+        assert_ne!(curve.order, Toy16Curve::order().into());
+    }
+}
+
+#[test]
+fn test_invalid_curves_need_a_zero() {
+    // y^2 = x^3 + x
+    assert!(matches!(
+        invalid_curves::<SupersingularCurve>(),
+        Err(InvalidCurveError::UnsupportedCurve)
+    ));
+}
+
+#[test]
+fn test_low_order_points_are_off_curve() {
+    for low in low_order_points::<Toy40Curve>(1 << 12).unwrap() {
+        assert!(!Toy40Curve::is_on_curve(&low.point.inner));
+        assert!(low.point.mul_biguint(&BigUint::from(low.order)).inner.is_infinity());
+        assert!(low.order <= 1 << 12);
+    }
+}
+
+#[test]
+fn test_bounded_exponent_does_not_overflow() {
+    assert_eq!(bounded_exponent(2, 200, u64::MAX), 63);
+    assert_eq!(bounded_exponent(3, 5, 1 << 12), 5);
+    assert_eq!(bounded_exponent(3, 9, 1 << 12), 7);
+    assert_eq!(bounded_exponent(65537, 3, 1 << 12), 0);
+}
+
+#[test]
+fn test_recovers_toy40_key() {
+    let private_key = *SecretKey::<Toy40Curve>::random().expose_scalar();
+    let victim = Victim::<Toy40Curve>::new(private_key);
+    let points = low_order_points::<Toy40Curve>(1 << 16).unwrap();
+
+    let recovery = recover_private_key(&victim, &points).unwrap();
+    let order: BigUint = Toy40Curve::order().into();
+    let key: BigUint = private_key.into_bigint().into();
+    assert_eq!(recovery.private_key(&order), Some(key));
+    assert_eq!(recovery.queries, points.len());
+}
+
+#[test]
+fn test_recovers_secp256k1_key_partially() {
+    let private_key = *SecretKey::<Secp256k1Curve>::random().expose_scalar();
+    let victim = Victim::<Secp256k1Curve>::new(private_key);
+    let points = low_order_points::<Secp256k1Curve>(1 << 12).unwrap();
+
+    // the small factors only reach a part of the 256-bit key
+    let recovery = recover_private_key(&victim, &points).unwrap();
+    let key: BigUint = private_key.into_bigint().into();
+    assert_eq!(recovery.residue, &key % &recovery.modulus);
+    assert!(recovery.modulus.bits() > 20);
+    assert_eq!(recovery.private_key(&Secp256k1Curve::order().into()), None);
+}
+
+#[test]
+fn test_hardened_victim_rejects_invalid_points() {
+    let private_key = *SecretKey::<Toy40Curve>::random().expose_scalar();
+    let victim = Victim::<Toy40Curve>::hardened(private_key);
+    let points = low_order_points::<Toy40Curve>(1 << 12).unwrap();
+
+    assert_eq!(
+        recover_private_key(&victim, &points),
+        Err(InvalidCurveError::Rejected(EcdhError::NotOnCurve))
+    );
+    // an honest peer still gets an answer
+    let peer = SecretKey::<Toy40Curve>::random().public_key().to_point();
+    assert!(victim.respond(&peer).is_ok());
+}
+
+#[test]
+fn test_checked_ecdh_rejects_secp256k1_twist_point() {
+    let points = low_order_points::<Secp256k1Curve>(1 << 8).unwrap();
+    let private_key = *SecretKey::<Secp256k1Curve>::random().expose_scalar();
+    for low in &points {
+        assert_ne!(low.b, FqSecp256k1::from(7u64));
+        assert_eq!(
+            Ecdh::<Secp256k1Curve>::compute_shared_secret_checked(&private_key, &low.point),
+            Err(EcdhError::NotOnCurve)
+        );
+    }
+}
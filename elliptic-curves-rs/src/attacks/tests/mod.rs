@@ -0,0 +1,6 @@
+pub mod dual_ec;
+pub mod ecdsa_nonce_reuse;
+pub mod fault;
+pub mod hnp;
+pub mod invalid_curve;
+pub mod twist;
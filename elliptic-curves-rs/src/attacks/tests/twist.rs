@@ -0,0 +1,144 @@
+use crate::attacks::twist::{
+    TwistError,
+    TwistRecovery,
+    Victim,
+    curve_order,
+    recover_key_bits,
+    report,
+    twist_order,
+    twist_points,
+    twist_security,
+};
+use crate::core::curve::Curve;
+use crate::core::ladder::{is_on_twist, ladder};
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::curves::toy::toy::{Toy16Curve, Toy20Curve};
+use crate::curves::weak::weak::{CuspCurve, SupersingularCurve};
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+use crate::protocols::keys::SecretKey;
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+use std::str::FromStr;
+
+fn big(n: u64) -> BigUint {
+    BigUint::from(n)
+}
+
+#[test]
+fn test_twist_orders() {
+    assert_eq!(curve_order::<Toy16Curve>(), Ok(big(41233)));
+    assert_eq!(twist_order::<Toy16Curve>(), Ok(big(41727)));
+    // cofactor 4, and p = 3 mod 4 makes the twist as large as the curve
+    assert_eq!(curve_order::<SupersingularCurve>(), Ok(big(4461229988)));
+    assert_eq!(twist_order::<SupersingularCurve>(), Ok(big(4461229988)));
+    assert_eq!(twist_order::<CuspCurve>(), Err(TwistError::Singular));
+
+    let secp256k1 = twist_security::<Secp256k1Curve>("secp256k1").unwrap();
+    let small: Vec<(u64, u32)> = secp256k1.twist_factors[..4]
+        .iter()
+        .map(|(prime, exponent)| (prime.try_into().unwrap(), *exponent))
+        .collect();
+    assert_eq!(small, vec![(3, 2), (13, 2), (3319, 1), (22639, 1)]);
+    assert_eq!(secp256k1.largest_twist_prime(), &BigUint::from_str(
+        "1013176677300131846900870239606035638738100997248092069256697437031"
+    ).unwrap());
+}
+
+#[test]
+fn test_twist_security_report() {
+    let report = report();
+    let score = |name: &str| {
+        report.iter()
+            .find(|(entry, _)| *entry == name)
+            .and_then(|(_, security)| security.as_ref().ok())
+            .map(|security| security.score())
+            .unwrap()
+    };
+    assert!(score("secp256k1") > 0.8);
+    // #E' = 3 * 5 * 13 * 179 * (241-bit prime)
+    assert!(score("P-256") > 0.9);
+    // #E' = 3^4 * 7^2 * 163
+    assert!(score("toy20") < 0.4);
+
+    let singular: Vec<_> = report.iter()
+        .filter(|(_, security)| *security == Err(TwistError::Singular))
+        .map(|(name, _)| *name)
+        .collect();
+    assert_eq!(singular, vec!["cusp", "split node", "non-split node"]);
+    for (_, security) in &report {
+        if let Ok(security) = security {
+            assert!(!security.to_string().is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_twist_points_have_small_order() {
+    let points = twist_points::<Toy16Curve>(1 << 12).unwrap();
+    let orders: Vec<u64> = points.iter().map(|point| point.order).collect();
+    assert_eq!(orders, vec![3, 7, 1987]);
+    for point in &points {
+        assert!(is_on_twist::<Toy16Curve>(&point.x));
+        assert_eq!(ladder::<Toy16Curve>(&point.x, &big(point.order)), None);
+    }
+}
+
+#[test]
+fn test_recovers_toy20_key_through_the_twist() {
+    let private_key = *SecretKey::<Toy20Curve>::random().expose_scalar();
+    let victim = Victim::<Toy20Curve>::new(private_key);
+    let points = twist_points::<Toy20Curve>(1 << 10).unwrap();
+
+    // #E' = 3^4 * 7^2 * 163, but its 3-part is Z/9 x Z/9
+    let recovery = recover_key_bits(&victim, &points).unwrap();
+    assert_eq!(recovery.modulus(), big(9 * 49 * 163));
+
+    // x-only keys are only defined up to sign
+    let n = big(649633);
+    let key: BigUint = private_key.into_bigint().into();
+    let found = recovery.private_key::<Toy20Curve>(&victim.public_key_x().unwrap()).unwrap();
+    assert!(found == key || found == &n - &key);
+}
+
+#[test]
+fn test_recovers_secp256k1_key_bits() {
+    let private_key = *SecretKey::<Secp256k1Curve>::random().expose_scalar();
+    let victim = Victim::<Secp256k1Curve>::new(private_key);
+    let points = twist_points::<Secp256k1Curve>(1 << 12).unwrap();
+
+    let recovery = recover_key_bits(&victim, &points).unwrap();
+    let key: BigUint = private_key.into_bigint().into();
+    // the twist has full 3-torsion, so 3^2 | #E' only gives d mod 3
+    assert_eq!(recovery.modulus(), big(3 * 169 * 3319));
+    assert!(recovery.candidates().contains(&(&key % recovery.modulus())));
+    assert!(recovery.bits() > 15.0);
+    assert_eq!(recovery.private_key::<Secp256k1Curve>(&victim.public_key_x().unwrap()), None);
+}
+
+#[test]
+fn test_hardened_x_only_victim() {
+    let private_key = *SecretKey::<Toy20Curve>::random().expose_scalar();
+    let victim = Victim::<Toy20Curve>::hardened(private_key);
+    let points = twist_points::<Toy20Curve>(1 << 10).unwrap();
+    assert_eq!(
+        recover_key_bits(&victim, &points),
+        Err(TwistError::Rejected(EcdhError::OnTwist))
+    );
+
+    let other_private_key = *SecretKey::<Toy20Curve>::random().expose_scalar();
+    let other_x = Ecdh::<Toy20Curve>::public_key_x(&other_private_key).unwrap();
+    assert_eq!(
+        victim.respond(&other_x),
+        Victim::<Toy20Curve>::new(private_key).respond(&other_x)
+    );
+    assert_eq!(Toy20Curve::order(), <Toy20Curve as Curve>::ScalarField::MODULUS);
+}
+
+#[test]
+fn test_candidates_cover_both_signs() {
+    let recovery = TwistRecovery { residues: vec![(big(1), big(3)), (big(0), big(7)), (big(2), big(5))] };
+    let candidates = recovery.candidates();
+    // 1 or 2 mod 3, 0 mod 7, 2 or 3 mod 5
+    assert_eq!(candidates, vec![big(7), big(28), big(77), big(98)]);
+}
@@ -0,0 +1,49 @@
+//! 작은 소수 거듭제곱 위수의 원소. 이런 원소를 피해자에게 보내고 그 위수를
+//! 법으로 키를 읽어 내는 공격들이 쓴다
+
+use num_bigint::BigUint;
+
+/// 작은 거듭제곱으로 물러서기 전에 소수 거듭제곱마다 시도하는 임의 원소 수
+const MAX_POINT_ATTEMPTS: usize = 32;
+
+/// prime^t <= `max_order`인 가장 큰 t <= `exponent`. 지수가 커도 overflow하지 않는다
+pub(crate) fn bounded_exponent(prime: u64, exponent: u32, max_order: u64) -> u32 {
+    (0..=exponent).rev().find(|t| prime.checked_pow(*t).is_some_and(|q| q <= max_order)).unwrap_or(0)
+}
+
+/// 위수 prime^e의 원소, e는 군이 허락하는 한 `target`에 가깝게. `order`의
+/// prime 부분은 순환군이 아닐 수 있어서 (예: 2-torsion 전체) 그 지수가
+/// `order`에서 prime의 지수인 `exponent`보다 작을 수 있다. `mul`은
+/// 항등원이면 None
+pub(crate) fn element_of_order<T>(
+    order: &BigUint,
+    prime: u64,
+    exponent: u32,
+    target: u32,
+    mut random: impl FnMut() -> T,
+    mul: impl Fn(&T, &BigUint) -> Option<T>,
+) -> Option<(T, u32)> {
+    let prime_free = order / BigUint::from(prime).pow(exponent);
+    let prime = BigUint::from(prime);
+    let mut best: Option<(T, u32)> = None;
+    for _ in 0..MAX_POINT_ATTEMPTS {
+        let Some(element) = mul(&random(), &prime_free) else {
+            continue;
+        };
+        // element has order prime^e
+        let mut e = 1;
+        let mut multiple = mul(&element, &prime);
+        while let Some(next) = multiple {
+            multiple = mul(&next, &prime);
+            e += 1;
+        }
+        if e >= target {
+            let element = mul(&element, &prime.pow(e - target)).expect("order prime^target");
+            return Some((element, target));
+        }
+        if best.as_ref().is_none_or(|(_, best)| e > *best) {
+            best = Some((element, e));
+        }
+    }
+    best
+}
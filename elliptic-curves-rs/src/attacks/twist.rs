@@ -0,0 +1,350 @@
+//! x만 쓰는 ECDH에 대한 twist 공격
+//!
+//! ladder는 y를 보지 않으므로 F_p의 어떤 x든 받는다. x^3 + ax + b가 제곱수가
+//! 아니면 x는 quadratic twist E': d y^2 = x^3 + ax + b (d는 비이차잉여)에
+//! 속하고, 피해자는 대신 E' 위에서 x(kP)를 계산한다. 이때
+//!
+//! ```text
+//! #E + #E' = 2(p + 1)
+//! ```
+//!
+//! #E'은 아무도 고르지 않았으므로 보통 smooth하다. #E'을 나누는 작은 소수
+//! 거듭제곱 q마다 공격자는 위수 q인 점의 x를 보내고 답을 k <= q / 2의
+//! x(kP)와 맞춰 본다. 그러면 q를 법으로 부호까지의 키를 얻는다
+//! (x(kP) = x(-kP)). 부호는 공개키를 재현하는 CRT 후보로 가려낸다.
+//!
+//! 곡선의 twist 안전성은 #E'을 나누는 가장 큰 소수 l의 rho 비용 sqrt(l),
+//! 즉 작은 인수를 뺀 뒤 남는 일을 곡선 자체의 sqrt(n)과 비교한 것이다.
+
+use crate::attacks::torsion::{bounded_exponent, element_of_order};
+use crate::core::curve::Curve;
+use crate::core::integer::{crt, factorize};
+use crate::core::ladder::{is_on_twist, ladder};
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::curves::toy::toy::{
+    Toy16Curve,
+    Toy20Curve,
+    Toy24Curve,
+    Toy28Curve,
+    Toy32Curve,
+    Toy40Curve,
+    Toy48Curve,
+    Toy56Curve,
+};
+use crate::curves::weak::weak::{
+    AnomalousCurve,
+    CuspCurve,
+    NonSplitNodeCurve,
+    SplitNodeCurve,
+    SupersingularCurve,
+};
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{ToPrimitive, Zero};
+use rand::thread_rng;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use std::fmt;
+
+/// `TwistRecovery::private_key`가 후보와 합동인 키를 아직 훑어보는 가장
+/// 큰 n / modulus
+const MAX_LIFTS: u64 = 1 << 16;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TwistError {
+    #[error("the curve is singular and has no twist in this sense")]
+    Singular,
+    #[error("the order n has several multiples in the Hasse interval")]
+    UnknownOrder,
+    #[error("the victim rejected the x-coordinate: {0}")]
+    Rejected(#[from] EcdhError),
+    #[error("no k up to {order} / 2 reproduces the victim's response")]
+    NoMatch { order: u64 },
+}
+
+fn modulus<C: Curve>() -> BigUint {
+    <C::BaseField as ArkPrimeField>::MODULUS.into()
+}
+
+/// #E(F_p): Hasse 구간 p + 1 - 2 sqrt(p) <= #E <= p + 1 + 2 sqrt(p)에 있는
+/// 유일한 n의 배수
+pub fn curve_order<C: Curve>() -> Result<BigUint, TwistError> {
+    if C::is_singular() {
+        return Err(TwistError::Singular);
+    }
+    let p = modulus::<C>();
+    let n: BigUint = C::order().into();
+    // 2 sqrt(p) rounded up, so the interval is never too narrow
+    let four_p: BigUint = &p << 2;
+    let width = four_p.sqrt() + 1u32;
+    let low = &p + 1u32 - &width;
+    let high = &p + 1u32 + &width;
+
+    let first = (&low + &n - 1u32) / &n;
+    let last = &high / &n;
+    if first != last {
+        return Err(TwistError::UnknownOrder);
+    }
+    Ok(first * n)
+}
+
+/// #E'(F_p) = 2(p + 1) - #E(F_p)
+pub fn twist_order<C: Curve>() -> Result<BigUint, TwistError> {
+    Ok((modulus::<C>() + 1u32) * 2u32 - curve_order::<C>()?)
+}
+
+/// 곡선 하나의 twist의 위수와 소인수분해
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwistSecurity {
+    pub name: &'static str,
+    pub curve_order: BigUint,
+    pub subgroup_order: BigUint,
+    pub twist_order: BigUint,
+    pub twist_factors: Vec<(BigUint, u32)>,
+}
+
+fn rho_bits(order: &BigUint) -> f64 {
+    order.to_f64().map_or(0.0, |order| order.log2() / 2.0)
+}
+
+impl TwistSecurity {
+    pub fn largest_twist_prime(&self) -> &BigUint {
+        &self.twist_factors.last().expect("the twist order is at least 2").0
+    }
+
+    /// #E'을 나누는 가장 큰 소수 l의 log2 sqrt(l)
+    pub fn twist_bits(&self) -> f64 {
+        rho_bits(self.largest_twist_prime())
+    }
+
+    /// 곡선 자체에서의 rho 비용 log2 sqrt(n)
+    pub fn curve_bits(&self) -> f64 {
+        rho_bits(&self.subgroup_order)
+    }
+
+    /// twist_bits / curve_bits, 1이 상한. 1이면 검증 없는 x-only 구현도 잃는 것이
+    /// 없다
+    pub fn score(&self) -> f64 {
+        (self.twist_bits() / self.curve_bits()).min(1.0)
+    }
+}
+
+impl fmt::Display for TwistSecurity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let factors: Vec<String> = self.twist_factors
+            .iter()
+            .map(|(prime, exponent)| match exponent {
+                1 => format!("{}", prime),
+                _ => format!("{}^{}", prime, exponent),
+            })
+            .collect();
+        write!(
+            f,
+            "{}: twist {:.1} bits / curve {:.1} bits, score {:.2}, #E' = {}",
+            self.name,
+            self.twist_bits(),
+            self.curve_bits(),
+            self.score(),
+            factors.join(" * "),
+        )
+    }
+}
+
+pub fn twist_security<C: Curve>(name: &'static str) -> Result<TwistSecurity, TwistError> {
+    let twist_order = twist_order::<C>()?;
+    Ok(TwistSecurity {
+        name,
+        curve_order: curve_order::<C>()?,
+        subgroup_order: C::order().into(),
+        twist_factors: factorize(&twist_order),
+        twist_order,
+    })
+}
+
+/// `curves`의 모든 곡선의 twist 안전성
+pub fn report() -> Vec<(&'static str, Result<TwistSecurity, TwistError>)> {
+    fn entry<C: Curve>(name: &'static str) -> (&'static str, Result<TwistSecurity, TwistError>) {
+        (name, twist_security::<C>(name))
+    }
+    vec![
+        entry::<Secp256k1Curve>("secp256k1"),
+        entry::<P256Curve>("P-256"),
+        entry::<Toy16Curve>("toy16"),
+        entry::<Toy20Curve>("toy20"),
+        entry::<Toy24Curve>("toy24"),
+        entry::<Toy28Curve>("toy28"),
+        entry::<Toy32Curve>("toy32"),
+        entry::<Toy40Curve>("toy40"),
+        entry::<Toy48Curve>("toy48"),
+        entry::<Toy56Curve>("toy56"),
+        entry::<AnomalousCurve>("anomalous"),
+        entry::<SupersingularCurve>("supersingular"),
+        entry::<CuspCurve>("cusp"),
+        entry::<SplitNodeCurve>("split node"),
+        entry::<NonSplitNodeCurve>("non-split node"),
+    ]
+}
+
+/// 위수 `order`인 twist 점의 x좌표
+pub struct TwistPoint<C: Curve> {
+    pub x: C::BaseField,
+    pub order: u64,
+}
+
+/// 공유 x좌표의 태그로 답하는 x-only 서버. hardened 서버만 검증한다.
+pub struct Victim<C: Curve> {
+    private_key: C::ScalarField,
+    hardened: bool,
+}
+
+impl<C: Curve> Victim<C> {
+    pub fn new(private_key: C::ScalarField) -> Self {
+        Self { private_key, hardened: false }
+    }
+
+    pub fn hardened(private_key: C::ScalarField) -> Self {
+        Self { private_key, hardened: true }
+    }
+
+    pub fn public_key_x(&self) -> Option<C::BaseField> {
+        Ecdh::<C>::public_key_x(&self.private_key)
+    }
+
+    pub fn respond(&self, x: &C::BaseField) -> Result<[u8; 32], EcdhError> {
+        let shared = if self.hardened {
+            Some(Ecdh::<C>::compute_shared_secret_x_checked(&self.private_key, x)?)
+        } else {
+            Ecdh::<C>::compute_shared_secret_x(&self.private_key, x)
+        };
+        Ok(tag::<C>(shared))
+    }
+}
+
+fn tag<C: Curve>(x: Option<C::BaseField>) -> [u8; 32] {
+    let bytes = x.map_or_else(Vec::new, |x| x.into_bigint().to_bytes_be());
+    Sha256::digest(bytes).into()
+}
+
+fn random_twist_x<C: Curve>() -> C::BaseField {
+    let p = modulus::<C>();
+    let mut rng = thread_rng();
+    loop {
+        let x = C::BaseField::from(rng.gen_biguint_below(&p));
+        if is_on_twist::<C>(&x) {
+            return x;
+        }
+    }
+}
+
+/// 위수 prime^e인 twist 점의 x, `element_of_order` 참고
+fn point_of_order<C: Curve>(
+    twist_order: &BigUint,
+    prime: u64,
+    exponent: u32,
+    target: u32,
+) -> Option<(C::BaseField, u32)> {
+    element_of_order(twist_order, prime, exponent, target, random_twist_x::<C>, ladder::<C>)
+}
+
+/// #E'을 나누는 소수 거듭제곱 q <= `max_order`마다 위수 q인 twist 점의 x
+pub fn twist_points<C: Curve>(max_order: u64) -> Result<Vec<TwistPoint<C>>, TwistError> {
+    let twist_order = twist_order::<C>()?;
+    let mut points = Vec::new();
+    for (prime, exponent) in factorize(&twist_order) {
+        let Some(prime) = prime.to_u64().filter(|prime| *prime <= max_order) else {
+            continue;
+        };
+        let target = bounded_exponent(prime, exponent, max_order);
+        if let Some((x, e)) = point_of_order::<C>(&twist_order, prime, exponent, target) {
+            points.push(TwistPoint { x, order: prime.pow(e) });
+        }
+    }
+    Ok(points)
+}
+
+/// 각 twist 위수를 법으로 부호까지 구한 개인키
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TwistRecovery {
+    /// d = +-k mod q인 (k, q)
+    pub residues: Vec<(BigUint, BigUint)>,
+}
+
+impl TwistRecovery {
+    pub fn modulus(&self) -> BigUint {
+        self.residues.iter().map(|(_, q)| q).product()
+    }
+
+    /// 복원한 비트 수, 즉 modulus의 log2에서 부호마다 하나씩 뺀 값
+    pub fn bits(&self) -> f64 {
+        let signs = self.residues.iter().filter(|(k, q)| !k.is_zero() && k * 2u32 != *q);
+        self.modulus().to_f64().map_or(0.0, f64::log2) - signs.count() as f64
+    }
+
+    /// 부호를 고르는 모든 경우의 d mod `modulus`
+    pub fn candidates(&self) -> Vec<BigUint> {
+        let mut combinations: Vec<Vec<(BigUint, BigUint)>> = vec![Vec::new()];
+        for (k, q) in &self.residues {
+            let negated = (q - k) % q;
+            let signs = if negated == *k { vec![k.clone()] } else { vec![k.clone(), negated] };
+            combinations = combinations
+                .iter()
+                .flat_map(|combination| {
+                    signs.iter().map(move |residue| {
+                        let mut extended = combination.clone();
+                        extended.push((residue.clone(), q.clone()));
+                        extended
+                    })
+                })
+                .collect();
+        }
+        let mut candidates: Vec<BigUint> = combinations
+            .iter()
+            .map(|combination| crt(combination).expect("coprime orders").0)
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+        candidates
+    }
+
+    /// `public_x`를 재현하는 키 d' = +-d mod n. modulus가 각 후보와 합동인 키를
+    /// 훑어볼 수 있을 만큼 커야 한다
+    pub fn private_key<C: Curve>(&self, public_x: &C::BaseField) -> Option<BigUint> {
+        let n: BigUint = C::order().into();
+        let modulus = self.modulus();
+        if &n / &modulus > BigUint::from(MAX_LIFTS) {
+            return None;
+        }
+        let g = C::generator().inner.x().expect("generator is affine");
+        for candidate in self.candidates() {
+            let mut key = candidate;
+            while key < n {
+                if ladder::<C>(&g, &key).as_ref() == Some(public_x) {
+                    return Some(key);
+                }
+                key += &modulus;
+            }
+        }
+        None
+    }
+}
+
+/// 모든 twist x를 피해자에게 보내고 돌아온 태그에 k = 0, ..., q / 2를 맞춰
+/// 각 위수를 법으로 부호까지의 d를 찾는다.
+pub fn recover_key_bits<C: Curve>(
+    victim: &Victim<C>,
+    points: &[TwistPoint<C>],
+) -> Result<TwistRecovery, TwistError> {
+    let mut residues = Vec::new();
+    for point in points {
+        let response = victim.respond(&point.x)?;
+        let k = (0..=point.order / 2)
+            .find(|k| tag::<C>(ladder::<C>(&point.x, &BigUint::from(*k))) == response)
+            .ok_or(TwistError::NoMatch { order: point.order })?;
+        residues.push((BigUint::from(k), BigUint::from(point.order)));
+    }
+    Ok(TwistRecovery { residues })
+}
@@ -0,0 +1,90 @@
+//! Runs the DLP solvers on the toy curves and prints a CSV or JSON report
+//!
+//! cargo run --release --bin dlp_complexity -- \
+//!     --max-bits 32 --runs 5 --format json --output report.json
+
+use elliptic_curves_rs::breaking_dlp::complexity::{Algorithm, Experiment};
+
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: dlp_complexity [--max-bits N] [--runs N] \
+[--algorithms a,b,..] [--max-steps N] [--format csv|json] [--output FILE]";
+
+fn parse_args() -> Result<(Experiment, String, Option<String>), String> {
+    let mut max_bits = 28;
+    let mut runs = 3;
+    let mut algorithms = Algorithm::ALL.to_vec();
+    let mut max_steps = None;
+    let mut format = "csv".to_string();
+    let mut output = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {}", flag));
+        match flag.as_str() {
+            "--max-bits" => max_bits = value()?.parse().map_err(|e| format!("{}", e))?,
+            "--runs" => runs = value()?.parse().map_err(|e| format!("{}", e))?,
+            "--max-steps" => {
+                max_steps = Some(value()?.parse().map_err(|e| format!("{}", e))?);
+            }
+            "--algorithms" => {
+                algorithms = value()?
+                    .split(',')
+                    .map(|name| {
+                        Algorithm::from_name(name)
+                            .ok_or(format!("unknown algorithm `{}`", name))
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "--format" => format = value()?,
+            "--output" => output = Some(value()?),
+            _ => return Err(format!("unknown argument `{}`", flag)),
+        }
+    }
+    if format != "csv" && format != "json" {
+        return Err(format!("unknown format `{}`", format));
+    }
+
+    let mut experiment = Experiment::new(max_bits, runs).with_algorithms(&algorithms);
+    if let Some(max_steps) = max_steps {
+        experiment = experiment.with_max_steps(max_steps);
+    }
+    Ok((experiment, format, output))
+}
+
+fn main() -> ExitCode {
+    let (experiment, format, output) = match parse_args() {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = experiment.run();
+    for fit in &report.fits {
+        eprintln!(
+            "{:<22} runs={:<3} steps ~ n^{:.3}  steps/n={:.4}  steps/sqrt(n)={:.4}",
+            fit.algorithm.name(),
+            fit.runs,
+            fit.exponent,
+            fit.steps_per_n,
+            fit.steps_per_sqrt_n,
+        );
+    }
+
+    let text = match format.as_str() {
+        "json" => report.to_json(),
+        _ => report.to_csv(),
+    };
+    match output {
+        Some(path) => {
+            if let Err(err) = std::fs::write(&path, text) {
+                eprintln!("cannot write {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", text),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,65 @@
+//! Dual_EC_DRBG backdoor demo on secp256k1 and P-256: picks Q = eP,
+//! watches one output block and predicts everything after it
+//!
+//! cargo run --release --bin dual_ec_backdoor -- --truncate 16
+
+use elliptic_curves_rs::attacks::dual_ec::{backdoored_q, recover_state};
+use elliptic_curves_rs::core::curve::Curve;
+use elliptic_curves_rs::curves::p256::p256::P256Curve;
+use elliptic_curves_rs::curves::secp256k1::secp256k1::Secp256k1Curve;
+use elliptic_curves_rs::protocols::dual_ec_drbg::{DualEcDrbg, TRUNCATED_BITS};
+
+use num_bigint::RandBigInt;
+use rand::thread_rng;
+
+use std::process::ExitCode;
+use std::time::Instant;
+
+const USAGE: &str = "usage: dual_ec_backdoor [--truncate BITS]";
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn demo<C: Curve>(name: &str, truncated_bits: u32) -> bool {
+    let p = C::generator();
+    let backdoor = backdoored_q(&p);
+    let seed = thread_rng().gen_biguint(256);
+    let mut victim = DualEcDrbg::with_truncation(p.clone(), backdoor.q.clone(), &seed, truncated_bits);
+    let block_len = victim.block_len();
+
+    let output = victim.generate(3 * block_len);
+    let (block, rest) = output.split_at(block_len);
+    println!("{}: observed block {}", name, hex(block));
+
+    let start = Instant::now();
+    let Some(mut attacker) = recover_state(&p, &backdoor, truncated_bits, block, &rest[..4]) else {
+        println!("{}: state not found", name);
+        return false;
+    };
+    println!("{}: state {:x} after {:.1?}", name, attacker.state(), start.elapsed());
+
+    let predicted = attacker.generate(rest.len());
+    let next_call = (attacker.generate(32), victim.generate(32));
+    println!("{}: predicted  {}", name, hex(&predicted[..block_len]));
+    println!("{}: next call  {}", name, hex(&next_call.0));
+    predicted == rest && next_call.0 == next_call.1
+}
+
+fn main() -> ExitCode {
+    let mut truncated_bits = TRUNCATED_BITS;
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        match (flag.as_str(), args.next().map(|value| value.parse())) {
+            ("--truncate", Some(Ok(bits))) if bits <= 24 => truncated_bits = bits,
+            _ => {
+                eprintln!("{}", USAGE);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let secp256k1 = demo::<Secp256k1Curve>("secp256k1", truncated_bits);
+    let p256 = demo::<P256Curve>("P-256", truncated_bits);
+    if secp256k1 && p256 { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
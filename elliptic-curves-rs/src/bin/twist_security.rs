@@ -0,0 +1,14 @@
+//! Prints the twist security of every curve in `curves`
+//!
+//! cargo run --release --bin twist_security
+
+use elliptic_curves_rs::attacks::twist::report;
+
+fn main() {
+    for (name, security) in report() {
+        match security {
+            Ok(security) => println!("{}", security),
+            Err(err) => println!("{}: {}", name, err),
+        }
+    }
+}
@@ -9,22 +9,77 @@
 //! Therefore, BSGS is not suitable to break DLP in real world.
 
 use crate::breaking_dlp::DiscreteLog;
-use crate::core::curve::Curve;
-use crate::core::point::CurvePoint;
-
-// TODO: Implement the Baby-step Giant-step (BSGS) algorithm for
-// solving the Discrete Logarithm Problem. This requires defining
-// the necessary imports and implementing the `DiscreteLog` trait for
-// the `BabyStepGiantStep` struct. Note that this algorithm is
-// currently impractical for real-world cryptographic curves due to its
-// high memory and time complexity.
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+};
+use crate::core::group::Group;
+
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+
+use std::collections::HashMap;
+
 pub struct BabyStepGiantStep;
 
-impl<C: Curve> DiscreteLog<C> for BabyStepGiantStep {
-    fn solve(
-            p: &CurvePoint<C>,
-            q: &CurvePoint<C>,
-        ) -> (u64, <C as Curve>::ScalarField) {
-        unimplemented!()
+impl<G: Group> DiscreteLog<G> for BabyStepGiantStep {
+    fn solve_with_order(
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
+
+        let mut run = SolverRun::new(config);
+
+        // m = ceil(sqrt(n)), so that k = i * m + j with 0 <= i, j < m
+        let mut m = order.sqrt();
+        if &m * &m < *order {
+            m += 1u32;
+        }
+        let Some(m) = m.to_u64() else {
+            return Ok(run.exhausted(Exhaustion::SearchSpace));
+        };
+
+        // baby steps: table of P^j for 0 <= j < m
+        let mut table: HashMap<Vec<u8>, u64> = HashMap::new();
+        let mut baby = G::identity();
+        for j in 0..m {
+            if let Some(outcome) = run.check() {
+                return Ok(outcome);
+            }
+            table.entry(baby.encode()).or_insert(j);
+            baby = baby.op(p);
+            run.add_group_operations(1);
+            run.step();
+        }
+
+        run.set_stored_elements(table.len() as u64);
+
+        // giant steps: Q * P^(-im) for 0 <= i < m
+        // after the loop above, baby = P^m
+        let giant = baby.inverse();
+        let mut gamma = q.clone();
+        for i in 0..m {
+            if let Some(outcome) = run.check() {
+                return Ok(outcome);
+            }
+            if let Some(&j) = table.get(&gamma.encode()) {
+                let k = (BigUint::from(i) * m + j) % order;
+                return Ok(run.found(k));
+            }
+            gamma = gamma.op(&giant);
+            run.add_group_operations(1);
+            run.step();
+        }
+
+        // Q is not in the subgroup generated by P
+        Ok(run.exhausted(Exhaustion::SearchSpace))
     }
-}
\ No newline at end of file
+}
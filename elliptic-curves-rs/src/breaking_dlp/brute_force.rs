@@ -1,40 +1,122 @@
-use crate::breaking_dlp::DiscreteLog;
-use crate::core::curve::Curve;
-use crate::core::point::CurvePoint;
-
-use ark_ff::One;
-use ark_std::{
-    UniformRand,
-    rand::thread_rng,
+use crate::breaking_dlp::{DiscreteLog, ResumableDiscreteLog};
+use crate::breaking_dlp::checkpoint::{Checkpoint, parse_values};
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
 };
+use crate::core::group::Group;
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+
+use std::path::Path;
 
 pub struct BruteForce;
 
+const SOLVER_NAME: &str = "brute_force";
+
+fn save_checkpoint<G: Group>(
+    path: &Path,
+    p: &G,
+    q: &G,
+    order: &BigUint,
+    start: &BigUint,
+    current: &BigUint,
+    run: &SolverRun<'_>,
+) -> Result<(), DlpError> {
+    let mut checkpoint = Checkpoint::new(SOLVER_NAME, p, q, order, run.stats());
+    checkpoint.push("search", &[start, current]);
+    checkpoint.write(path)?;
+    Ok(())
+}
+
+/// Tries `current`, `current + 1`, ... until `p^current = q` or the walk
+/// comes back to `start`.
+fn search<G: Group>(
+    p: &G,
+    q: &G,
+    order: &BigUint,
+    start: BigUint,
+    mut current: BigUint,
+    mut run: SolverRun<'_>,
+) -> Result<DlpOutcome<BigUint>, DlpError> {
+    // result = p^current, updated by one group operation per step
+    let start_point = p.pow(&start);
+    let mut result = p.pow(&current);
+    run.add_group_operations(pow_cost(&start) + pow_cost(&current));
+    run.set_stored_elements(2);
+
+    loop {
+        if let Some(outcome) = run.check() {
+            if let Some(path) = run.checkpoint_path() {
+                save_checkpoint(path, p, q, order, &start, &current, &run)?;
+            }
+            return Ok(outcome);
+        }
+        if result == *q {
+            return Ok(run.found(current));
+        }
+
+        current = (current + 1u32) % order;
+        result = result.op(p);
+        run.add_group_operations(1);
+        run.step();
+
+        // walked around the whole subgroup generated by P
+        if result == start_point {
+            return Ok(run.exhausted(Exhaustion::SearchSpace));
+        }
+
+        if run.checkpoint_due()
+            && let Some(path) = run.checkpoint_path()
+        {
+            save_checkpoint(path, p, q, order, &start, &current, &run)?;
+        }
+    }
+}
+
 /// For breaking discrete logarithm problem using brute-force, it takes
-/// very long time to break. Therefore, in this implementation, I limit
-/// the range of loop for observing the algorithm process.
-impl<C: Curve> DiscreteLog<C> for BruteForce {
-    fn solve(
-            p: &CurvePoint<C>,
-            q: &CurvePoint<C>,
-        ) -> (u64, C::ScalarField) {
-        if !C::is_on_curve(&p.inner) || !C::is_on_curve(&q.inner) {
-            panic!("p or q are not on curve");
+/// very long time to break. Therefore, the search starts at a random
+/// exponent and the range of the loop is bounded by `SolverConfig` for
+/// observing the algorithm process.
+impl<G: Group> DiscreteLog<G> for BruteForce {
+    fn solve_with_order(
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
         }
 
-        let mut rng = thread_rng();
-        let start = C::ScalarField::rand(&mut rng);
-        let mut current = start;
-        let end = u64::MAX;
+        let start = thread_rng().gen_biguint_below(order);
 
-        for steps in 0..end {
-            let result = p.mul_scalar(&current);
-            if result.inner == q.inner {
-                return (steps, current);
-            }
+        search(p, q, order, start.clone(), start, SolverRun::new(config))
+    }
+}
 
-            current += C::ScalarField::one();
+impl<G: Group> ResumableDiscreteLog<G> for BruteForce {
+    fn resume_with_order(
+            checkpoint: &Path,
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
         }
-        panic!("Failed to find discrete logarithm within {} iterations", end);
+
+        let checkpoint = Checkpoint::read(checkpoint)?;
+        checkpoint.verify(SOLVER_NAME, p, q, order)?;
+        let [start, current] = parse_values(checkpoint.record("search")?)?;
+
+        let run = SolverRun::resume(config, checkpoint.stats);
+        search(p, q, order, start, current, run)
     }
-}
\ No newline at end of file
+}
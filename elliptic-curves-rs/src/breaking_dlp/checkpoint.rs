@@ -0,0 +1,276 @@
+//! Checkpoint files for long-running Discrete Logarithm solvers
+//!
+//! A checkpoint is a small line-oriented text file:
+//!
+//! ```text
+//! elliptic-curves-rs dlp-checkpoint
+//! version 3
+//! solver pollards_rho
+//! group <sha-256 of the group parameters>
+//! targets <sha-256 of P, Q and the order of P>
+//! steps 123456
+//! group_operations 370388
+//! stored_elements 22
+//! elapsed_ms 5321
+//! <solver specific records, one per line>
+//! ```
+//!
+//! Exponents are written in decimal, so files stay readable and diffable.
+//! The header is checked against the group and the target points on
+//! resume, which prevents continuing a walk on another problem.
+//! Version 1 files, written before the solvers became generic over
+//! `Group`, stored ark-ff scalars and are not accepted anymore; version 2
+//! files lack `stored_elements` and are rejected as well.
+
+use crate::breaking_dlp::solver::SolverStats;
+use crate::core::group::{Group, hex};
+
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const CHECKPOINT_VERSION: u32 = 3;
+const MAGIC: &str = "elliptic-curves-rs dlp-checkpoint";
+
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    #[error("checkpoint i/o failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("malformed checkpoint: {0}")]
+    Malformed(String),
+    #[error("unsupported checkpoint version {found} (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+    #[error("checkpoint was written by `{found}`, not `{expected}`")]
+    SolverMismatch { found: String, expected: String },
+    #[error("checkpoint belongs to a different group")]
+    GroupMismatch,
+    #[error("checkpoint belongs to different target points")]
+    TargetMismatch,
+}
+
+/// Where and how often a solver writes its state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointConfig {
+    pub path: PathBuf,
+    /// Number of solver steps between two writes
+    pub interval: u64,
+}
+
+impl CheckpointConfig {
+    pub fn new(path: impl Into<PathBuf>, interval: u64) -> Self {
+        Self {
+            path: path.into(),
+            interval: interval.max(1),
+        }
+    }
+}
+
+/// In-memory form of a checkpoint file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub solver: String,
+    pub group: String,
+    pub targets: String,
+    pub stats: SolverStats,
+    records: Vec<(String, Vec<String>)>,
+}
+
+impl Checkpoint {
+    pub fn new<G: Group>(
+        solver: &str,
+        p: &G,
+        q: &G,
+        order: &BigUint,
+        stats: SolverStats,
+    ) -> Self {
+        Self {
+            solver: solver.to_string(),
+            group: group_fingerprint::<G>(),
+            targets: targets_fingerprint(p, q, order),
+            stats,
+            records: Vec::new(),
+        }
+    }
+
+    /// Appends a record; a key may be repeated to form a table.
+    pub fn push<T: Display>(&mut self, key: &str, values: &[T]) {
+        let values = values.iter().map(|v| v.to_string()).collect();
+        self.records.push((key.to_string(), values));
+    }
+
+    /// All records stored under `key`, in file order.
+    pub fn records(&self, key: &str) -> impl Iterator<Item = &[String]> {
+        let key = key.to_string();
+        self.records
+            .iter()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, v)| v.as_slice())
+    }
+
+    /// The single record stored under `key`.
+    pub fn record(&self, key: &str) -> Result<&[String], CheckpointError> {
+        let mut found = self.records(key);
+        match (found.next(), found.next()) {
+            (Some(values), None) => Ok(values),
+            (None, _) => Err(malformed(format!("missing `{}` record", key))),
+            (Some(_), Some(_)) => Err(malformed(format!("duplicate `{}` record", key))),
+        }
+    }
+
+    /// Checks that this checkpoint continues `solver` on `(p, q)` in `G`.
+    pub fn verify<G: Group>(
+        &self,
+        solver: &str,
+        p: &G,
+        q: &G,
+        order: &BigUint,
+    ) -> Result<(), CheckpointError> {
+        if self.solver != solver {
+            return Err(CheckpointError::SolverMismatch {
+                found: self.solver.clone(),
+                expected: solver.to_string(),
+            });
+        }
+        if self.group != group_fingerprint::<G>() {
+            return Err(CheckpointError::GroupMismatch);
+        }
+        if self.targets != targets_fingerprint(p, q, order) {
+            return Err(CheckpointError::TargetMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        text.push_str(MAGIC);
+        text.push('\n');
+        text.push_str(&format!("version {}\n", CHECKPOINT_VERSION));
+        text.push_str(&format!("solver {}\n", self.solver));
+        text.push_str(&format!("group {}\n", self.group));
+        text.push_str(&format!("targets {}\n", self.targets));
+        text.push_str(&format!("steps {}\n", self.stats.steps));
+        text.push_str(&format!("group_operations {}\n", self.stats.group_operations));
+        text.push_str(&format!("stored_elements {}\n", self.stats.stored_elements));
+        text.push_str(&format!("elapsed_ms {}\n", self.stats.elapsed.as_millis()));
+        for (key, values) in &self.records {
+            text.push_str(key);
+            for value in values {
+                text.push(' ');
+                text.push_str(value);
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, CheckpointError> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+
+        if lines.next() != Some(MAGIC) {
+            return Err(malformed("not a dlp checkpoint"));
+        }
+        let version: u32 = parse(header(lines.next(), "version")?)?;
+        if version != CHECKPOINT_VERSION {
+            return Err(CheckpointError::UnsupportedVersion {
+                found: version,
+                expected: CHECKPOINT_VERSION,
+            });
+        }
+        let solver = header(lines.next(), "solver")?.to_string();
+        let group = header(lines.next(), "group")?.to_string();
+        let targets = header(lines.next(), "targets")?.to_string();
+        let stats = SolverStats {
+            steps: parse(header(lines.next(), "steps")?)?,
+            group_operations: parse(header(lines.next(), "group_operations")?)?,
+            stored_elements: parse(header(lines.next(), "stored_elements")?)?,
+            elapsed: Duration::from_millis(parse(header(lines.next(), "elapsed_ms")?)?),
+        };
+
+        let records = lines
+            .map(|line| {
+                let mut words = line.split_whitespace().map(str::to_string);
+                let key = words.next().unwrap_or_default();
+                (key, words.collect())
+            })
+            .collect();
+
+        Ok(Self { solver, group, targets, stats, records })
+    }
+
+    /// Writes the checkpoint through a temporary file and a rename, so
+    /// an interruption never leaves a truncated checkpoint behind.
+    pub fn write(&self, path: &Path) -> Result<(), CheckpointError> {
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(self.to_text().as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self, CheckpointError> {
+        Self::from_text(&fs::read_to_string(path)?)
+    }
+}
+
+/// Parses one decimal value (integer or field element) of a record.
+pub fn parse<T: FromStr>(value: &str) -> Result<T, CheckpointError> {
+    value
+        .parse()
+        .map_err(|_| malformed(format!("cannot parse `{}`", value)))
+}
+
+/// Parses exactly `N` values of a record.
+pub fn parse_values<T: FromStr, const N: usize>(
+    values: &[String],
+) -> Result<[T; N], CheckpointError> {
+    if values.len() != N {
+        return Err(malformed(format!(
+            "expected {} values, found {}",
+            N,
+            values.len(),
+        )));
+    }
+    let parsed = values
+        .iter()
+        .map(|v| parse(v))
+        .collect::<Result<Vec<T>, _>>()?;
+    parsed
+        .try_into()
+        .map_err(|_| malformed("record has the wrong length"))
+}
+
+/// SHA-256 over `Group::description`.
+pub fn group_fingerprint<G: Group>() -> String {
+    hex(&Sha256::digest(G::description().as_bytes()))
+}
+
+fn targets_fingerprint<G: Group>(p: &G, q: &G, order: &BigUint) -> String {
+    let mut hasher = Sha256::new();
+    for bytes in [p.encode(), q.encode(), order.to_bytes_be()] {
+        hasher.update((bytes.len() as u64).to_be_bytes());
+        hasher.update(bytes);
+    }
+    hex(&hasher.finalize())
+}
+
+fn header<'a>(line: Option<&'a str>, key: &str) -> Result<&'a str, CheckpointError> {
+    line.and_then(|line| line.strip_prefix(key))
+        .and_then(|rest| rest.strip_prefix(' '))
+        .map(str::trim)
+        .ok_or_else(|| malformed(format!("missing `{}` header", key)))
+}
+
+fn malformed(message: impl Into<String>) -> CheckpointError {
+    CheckpointError::Malformed(message.into())
+}
@@ -0,0 +1,341 @@
+//! Empirical complexity of the generic DLP solvers
+//!
+//! Runs `BruteForce`, `BabyStepGiantStep`, `PollardsRho` and `Kangaroo`
+//! on the toy curves (16 to 56 bits) with random logarithms and records
+//! steps, group operations, memory and time of every run. A least-squares
+//! fit of ln(steps) against ln(n) then gives the exponent e of n^e, which
+//! should come out near 1 for brute force and near 0.5 for the others,
+//! as claimed in the doc comments of each solver.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::baby_step_giant_step::BabyStepGiantStep;
+use crate::breaking_dlp::brute_force::BruteForce;
+use crate::breaking_dlp::kangaroo::Kangaroo;
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::solver::{DlpError, DlpOutcome, SolverConfig};
+use crate::core::curve::Curve;
+use crate::core::group::Group;
+use crate::core::point::CurvePoint;
+use crate::curves::toy::toy::{
+    Toy16Curve,
+    Toy20Curve,
+    Toy24Curve,
+    Toy28Curve,
+    Toy32Curve,
+    Toy40Curve,
+    Toy48Curve,
+    Toy56Curve,
+};
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::ToPrimitive;
+use rand::thread_rng;
+
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Bit sizes of the toy curves in `curves::toy`
+pub const TOY_BITS: [u32; 8] = [16, 20, 24, 28, 32, 40, 48, 56];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    BruteForce,
+    BabyStepGiantStep,
+    PollardsRho,
+    Kangaroo,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 4] = [
+        Algorithm::BruteForce,
+        Algorithm::BabyStepGiantStep,
+        Algorithm::PollardsRho,
+        Algorithm::Kangaroo,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Algorithm::BruteForce => "brute_force",
+            Algorithm::BabyStepGiantStep => "baby_step_giant_step",
+            Algorithm::PollardsRho => "pollards_rho",
+            Algorithm::Kangaroo => "kangaroo",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|algorithm| algorithm.name() == name)
+    }
+
+    /// Largest toy curve the algorithm finishes on in reasonable time
+    pub fn max_bits(self) -> u32 {
+        match self {
+            // O(n): 2^24 steps already take a while
+            Algorithm::BruteForce => 24,
+            // 2^20 steps; BSGS also keeps 2^20 points in memory
+            Algorithm::BabyStepGiantStep
+            | Algorithm::PollardsRho
+            | Algorithm::Kangaroo => 40,
+        }
+    }
+
+    fn solve<G: Group>(
+        self,
+        p: &G,
+        q: &G,
+        config: &SolverConfig,
+    ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        match self {
+            Algorithm::BruteForce => BruteForce::solve(p, q, config),
+            Algorithm::BabyStepGiantStep => BabyStepGiantStep::solve(p, q, config),
+            Algorithm::PollardsRho => PollardsRho::solve(p, q, config),
+            Algorithm::Kangaroo => Kangaroo::solve(p, q, config),
+        }
+    }
+}
+
+/// Measurements of a single solver run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunRecord {
+    pub algorithm: Algorithm,
+    pub bits: u32,
+    /// Group order n
+    pub order: BigUint,
+    pub steps: u64,
+    pub group_operations: u64,
+    pub stored_elements: u64,
+    /// `stored_elements` times the size of an encoded element
+    pub memory_bytes: u64,
+    pub elapsed: Duration,
+    /// Whether the solver returned the correct logarithm
+    pub found: bool,
+}
+
+/// Least-squares fit of the found runs of one algorithm
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    pub algorithm: Algorithm,
+    pub runs: usize,
+    /// e in steps ~ c * n^e
+    pub exponent: f64,
+    /// ln(c)
+    pub intercept: f64,
+    /// mean of steps / n
+    pub steps_per_n: f64,
+    /// mean of steps / sqrt(n)
+    pub steps_per_sqrt_n: f64,
+}
+
+/// Which runs to perform
+#[derive(Debug, Clone)]
+pub struct Experiment {
+    pub algorithms: Vec<Algorithm>,
+    /// Toy curve sizes, each one of `TOY_BITS`
+    pub bits: Vec<u32>,
+    /// Runs per (algorithm, curve) pair
+    pub runs: u32,
+    /// Step budget of a single run, so that one unlucky run cannot stall
+    /// the whole experiment
+    pub max_steps: Option<u64>,
+}
+
+impl Experiment {
+    /// Every algorithm on every toy curve up to `max_bits`, capped by
+    /// `Algorithm::max_bits`
+    pub fn new(max_bits: u32, runs: u32) -> Self {
+        Self {
+            algorithms: Algorithm::ALL.to_vec(),
+            bits: TOY_BITS.into_iter().filter(|&bits| bits <= max_bits).collect(),
+            runs,
+            max_steps: None,
+        }
+    }
+
+    pub fn with_algorithms(mut self, algorithms: &[Algorithm]) -> Self {
+        self.algorithms = algorithms.to_vec();
+        self
+    }
+
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    pub fn run(&self) -> Report {
+        let mut config = SolverConfig::new();
+        if let Some(max_steps) = self.max_steps {
+            config = config.with_max_steps(max_steps);
+        }
+
+        let mut records = Vec::new();
+        for &algorithm in &self.algorithms {
+            for &bits in self.bits.iter().filter(|&&bits| bits <= algorithm.max_bits()) {
+                for _ in 0..self.runs {
+                    if let Some(record) = run_once(algorithm, bits, &config) {
+                        records.push(record);
+                    }
+                }
+            }
+        }
+
+        let fits = self.algorithms
+            .iter()
+            .filter_map(|&algorithm| fit(&records, algorithm))
+            .collect();
+        Report { records, fits }
+    }
+}
+
+/// Solves k * G = Q for a random k on the toy curve with `bits` bits,
+/// or returns None if there is no such toy curve.
+pub fn run_once(algorithm: Algorithm, bits: u32, config: &SolverConfig) -> Option<RunRecord> {
+    let record = match bits {
+        16 => run_on::<Toy16Curve>(algorithm, bits, config),
+        20 => run_on::<Toy20Curve>(algorithm, bits, config),
+        24 => run_on::<Toy24Curve>(algorithm, bits, config),
+        28 => run_on::<Toy28Curve>(algorithm, bits, config),
+        32 => run_on::<Toy32Curve>(algorithm, bits, config),
+        40 => run_on::<Toy40Curve>(algorithm, bits, config),
+        48 => run_on::<Toy48Curve>(algorithm, bits, config),
+        56 => run_on::<Toy56Curve>(algorithm, bits, config),
+        _ => return None,
+    };
+    Some(record)
+}
+
+fn run_on<C: Curve>(algorithm: Algorithm, bits: u32, config: &SolverConfig) -> RunRecord {
+    let order = <CurvePoint<C> as Group>::order();
+    let g = C::generator();
+    let k = thread_rng().gen_biguint_below(&order);
+    let q = g.pow(&k);
+
+    let outcome = algorithm
+        .solve(&g, &q, config)
+        .expect("toy curve points are on the curve");
+    let stats = *outcome.stats();
+    let element_size = g.encode().len() as u64;
+
+    RunRecord {
+        algorithm,
+        bits,
+        order,
+        steps: stats.steps,
+        group_operations: stats.group_operations,
+        stored_elements: stats.stored_elements,
+        memory_bytes: stats.stored_elements * element_size,
+        elapsed: stats.elapsed,
+        found: outcome.logarithm() == Some(&k),
+    }
+}
+
+/// Fits ln(steps) = e * ln(n) + ln(c) over the found runs of `algorithm`.
+/// Needs at least two different group orders.
+pub fn fit(records: &[RunRecord], algorithm: Algorithm) -> Option<Fit> {
+    let points: Vec<(f64, f64, f64)> = records
+        .iter()
+        .filter(|r| r.algorithm == algorithm && r.found && r.steps > 0)
+        .map(|r| {
+            let n = r.order.to_f64().unwrap_or(f64::MAX);
+            (n, n.ln(), (r.steps as f64).ln())
+        })
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(_, x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, _, y)| y).sum::<f64>() / count;
+    let sxx: f64 = points.iter().map(|(_, x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|(_, x, y)| (x - mean_x) * (y - mean_y)).sum();
+    if sxx == 0.0 {
+        return None;
+    }
+    let exponent = sxy / sxx;
+
+    let ratio = |scale: fn(f64) -> f64| {
+        points.iter().map(|(n, _, y)| y.exp() / scale(*n)).sum::<f64>() / count
+    };
+
+    Some(Fit {
+        algorithm,
+        runs: points.len(),
+        exponent,
+        intercept: mean_y - exponent * mean_x,
+        steps_per_n: ratio(|n| n),
+        steps_per_sqrt_n: ratio(f64::sqrt),
+    })
+}
+
+/// All runs of an experiment together with one fit per algorithm
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub records: Vec<RunRecord>,
+    pub fits: Vec<Fit>,
+}
+
+const CSV_HEADER: &str = "algorithm,bits,order,steps,group_operations,\
+stored_elements,memory_bytes,elapsed_ms,found";
+
+impl Report {
+    /// One line per run; the fits are left to the spreadsheet
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for r in &self.records {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{:.3},{}",
+                r.algorithm.name(),
+                r.bits,
+                r.order,
+                r.steps,
+                r.group_operations,
+                r.stored_elements,
+                r.memory_bytes,
+                r.elapsed.as_secs_f64() * 1000.0,
+                r.found,
+            ).unwrap();
+        }
+        csv
+    }
+
+    /// `{"runs": [...], "fits": [...]}`, the order is written as a string
+    /// since it does not always fit in a JSON number
+    pub fn to_json(&self) -> String {
+        let runs: Vec<String> = self.records
+            .iter()
+            .map(|r| format!(
+                "{{\"algorithm\":\"{}\",\"bits\":{},\"order\":\"{}\",\"steps\":{},\
+                \"group_operations\":{},\"stored_elements\":{},\"memory_bytes\":{},\
+                \"elapsed_ms\":{:.3},\"found\":{}}}",
+                r.algorithm.name(),
+                r.bits,
+                r.order,
+                r.steps,
+                r.group_operations,
+                r.stored_elements,
+                r.memory_bytes,
+                r.elapsed.as_secs_f64() * 1000.0,
+                r.found,
+            ))
+            .collect();
+        let fits: Vec<String> = self.fits
+            .iter()
+            .map(|f| format!(
+                "{{\"algorithm\":\"{}\",\"runs\":{},\"exponent\":{:.4},\"intercept\":{:.4},\
+                \"steps_per_n\":{:.6},\"steps_per_sqrt_n\":{:.6}}}",
+                f.algorithm.name(),
+                f.runs,
+                f.exponent,
+                f.intercept,
+                f.steps_per_n,
+                f.steps_per_sqrt_n,
+            ))
+            .collect();
+        format!(
+            "{{\n  \"runs\": [\n    {}\n  ],\n  \"fits\": [\n    {}\n  ]\n}}\n",
+            runs.join(",\n    "),
+            fits.join(",\n    "),
+        )
+    }
+}
@@ -0,0 +1,283 @@
+//! Index calculus for the Discrete Logarithm Problem in F_p^*
+//!
+//! Elements of F_p^* are integers, and integers factor over small primes,
+//! so the logarithms of a factor base {2, 3, 5, ..., B} can be learned
+//! from relations g^e = prod q_i^v_i, solved as a linear system modulo
+//! p - 1. A single smooth h * g^s then gives log(h) (the descent step).
+//! With B = L_p[1/2, 1/sqrt(2)] the cost is subexponential, L_p[1/2, sqrt(2)],
+//! while the best generic attack on an elliptic curve group of the same
+//! size (Pollard's rho) stays at O(sqrt(n)). Curve points have no
+//! notion of "small" or "factoring", which is why no index calculus
+//! is known for them.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::group::{Group, Multiplicative};
+use crate::core::integer::{crt, factorize};
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+use rand::thread_rng;
+
+pub struct IndexCalculus;
+
+/// Extra relations collected on top of the factor base size, so that the
+/// system has full rank modulo every prime factor of p - 1.
+const EXTRA_RELATIONS: usize = 16;
+/// How many times to collect more relations when the system is singular.
+const MAX_LINEAR_ALGEBRA_ROUNDS: usize = 8;
+
+/// B = exp(sqrt(ln p * ln ln p) / sqrt(2)), kept in a practical range
+pub fn smoothness_bound(p: &BigUint) -> u64 {
+    let ln_p = p.bits() as f64 * std::f64::consts::LN_2;
+    let bound = ((ln_p * ln_p.ln()).sqrt() / std::f64::consts::SQRT_2).exp();
+    bound.clamp(30.0, 1_000_000.0) as u64
+}
+
+/// All primes up to `bound` (sieve of Eratosthenes)
+pub fn factor_base(bound: u64) -> Vec<u64> {
+    let bound = bound as usize;
+    let mut is_prime = vec![true; bound + 1];
+    let mut primes = Vec::new();
+    for i in 2..=bound {
+        if is_prime[i] {
+            primes.push(i as u64);
+            for multiple in (i * i..=bound).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    primes
+}
+
+/// Exponent vector of `value` over `base`, or None if it is not smooth
+fn factor_over(value: &BigUint, base: &[u64]) -> Option<Vec<u64>> {
+    let mut rest = value.clone();
+    let mut exponents = vec![0u64; base.len()];
+    for (i, &q) in base.iter().enumerate() {
+        let q = BigUint::from(q);
+        loop {
+            let (quotient, remainder) = rest.div_rem(&q);
+            if !remainder.is_zero() {
+                break;
+            }
+            rest = quotient;
+            exponents[i] += 1;
+        }
+        if rest.is_one() {
+            return Some(exponents);
+        }
+    }
+    None
+}
+
+/// One relation g^e = prod q_i^v_i
+struct Relation {
+    exponents: Vec<u64>,
+    e: BigUint,
+}
+
+/// Solves `sum_j v_ij x_j = e_i (mod modulus)` for x, where
+/// modulus = prime^k, by Gaussian elimination with unit pivots.
+///
+/// Large factor base primes rarely divide a relation, so some columns
+/// may have no pivot; their variables are set to zero and every value
+/// depending on them comes out wrong, which the caller detects by
+/// checking g^x = q.
+fn solve_mod_prime_power(
+    relations: &[Relation],
+    columns: usize,
+    prime: &BigUint,
+    modulus: &BigUint,
+) -> Vec<Option<BigUint>> {
+    // augmented matrix [V | e]
+    let mut rows: Vec<Vec<BigUint>> = relations
+        .iter()
+        .map(|r| {
+            let mut row: Vec<BigUint> = r.exponents
+                .iter()
+                .map(|&v| BigUint::from(v) % modulus)
+                .collect();
+            row.push(&r.e % modulus);
+            row
+        })
+        .collect();
+
+    let mut pivots = vec![None; columns];
+    let mut rank = 0;
+    for col in 0..columns {
+        let Some(pivot) = (rank..rows.len())
+            .find(|&r| !(&rows[r][col] % prime).is_zero())
+        else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let inv = rows[rank][col]
+            .modinv(modulus)
+            .expect("pivot is a unit modulo a prime power");
+        for value in rows[rank].iter_mut() {
+            *value = (&*value * &inv) % modulus;
+        }
+
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == rank || row[col].is_zero() {
+                continue;
+            }
+            let factor = row[col].clone();
+            for (value, pivot_value) in row.iter_mut().zip(&pivot_row) {
+                // value - factor * pivot_value (mod modulus)
+                let sub = (&factor * pivot_value) % modulus;
+                *value = (&*value + modulus - sub) % modulus;
+            }
+        }
+        pivots[col] = Some(rank);
+        rank += 1;
+    }
+
+    pivots
+        .iter()
+        .map(|pivot| pivot.map(|r| rows[r][columns].clone()))
+        .collect()
+}
+
+/// Logarithms of the factor base modulo n = p - 1, `None` where the
+/// relations do not determine them (yet).
+fn solve_factor_base(
+    relations: &[Relation],
+    base: &[u64],
+    n: &BigUint,
+) -> Vec<Option<BigUint>> {
+    let per_modulus: Vec<(Vec<Option<BigUint>>, BigUint)> = factorize(n)
+        .into_iter()
+        .map(|(prime, exponent)| {
+            let modulus = prime.pow(exponent);
+            let logs = solve_mod_prime_power(relations, base.len(), &prime, &modulus);
+            (logs, modulus)
+        })
+        .collect();
+
+    (0..base.len())
+        .map(|i| {
+            let residues = per_modulus
+                .iter()
+                .map(|(logs, modulus)| Some((logs[i].clone()?, modulus.clone())))
+                .collect::<Option<Vec<(BigUint, BigUint)>>>()?;
+            crt(&residues).map(|(x, _)| x)
+        })
+        .collect()
+}
+
+fn to_integer<F: ArkPrimeField>(element: &Multiplicative<F>) -> BigUint {
+    element.0.into_bigint().into()
+}
+
+impl<F: ArkPrimeField> DiscreteLog<Multiplicative<F>> for IndexCalculus {
+    /// `order` must be p - 1, i.e. `g` has to generate F_p^*, because
+    /// only then is every factor base prime a power of `g`.
+    fn solve_with_order(
+            g: &Multiplicative<F>,
+            h: &Multiplicative<F>,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !g.is_element() || !h.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
+
+        let mut run = SolverRun::new(config);
+        let n = <Multiplicative<F> as Group>::order();
+        if *order != n {
+            return Ok(run.exhausted(Exhaustion::SearchSpace));
+        }
+
+        let p: BigUint = F::MODULUS.into();
+        let bound = smoothness_bound(&p).min(p.to_u64().unwrap_or(u64::MAX) - 1);
+        let base = factor_base(bound);
+        let mut rng = thread_rng();
+
+        // 1. relation collection: g^e smooth over the factor base
+        let mut relations: Vec<Relation> = Vec::new();
+        let mut wanted = base.len() + EXTRA_RELATIONS;
+        let mut logs: Vec<Option<BigUint>> = vec![None; base.len()];
+        for _ in 0..MAX_LINEAR_ALGEBRA_ROUNDS {
+            while relations.len() < wanted {
+                if let Some(outcome) = run.check() {
+                    return Ok(outcome);
+                }
+                let e = rng.gen_biguint_below(&n);
+                let value = to_integer(&g.pow(&e));
+                run.add_group_operations(pow_cost(&e));
+                run.step();
+
+                if let Some(exponents) = factor_over(&value, &base) {
+                    relations.push(Relation { exponents, e });
+                }
+            }
+
+            // 2. linear algebra modulo p - 1, keeping only logs with g^x = q
+            logs = solve_factor_base(&relations, &base, &n)
+                .into_iter()
+                .zip(&base)
+                .map(|(log, &q)| {
+                    log.filter(|log| to_integer(&g.pow(log)) == BigUint::from(q))
+                })
+                .collect();
+            run.add_group_operations(base.len() as u64 * pow_cost(&n));
+
+            // the rare large primes may stay unknown, the descent skips them
+            let known = logs.iter().filter(|log| log.is_some()).count();
+            if known * 10 >= base.len() * 9 {
+                break;
+            }
+            wanted += EXTRA_RELATIONS;
+        }
+        if logs.iter().all(Option::is_none) {
+            // typically g does not generate F_p^*
+            return Ok(run.exhausted(Exhaustion::SearchSpace));
+        }
+
+        // 3. descent: h * g^s = prod q_i^v_i gives log h = sum v_i log q_i - s.
+        // A smooth h * g^s is as rare as a relation, so give up after as many
+        // attempts as the whole relation collection took
+        let attempts = run.stats().steps;
+        for _ in 0..attempts {
+            if let Some(outcome) = run.check() {
+                return Ok(outcome);
+            }
+            let s = rng.gen_biguint_below(&n);
+            let value = to_integer(&h.op(&g.pow(&s)));
+            run.add_group_operations(pow_cost(&s) + 1);
+            run.step();
+
+            let Some(exponents) = factor_over(&value, &base) else {
+                continue;
+            };
+            let Some(sum) = exponents
+                .iter()
+                .zip(&logs)
+                .filter(|(v, _)| **v > 0)
+                .try_fold(BigUint::zero(), |acc, (&v, log)| Some(acc + log.as_ref()? * v))
+            else {
+                continue;
+            };
+            let k = (sum + &n - s) % &n;
+
+            run.add_group_operations(pow_cost(&k));
+            if g.pow(&k) == *h {
+                return Ok(run.found(k));
+            }
+        }
+        Ok(run.exhausted(Exhaustion::SearchSpace))
+    }
+}
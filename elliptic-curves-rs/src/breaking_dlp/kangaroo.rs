@@ -0,0 +1,311 @@
+//! Pollard's kangaroo (lambda) algorithm for Discrete Logarithm Problem
+//!
+//! A tame kangaroo starts at a known exponent and a wild kangaroo starts
+//! at Q; both jump by P^(2^j) where j depends on the current point, so
+//! once their trails meet they stay together. Only distinguished points
+//! (hash with `dp_bits` low zero bits) are stored, which keeps the
+//! memory small while the time stays O(sqrt(n)). Unlike rho, it also
+//! works on an interval [a, a + w) in O(sqrt(w)), although here the
+//! interval is always the whole group. There the jumps wrap around, so
+//! a kangaroo can end up in a cycle; it is then restarted at a random
+//! distance, which keeps the walk a valid tame or wild trail.
+
+use crate::breaking_dlp::{DiscreteLog, ResumableDiscreteLog};
+use crate::breaking_dlp::checkpoint::{Checkpoint, CheckpointError, parse, parse_values};
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::group::Group;
+
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{ToPrimitive, Zero};
+use rand::thread_rng;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+pub struct Kangaroo;
+
+const SOLVER_NAME: &str = "kangaroo";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Tame,
+    Wild,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Tame => "tame",
+            Kind::Wild => "wild",
+        }
+    }
+
+    fn from_name(name: &str) -> Result<Self, CheckpointError> {
+        match name {
+            "tame" => Ok(Kind::Tame),
+            "wild" => Ok(Kind::Wild),
+            _ => Err(CheckpointError::Malformed(format!("unknown kangaroo `{}`", name))),
+        }
+    }
+}
+
+/// Position = start * P^distance, where start is P^0 or Q
+struct Runner<G: Group> {
+    kind: Kind,
+    point: G,
+    distance: BigUint,
+}
+
+/// Jump table and distinguished point rule, both derived from the order
+struct Herd<'a, G: Group> {
+    order: &'a BigUint,
+    jumps: Vec<G>,
+    dp_mask: u64,
+    /// distinguished point -> (kind, distance)
+    table: HashMap<Vec<u8>, (Kind, BigUint)>,
+}
+
+fn hash(point: &impl Group) -> u64 {
+    // FNV-1a over the canonical encoding
+    point
+        .encode()
+        .iter()
+        .fold(0xcbf29ce484222325u64, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+impl<'a, G: Group> Herd<'a, G> {
+    fn new(p: &G, order: &'a BigUint) -> Self {
+        // mean jump (2^k - 1) / k should be about sqrt(n) / 2
+        let target = (order.sqrt() / 2u32).to_u64().unwrap_or(u64::MAX).max(1);
+        let mut k = 1u32;
+        while k < 63 && ((1u64 << k) - 1) / (k as u64) < target {
+            k += 1;
+        }
+
+        let mut jumps = Vec::with_capacity(k as usize);
+        let mut jump = p.clone();
+        for _ in 0..k {
+            jumps.push(jump.clone());
+            jump = jump.op(&jump);
+        }
+
+        // about 2^(bits(sqrt(n)) / 3) steps between distinguished points
+        let dp_bits = order.sqrt().bits() / 3;
+
+        Self {
+            order,
+            jumps,
+            dp_mask: (1u64 << dp_bits) - 1,
+            table: HashMap::new(),
+        }
+    }
+
+    fn jump(&self, runner: &mut Runner<G>) {
+        let j = (hash(&runner.point) % self.jumps.len() as u64) as usize;
+        runner.point = runner.point.op(&self.jumps[j]);
+        runner.distance += 1u64 << j;
+    }
+
+    /// Stores `runner` if it stands on a distinguished point and reports
+    /// whether it met the trail of the other kangaroo or its own.
+    fn visit(&mut self, runner: &Runner<G>) -> Visit {
+        if hash(&runner.point) & self.dp_mask != 0 {
+            return Visit::Ordinary;
+        }
+        let key = runner.point.encode();
+        match self.table.get(&key) {
+            Some((kind, distance)) if *kind != runner.kind => {
+                // P^tame = Q * P^wild  =>  k = tame - wild (mod n)
+                let (tame, wild) = match runner.kind {
+                    Kind::Tame => (&runner.distance, distance),
+                    Kind::Wild => (distance, &runner.distance),
+                };
+                let tame = tame % self.order;
+                let wild = wild % self.order;
+                Visit::Collision((tame + self.order - wild) % self.order)
+            }
+            Some(_) => Visit::Cycle,
+            None => {
+                self.table.insert(key, (runner.kind, runner.distance.clone()));
+                Visit::Distinguished
+            }
+        }
+    }
+
+    /// Longest walk without a distinguished point before the runner is
+    /// considered stuck in a cycle that contains none.
+    fn patience(&self) -> u64 {
+        (self.dp_mask + 1) * 32
+    }
+
+    /// Puts the runner back on a fresh random position on its own trail
+    fn restart(&self, p: &G, q: &G, runner: &mut Runner<G>) -> u64 {
+        let distance = thread_rng().gen_biguint_below(self.order);
+        runner.point = start(p, q, runner.kind, &distance);
+        runner.distance = distance;
+        pow_cost(&runner.distance) + 1
+    }
+}
+
+enum Visit {
+    Ordinary,
+    Distinguished,
+    /// The runner came back to one of its own distinguished points
+    Cycle,
+    Collision(BigUint),
+}
+
+/// P^distance for the tame kangaroo, Q * P^distance for the wild one
+fn start<G: Group>(p: &G, q: &G, kind: Kind, distance: &BigUint) -> G {
+    match kind {
+        Kind::Tame => p.pow(distance),
+        Kind::Wild => q.op(&p.pow(distance)),
+    }
+}
+
+fn save_checkpoint<G: Group>(
+    path: &Path,
+    p: &G,
+    q: &G,
+    herd: &Herd<'_, G>,
+    runners: &[Runner<G>; 2],
+    run: &SolverRun<'_>,
+) -> Result<(), DlpError> {
+    let mut checkpoint = Checkpoint::new(SOLVER_NAME, p, q, herd.order, run.stats());
+    for runner in runners {
+        checkpoint.push(runner.kind.name(), &[&runner.distance]);
+    }
+    for (kind, distance) in herd.table.values() {
+        checkpoint.push("dp", &[kind.name(), &distance.to_string()]);
+    }
+    checkpoint.write(path)?;
+    Ok(())
+}
+
+fn hop<G: Group>(
+    p: &G,
+    q: &G,
+    mut herd: Herd<'_, G>,
+    mut runners: [Runner<G>; 2],
+    mut run: SolverRun<'_>,
+) -> Result<DlpOutcome<BigUint>, DlpError> {
+    // hops since the last distinguished point of each kangaroo
+    let mut since_dp = [0u64; 2];
+    loop {
+        run.set_stored_elements(herd.table.len() as u64 + herd.jumps.len() as u64);
+        if let Some(outcome) = run.check() {
+            if let Some(path) = run.checkpoint_path() {
+                save_checkpoint(path, p, q, &herd, &runners, &run)?;
+            }
+            return Ok(outcome);
+        }
+
+        for (runner, since_dp) in runners.iter_mut().zip(since_dp.iter_mut()) {
+            herd.jump(runner);
+            *since_dp += 1;
+            match herd.visit(runner) {
+                Visit::Ordinary if *since_dp < herd.patience() => continue,
+                Visit::Distinguished => {}
+                Visit::Collision(k) => {
+                    run.add_group_operations(pow_cost(&k));
+                    if p.pow(&k) == *q {
+                        run.add_group_operations(1);
+                        run.step();
+                        return Ok(run.found(k));
+                    }
+                }
+                // the walk is a random mapping of a finite group, so it
+                // ends in a cycle which the other kangaroo may never enter
+                Visit::Ordinary | Visit::Cycle => {
+                    run.add_group_operations(herd.restart(p, q, runner));
+                }
+            }
+            *since_dp = 0;
+        }
+        run.add_group_operations(2);
+        run.step();
+
+        if run.checkpoint_due()
+            && let Some(path) = run.checkpoint_path()
+        {
+            save_checkpoint(path, p, q, &herd, &runners, &run)?;
+        }
+    }
+}
+
+impl<G: Group> DiscreteLog<G> for Kangaroo {
+    fn solve_with_order(
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
+
+        let mut run = SolverRun::new(config);
+        let herd = Herd::new(p, order);
+        run.add_group_operations(herd.jumps.len() as u64);
+
+        // the tame kangaroo starts in the middle of [0, n)
+        let middle = order / 2u32;
+        let tame = Runner { kind: Kind::Tame, point: p.pow(&middle), distance: middle };
+        let wild = Runner { kind: Kind::Wild, point: q.clone(), distance: BigUint::zero() };
+        run.add_group_operations(pow_cost(&tame.distance));
+
+        hop(p, q, herd, [tame, wild], run)
+    }
+}
+
+impl<G: Group> ResumableDiscreteLog<G> for Kangaroo {
+    fn resume_with_order(
+            checkpoint: &Path,
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
+
+        let checkpoint = Checkpoint::read(checkpoint)?;
+        checkpoint.verify(SOLVER_NAME, p, q, order)?;
+
+        let mut run = SolverRun::resume(config, checkpoint.stats);
+        let mut herd = Herd::new(p, order);
+        run.add_group_operations(herd.jumps.len() as u64);
+
+        // positions are recomputed from the distances
+        for values in checkpoint.records("dp") {
+            let [kind, distance]: [String; 2] = parse_values(values)?;
+            let kind = Kind::from_name(&kind)?;
+            let distance: BigUint = parse(&distance)?;
+            let point = start(p, q, kind, &distance);
+            run.add_group_operations(pow_cost(&distance) + 1);
+            herd.table.insert(point.encode(), (kind, distance));
+        }
+
+        let runners = [Kind::Tame, Kind::Wild].map(|kind| {
+            let [distance] = parse_values(checkpoint.record(kind.name())?)?;
+            let point = start(p, q, kind, &distance);
+            Ok::<_, CheckpointError>(Runner { kind, point, distance })
+        });
+        let [tame, wild] = runners;
+        let runners = [tame?, wild?];
+        for runner in &runners {
+            run.add_group_operations(pow_cost(&runner.distance) + 1);
+        }
+
+        hop(p, q, herd, runners, run)
+    }
+}
@@ -1,19 +1,71 @@
 pub mod brute_force;
 pub mod baby_step_giant_step;
+pub mod checkpoint;
+pub mod complexity;
+pub mod index_calculus;
+pub mod kangaroo;
+pub mod mov;
+pub mod pohlig_hellman;
 pub mod pollards_rho;
+pub mod singular;
+pub mod smart_attack;
+pub mod solver;
 #[cfg(test)]
 pub mod tests;
 
-use crate::core::curve::Curve;
-use crate::core::point::CurvePoint;
+use crate::breaking_dlp::solver::{DlpError, DlpOutcome, SolverConfig};
+use crate::core::group::Group;
+
+use num_bigint::BigUint;
+
+use std::path::Path;
 
 // 추상 메서드(=trait)만 선언하고, 하위 파일에서 구체 구현
 // 동일한 인터페이스(solve)를 갖되, 여러 알고리즘이 플러그인 형태도 들어와야.
 // 하지만, ECDH는 주어진 Curve, Field 타입에 대해 동작하는 단일 프로토콜 구현이 필요했기 떄문에
 // 제네릭 구조체 하나만으로 충분
-pub trait DiscreteLog<C: Curve> {
+// 실행 제어(step budget, timeout, progress, cancel)는 SolverConfig로 전달하고,
+// 결과는 found/exhausted/cancelled 중 하나와 통계를 담은 DlpOutcome으로 돌려준다.
+// 알고리즘은 Group의 연산만 사용하므로 CurvePoint<C>와 F_p^*에 모두 적용된다.
+// 지수(logarithm)는 합성수 order에서도 다룰 수 있도록 BigUint로 표현한다.
+pub trait DiscreteLog<G: Group> {
+    /// Finds k in [0, order) with p^k = q, where `order` is the order of p
+    fn solve_with_order(
+        p: &G,
+        q: &G,
+        order: &BigUint,
+        config: &SolverConfig,
+    ) -> Result<DlpOutcome<BigUint>, DlpError>;
+
+    /// Same as `solve_with_order` with the order of the whole group
     fn solve(
-        p: &CurvePoint<C>,
-        q: &CurvePoint<C>,
-    ) -> (u64, C::ScalarField); // (steps, logarithm)
-}
\ No newline at end of file
+        p: &G,
+        q: &G,
+        config: &SolverConfig,
+    ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        Self::solve_with_order(p, q, &G::order(), config)
+    }
+}
+
+/// 긴 실험을 중단했다가 이어서 돌릴 수 있는 solver
+/// `SolverConfig::checkpoint`가 설정되어 있으면 solve 도중 상태를 파일로 저장하고,
+/// resume은 그 파일을 읽어 같은 group, 같은 (p, q, order)인지 확인한 뒤 이어서 진행한다.
+/// 통계(steps 등)도 이어서 세므로 `max_steps`는 전체 실행에 대한 budget이 된다.
+pub trait ResumableDiscreteLog<G: Group>: DiscreteLog<G> {
+    fn resume_with_order(
+        checkpoint: &Path,
+        p: &G,
+        q: &G,
+        order: &BigUint,
+        config: &SolverConfig,
+    ) -> Result<DlpOutcome<BigUint>, DlpError>;
+
+    fn resume(
+        checkpoint: &Path,
+        p: &G,
+        q: &G,
+        config: &SolverConfig,
+    ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        Self::resume_with_order(checkpoint, p, q, &G::order(), config)
+    }
+}
@@ -0,0 +1,183 @@
+//! MOV / Frey-Rück reduction of the ECDLP to a finite field
+//!
+//! The embedding degree of a subgroup of prime order n is the smallest k
+//! with n | p^k - 1. The n-th roots of unity then live in F_{p^k}^*, and
+//! a pairing e with e(P, R) != 1 maps Q = kP to e(Q, R) = e(P, R)^k.
+//! The logarithm can therefore be taken in F_{p^k}^*, where index
+//! calculus runs in subexponential time. Random curves have k about n,
+//! which makes this useless, but supersingular curves have k <= 6 (k = 2
+//! for y^2 = x^3 + x with p = 3 mod 4) and some pairing-friendly curves
+//! are chosen with a small k on purpose, so their p^k must be large
+//! enough on its own.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::curve::Curve;
+use crate::core::extension::{ExtensionConfig, ExtensionField};
+use crate::core::group::{Group, Multiplicative};
+use crate::core::pairing::{ExtensionPoint, tate_pairing};
+use crate::core::point::CurvePoint;
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+use num_traits::One;
+use rand::thread_rng;
+use thiserror::Error;
+
+use std::marker::PhantomData;
+
+/// Random points R tried before the pairing is declared degenerate
+const MAX_PAIRING_ATTEMPTS: usize = 16;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MovError {
+    #[error("embedding degree is larger than {max}")]
+    DegreeTooLarge { max: u32 },
+    #[error("embedding degree {embedding} does not divide the extension degree {extension}")]
+    ExtensionMismatch { embedding: u32, extension: u32 },
+    #[error("base or target is not on the curve")]
+    NotInGroup,
+    #[error("base point is the point at infinity")]
+    TrivialBase,
+    #[error("the pairing stayed trivial on every random point")]
+    DegeneratePairing,
+}
+
+/// Smallest k <= `max_degree` with n | p^k - 1
+pub fn embedding_degree(p: &BigUint, n: &BigUint, max_degree: u32) -> Option<u32> {
+    let p = p % n;
+    let mut power = p.clone();
+    for k in 1..=max_degree {
+        if power.is_one() {
+            return Some(k);
+        }
+        power = (power * &p) % n;
+    }
+    None
+}
+
+/// Embedding degree of the subgroup generated by `C::generator()`
+pub fn curve_embedding_degree<C: Curve>(max_degree: u32) -> Option<u32> {
+    let p: BigUint = <C::BaseField as ArkPrimeField>::MODULUS.into();
+    embedding_degree(&p, &C::order().into(), max_degree)
+}
+
+/// P and Q mapped into F_{p^k}^*, with the same logarithm
+pub struct MovReduction<E: ExtensionConfig> {
+    pub base: Multiplicative<ExtensionField<E>>,
+    pub target: Multiplicative<ExtensionField<E>>,
+    pub embedding_degree: u32,
+}
+
+/// Maps P and Q = kP, where P has prime order `order`, to
+/// t(P, R) and t(Q, R) = t(P, R)^k for a random R in E(F_{p^k}).
+pub fn reduce<C, E>(
+    p: &CurvePoint<C>,
+    q: &CurvePoint<C>,
+    order: &BigUint,
+) -> Result<MovReduction<E>, MovError>
+where
+    C: Curve,
+    E: ExtensionConfig<BaseField = C::BaseField>,
+{
+    if !p.is_element() || !q.is_element() {
+        return Err(MovError::NotInGroup);
+    }
+    if p.is_identity() {
+        return Err(MovError::TrivialBase);
+    }
+
+    let extension = E::degree() as u32;
+    let modulus: BigUint = <C::BaseField as ArkPrimeField>::MODULUS.into();
+    let embedding = embedding_degree(&modulus, order, extension)
+        .ok_or(MovError::DegreeTooLarge { max: extension })?;
+    if !extension.is_multiple_of(embedding) {
+        return Err(MovError::ExtensionMismatch { embedding, extension });
+    }
+
+    let mut rng = thread_rng();
+    for _ in 0..MAX_PAIRING_ATTEMPTS {
+        let r = ExtensionPoint::<E>::random::<C, _>(&mut rng);
+        let Some(alpha) = tate_pairing(p, &r, order) else {
+            continue;
+        };
+        if alpha.is_one() {
+            continue;
+        }
+        let beta = if q.is_identity() {
+            ExtensionField::one()
+        } else {
+            match tate_pairing(q, &r, order) {
+                Some(beta) => beta,
+                None => continue,
+            }
+        };
+        return Ok(MovReduction {
+            base: Multiplicative(alpha),
+            target: Multiplicative(beta),
+            embedding_degree: embedding,
+        });
+    }
+    Err(MovError::DegeneratePairing)
+}
+
+/// MOV reduction into `ExtensionField<E>`, solved there with `S`, e.g.
+/// `Mov<PollardsRho, SupersingularExtension>`.
+pub struct Mov<S, E> {
+    _solver: PhantomData<S>,
+    _extension: PhantomData<E>,
+}
+
+impl<C, E, S> DiscreteLog<CurvePoint<C>> for Mov<S, E>
+where
+    C: Curve,
+    E: ExtensionConfig<BaseField = C::BaseField>,
+    S: DiscreteLog<Multiplicative<ExtensionField<E>>>,
+{
+    fn solve_with_order(
+            p: &CurvePoint<C>,
+            q: &CurvePoint<C>,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        let mut run = SolverRun::new(config);
+        let reduction = match reduce::<C, E>(p, q, order) {
+            Ok(reduction) => reduction,
+            Err(MovError::NotInGroup) => return Err(DlpError::NotInGroup),
+            Err(_) => return Ok(run.exhausted(Exhaustion::SearchSpace)),
+        };
+        // two Miller loops and final exponentiations
+        let final_exponent = (E::size() - 1u32) / order;
+        run.add_group_operations(2 * (pow_cost(order) + pow_cost(&final_exponent)));
+
+        // the logarithm is unchanged, only the group is different
+        let outcome = S::solve_with_order(
+            &reduction.base,
+            &reduction.target,
+            order,
+            &run.nested_config(),
+        )?;
+        run.absorb(outcome.stats());
+        let k = match outcome {
+            DlpOutcome::Found { logarithm, .. } => logarithm,
+            DlpOutcome::Exhausted { reason, .. } => return Ok(run.exhausted(reason)),
+            DlpOutcome::Cancelled { .. } => {
+                return Ok(DlpOutcome::Cancelled { stats: run.stats() });
+            }
+        };
+
+        run.add_group_operations(pow_cost(&k));
+        if p.pow(&k) == *q {
+            Ok(run.found(k))
+        } else {
+            Ok(run.exhausted(Exhaustion::SearchSpace))
+        }
+    }
+}
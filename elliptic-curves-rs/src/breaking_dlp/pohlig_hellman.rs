@@ -0,0 +1,99 @@
+//! Pohlig-Hellman reduction for the Discrete Logarithm Problem
+//!
+//! If the order n of P factors as prod p_i^e_i, the logarithm can be
+//! computed modulo every p_i^e_i inside the subgroup of order p_i, one
+//! base-p_i digit at a time, and glued together with the CRT. The cost
+//! is dominated by the largest prime factor of n, which is why curves
+//! such as secp256k1 use a group of prime order, while F_p^* with a
+//! smooth p - 1 falls almost immediately.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::group::Group;
+use crate::core::integer::{crt, factorize};
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use std::marker::PhantomData;
+
+/// Pohlig-Hellman on top of the prime-order solver `S`, e.g.
+/// `PohligHellman<BabyStepGiantStep>` or `PohligHellman<PollardsRho>`.
+pub struct PohligHellman<S> {
+    _solver: PhantomData<S>,
+}
+
+impl<G: Group, S: DiscreteLog<G>> DiscreteLog<G> for PohligHellman<S> {
+    fn solve_with_order(
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
+
+        let mut run = SolverRun::new(config);
+        let mut residues = Vec::new();
+
+        for (prime, exponent) in factorize(order) {
+            // gamma = P^(n / p) has order p
+            let cofactor = order / &prime;
+            let gamma = p.pow(&cofactor);
+            run.add_group_operations(pow_cost(&cofactor));
+
+            // x = d_0 + d_1 p + ... + d_(e-1) p^(e-1)
+            let mut x = BigUint::zero();
+            let mut prime_power = BigUint::one();
+            for _ in 0..exponent {
+                if let Some(outcome) = run.check() {
+                    return Ok(outcome);
+                }
+
+                // h_k = (P^(-x) * Q)^(n / p^(k+1)) has order dividing p
+                let shift = order / (&prime_power * &prime);
+                let h = p.pow(&x).inverse().op(q).pow(&shift);
+                run.add_group_operations(pow_cost(&x) + pow_cost(&shift) + 2);
+
+                let digit = match S::solve_with_order(&gamma, &h, &prime, &run.nested_config())? {
+                    DlpOutcome::Found { logarithm, stats } => {
+                        run.absorb(&stats);
+                        logarithm
+                    }
+                    DlpOutcome::Exhausted { reason, stats } => {
+                        run.absorb(&stats);
+                        return Ok(run.exhausted(reason));
+                    }
+                    DlpOutcome::Cancelled { stats } => {
+                        run.absorb(&stats);
+                        return Ok(DlpOutcome::Cancelled { stats: run.stats() });
+                    }
+                };
+
+                x += digit * &prime_power;
+                prime_power *= &prime;
+            }
+            residues.push((x, prime_power));
+        }
+
+        let Some((k, _)) = crt(&residues) else {
+            return Ok(run.exhausted(Exhaustion::SearchSpace));
+        };
+
+        // Q may lie outside the subgroup generated by P
+        run.add_group_operations(pow_cost(&k));
+        if p.pow(&k) == *q {
+            Ok(run.found(k))
+        } else {
+            Ok(run.exhausted(Exhaustion::SearchSpace))
+        }
+    }
+}
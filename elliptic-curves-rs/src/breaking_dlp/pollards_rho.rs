@@ -1,168 +1,301 @@
 //! Pollard's rho algorithm for Discrete Logarithm Problem
 
-use crate::breaking_dlp::DiscreteLog;
-use crate::core::curve::Curve;
-use crate::core::point::CurvePoint;
-
-use ark_ff::{BigInteger, Field, PrimeField, Zero};
-use ark_std::{UniformRand, rand::thread_rng};
+use crate::breaking_dlp::{DiscreteLog, ResumableDiscreteLog};
+use crate::breaking_dlp::checkpoint::{Checkpoint, CheckpointError, parse_values};
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::group::Group;
+use crate::core::integer::{mod_sub, solve_linear_congruence};
+
+use num_bigint::{BigUint, RandBigInt};
+use rand::thread_rng;
+
+use std::path::Path;
 
 pub struct PollardsRho;
 
+/// Upper bound on the number of candidates tried when gcd(b2 - b1, n) > 1,
+/// which only happens for composite orders such as the one of F_p^*.
+const MAX_COLLISION_CANDIDATES: u64 = 1 << 16;
+
 /// X = aP + bQ
 #[derive(Debug)]
-struct IterationState<C: Curve> {
-    point: CurvePoint<C>,
-    a: C::ScalarField,
-    b: C::ScalarField,
+struct IterationState<G: Group> {
+    point: G,
+    a: BigUint,
+    b: BigUint,
 }
 
-impl<C: Curve> IterationState<C> {
-    fn new(
-        point: CurvePoint<C>,
-        a: C::ScalarField,
-        b: C::ScalarField,
-    ) -> Self {
+impl<G: Group> IterationState<G> {
+    fn new(point: G, a: BigUint, b: BigUint) -> Self {
         Self { point, a, b }
     }
+
+    fn from_coefficients(p: &G, q: &G, a: BigUint, b: BigUint) -> Self {
+        let point = p.pow(&a).op(&q.pow(&b));
+        Self::new(point, a, b)
+    }
+
+    fn cost(&self) -> u64 {
+        pow_cost(&self.a) + pow_cost(&self.b) + 1
+    }
 }
 
 /// pseudo-random function
-struct PseudoRandomFunction<C: Curve> {
-    p: CurvePoint<C>,
-    q: CurvePoint<C>,
+struct PseudoRandomFunction<'a, G: Group> {
+    order: &'a BigUint,
     partition_count: usize,
-    precomputed_points: Vec<CurvePoint<C>>,
-    a_coeffs: Vec<C::ScalarField>,
-    b_coeffs: Vec<C::ScalarField>,
+    precomputed_points: Vec<G>,
+    a_coeffs: Vec<BigUint>,
+    b_coeffs: Vec<BigUint>,
 }
 
-impl<C: Curve> PseudoRandomFunction<C> {
-    fn new(p: &CurvePoint<C>, q: &CurvePoint<C>) -> Self {
+impl<'a, G: Group> PseudoRandomFunction<'a, G> {
+    fn new(p: &G, q: &G, order: &'a BigUint) -> Self {
         let mut rng = thread_rng();
         let partition_count = 20;
 
-        let mut precomputed_points = Vec::with_capacity(partition_count);
-        let mut a_coeffs = Vec::with_capacity(partition_count);
-        let mut b_coeffs = Vec::with_capacity(partition_count);
+        let a_coeffs = (0..partition_count)
+            .map(|_| rng.gen_biguint_below(order))
+            .collect();
+        let b_coeffs = (0..partition_count)
+            .map(|_| rng.gen_biguint_below(order))
+            .collect();
 
-        // precompute a point R from R_0 to R_19
-        for i in 0..partition_count {
-            let a = C::ScalarField::rand(&mut rng);
-            let b = C::ScalarField::rand(&mut rng);
-
-            // R_i = aP + bQ
-            let r_i = p.mul_scalar(&a).add(&q.mul_scalar(&b));
+        Self::from_coefficients(p, q, order, a_coeffs, b_coeffs)
+    }
 
-            precomputed_points.push(r_i);
-            a_coeffs.push(a);
-            b_coeffs.push(b);
-        }
+    /// Rebuilds the walk from its coefficients, e.g. after a resume
+    fn from_coefficients(
+        p: &G,
+        q: &G,
+        order: &'a BigUint,
+        a_coeffs: Vec<BigUint>,
+        b_coeffs: Vec<BigUint>,
+    ) -> Self {
+        // precompute a point R from R_0 to R_19
+        // R_i = a_i * P + b_i * Q
+        let precomputed_points = a_coeffs
+            .iter()
+            .zip(&b_coeffs)
+            .map(|(a, b)| p.pow(a).op(&q.pow(b)))
+            .collect();
 
         Self {
-            p: p.clone(),
-            q: q.clone(),
-            partition_count,
+            order,
+            partition_count: a_coeffs.len(),
             precomputed_points,
             a_coeffs,
             b_coeffs,
         }
     }
 
-    /// When given point X, determine the point that belongs
-    fn get_partition(&self, point: &CurvePoint<C>) -> usize {
-        match point.inner.x() {
-            Some(x) => {
-                let bytes = x.into_bigint().to_bytes_be();
-                
-                let mut hash = 0usize;
-                for (i, &byte) in bytes.iter().take(8).enumerate() {
-                    hash ^= (byte as usize) << (i * 8);
-                }
+    fn cost(&self) -> u64 {
+        self.a_coeffs
+            .iter()
+            .zip(&self.b_coeffs)
+            .map(|(a, b)| pow_cost(a) + pow_cost(b) + 1)
+            .sum()
+    }
 
-                hash % self.partition_count
-            }
-            None => 0,
-        }
+    /// When given point X, determine the point that belongs
+    fn get_partition(&self, point: &G) -> usize {
+        // FNV-1a over the canonical encoding
+        let hash = point
+            .encode()
+            .iter()
+            .fold(0xcbf29ce484222325u64, |hash, &byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+
+        (hash % self.partition_count as u64) as usize
     }
 
     /// f(X) = X + R_j, where j = partition(X)
     /// R_j = a_j * P + b_j * Q
-    fn apply(&self, state: &IterationState<C>) -> IterationState<C> {
+    fn apply(&self, state: &IterationState<G>) -> IterationState<G> {
         let j = self.get_partition(&state.point);
 
-        let new_point = state.point.add(&self.precomputed_points[j]);
+        let new_point = state.point.op(&self.precomputed_points[j]);
 
-        let new_a = state.a + self.a_coeffs[j];
-        let new_b = state.b + self.b_coeffs[j];
+        let new_a = (&state.a + &self.a_coeffs[j]) % self.order;
+        let new_b = (&state.b + &self.b_coeffs[j]) % self.order;
 
         IterationState::new(new_point, new_a, new_b)
     }
 }
 
-impl<C: Curve> DiscreteLog<C> for PollardsRho {
-    fn solve(
-            p: &CurvePoint<C>,
-            q: &CurvePoint<C>,
-        ) -> (u64, <C as Curve>::ScalarField) {
-        if !C::is_on_curve(&p.inner) || !C::is_on_curve(&q.inner) {
-            panic!("p or q are not on curve");
-        }
-
-        let f = PseudoRandomFunction::new(p, q);
+/// Random starting point X_0 = aP + bQ
+fn random_start<G: Group>(p: &G, q: &G, order: &BigUint) -> IterationState<G> {
+    let mut rng = thread_rng();
+    let a = rng.gen_biguint_below(order);
+    let b = rng.gen_biguint_below(order);
+    IterationState::from_coefficients(p, q, a, b)
+}
 
-        let mut rng = thread_rng();
-        let start_a = C::ScalarField::rand(&mut rng);
-        let start_b = C::ScalarField::rand(&mut rng);
-        let start_point = p.mul_scalar(&start_a).add(&q.mul_scalar(&start_b));
+const SOLVER_NAME: &str = "pollards_rho";
+
+fn save_checkpoint<G: Group>(
+    path: &Path,
+    p: &G,
+    q: &G,
+    f: &PseudoRandomFunction<'_, G>,
+    tortoise: &IterationState<G>,
+    hare: &IterationState<G>,
+    run: &SolverRun<'_>,
+) -> Result<(), DlpError> {
+    let mut checkpoint = Checkpoint::new(SOLVER_NAME, p, q, f.order, run.stats());
+    for (a, b) in f.a_coeffs.iter().zip(&f.b_coeffs) {
+        checkpoint.push("partition", &[a, b]);
+    }
+    checkpoint.push("tortoise", &[&tortoise.a, &tortoise.b]);
+    checkpoint.push("hare", &[&hare.a, &hare.b]);
+    checkpoint.write(path)?;
+    Ok(())
+}
 
-        let mut tortoise = IterationState::new(start_point.clone(), start_a, start_b);
-        let mut hare = IterationState::new(start_point.clone(), start_a, start_b);
+/// Runs Floyd's cycle detection from the given walk positions until a
+/// useful collision is found or `run` says to stop.
+fn walk<G: Group>(
+    p: &G,
+    q: &G,
+    f: PseudoRandomFunction<'_, G>,
+    mut tortoise: IterationState<G>,
+    mut hare: IterationState<G>,
+    mut run: SolverRun<'_>,
+) -> Result<DlpOutcome<BigUint>, DlpError> {
+    let order = f.order;
+    // R_0..R_19, the tortoise and the hare
+    run.set_stored_elements(f.partition_count as u64 + 2);
+    loop {
+        if let Some(outcome) = run.check() {
+            if let Some(path) = run.checkpoint_path() {
+                save_checkpoint(path, p, q, &f, &tortoise, &hare, &run)?;
+            }
+            return Ok(outcome);
+        }
 
-        let mut steps = 0u64;
+        tortoise = f.apply(&tortoise);
+
+        hare = f.apply(&hare);
+        hare = f.apply(&hare);
+
+        run.add_group_operations(3);
+        run.step();
+
+        if tortoise.point == hare.point {
+            // tortoise: a1*P + b1*Q
+            // hare: a2*P + b2*Q
+            // tortoise = hare: a1*P + b1*Q = a2*P + b2*Q
+            //                  (a1-a2)*P = (b2-b1)*Q
+            // Q = k*P:         (a1-a2)*P = k*(b2-b1)*P
+            // k = (a1-a2)/(b2-b1) mod n
+            // If n is composite, (b2-b1) may not be invertible and every
+            // solution of the congruence is a candidate for k.
+
+            let a_diff = mod_sub(&tortoise.a, &hare.a, order);
+            let b_diff = mod_sub(&hare.b, &tortoise.b, order);
+
+            let candidates = solve_linear_congruence(
+                &b_diff,
+                &a_diff,
+                order,
+                MAX_COLLISION_CANDIDATES,
+            );
+            for k in candidates {
+                run.add_group_operations(pow_cost(&k));
+                if p.pow(&k) == *q {
+                    return Ok(run.found(k));
+                }
+            }
 
-        loop {
-            tortoise = f.apply(&tortoise);
+            // useless collision: both walks would keep meeting on the
+            // same cycle, so restart from a fresh random point
+            tortoise = random_start(p, q, order);
+            hare = IterationState::new(
+                tortoise.point.clone(),
+                tortoise.a.clone(),
+                tortoise.b.clone(),
+            );
+            run.add_group_operations(tortoise.cost());
+        }
 
-            hare = f.apply(&hare);
-            hare = f.apply(&hare);
+        if run.checkpoint_due()
+            && let Some(path) = run.checkpoint_path()
+        {
+            save_checkpoint(path, p, q, &f, &tortoise, &hare, &run)?;
+        }
+    }
+}
 
-            steps += 1;
-            
-            if steps % 10000 == 0 {
-                println!("Steps: {}", steps);
-            }
+impl<G: Group> DiscreteLog<G> for PollardsRho {
+    fn solve_with_order(
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
 
-            if tortoise.point.inner == hare.point.inner {
-                println!("Collision found at step {}", steps);
-                // tortoise: a1*P + b1*Q
-                // hare: a2*P + b2*Q
-                // tortoise = hare: a1*P + b1*Q = a2*P + b2*Q
-                //                  (a1-a2)*P = (b2-b1)*Q
-                // Q = k*P:         (a1-a2)*P = k*(b2-b1)*P
-                // k = (a1-a2)/(b2-b1) mod n
+        let mut run = SolverRun::new(config);
+        let f = PseudoRandomFunction::new(p, q, order);
+        run.add_group_operations(f.cost());
 
-                let a_diff = tortoise.a - hare.a;
-                let b_diff = hare.b - tortoise.b;
+        let tortoise = random_start(p, q, order);
+        run.add_group_operations(tortoise.cost());
+        let hare = IterationState::new(
+            tortoise.point.clone(),
+            tortoise.a.clone(),
+            tortoise.b.clone(),
+        );
 
-                println!("a_diff: {:?}, b_diff: {:?}", a_diff, b_diff);
+        walk(p, q, f, tortoise, hare, run)
+    }
+}
 
-                if b_diff.is_zero() {
-                    println!("b_diff is zero, retrying...");
-                    continue;
-                }
+impl<G: Group> ResumableDiscreteLog<G> for PollardsRho {
+    fn resume_with_order(
+            checkpoint: &Path,
+            p: &G,
+            q: &G,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        if !p.is_element() || !q.is_element() {
+            return Err(DlpError::NotInGroup);
+        }
 
-                if let Some(b_diff_inv) = b_diff.inverse() {
-                    let k = a_diff * b_diff_inv;
+        let checkpoint = Checkpoint::read(checkpoint)?;
+        checkpoint.verify(SOLVER_NAME, p, q, order)?;
 
-                    if &p.mul_scalar(&k) == q {
-                        return (steps, k);
-                    }
-                }
-            }
-            if steps >= 10_000_000 {
-                panic!("Failed to find discrete logarithm within 10m steps");
-            }
+        let mut a_coeffs = Vec::new();
+        let mut b_coeffs = Vec::new();
+        for values in checkpoint.records("partition") {
+            let [a, b] = parse_values(values)?;
+            a_coeffs.push(a);
+            b_coeffs.push(b);
         }
+        if a_coeffs.is_empty() {
+            return Err(CheckpointError::Malformed(
+                "missing `partition` records".to_string(),
+            ).into());
+        }
+        let [tortoise_a, tortoise_b] = parse_values(checkpoint.record("tortoise")?)?;
+        let [hare_a, hare_b] = parse_values(checkpoint.record("hare")?)?;
+
+        let mut run = SolverRun::resume(config, checkpoint.stats);
+        let f = PseudoRandomFunction::from_coefficients(p, q, order, a_coeffs, b_coeffs);
+        let tortoise = IterationState::from_coefficients(p, q, tortoise_a, tortoise_b);
+        let hare = IterationState::from_coefficients(p, q, hare_a, hare_b);
+        run.add_group_operations(f.cost() + tortoise.cost() + hare.cost());
+
+        walk(p, q, f, tortoise, hare, run)
     }
 }
@@ -0,0 +1,251 @@
+//! Discrete logarithms on singular cubics (cusp and node)
+//!
+//! If the discriminant of y^2 = x^3 + ax + b vanishes, the cubic has a
+//! repeated root alpha and (alpha, 0) is a singular point. The chord and
+//! tangent law still makes the other points a group, but it is just a
+//! finite field group in disguise. With X = x - alpha:
+//!
+//! - cusp, y^2 = X^3 (triple root): (x, y) -> X / y is an isomorphism
+//!   onto F_p^+, where the logarithm is a single division;
+//! - node, y^2 = X^2 (X + 3 alpha) (double root): with s^2 = 3 alpha,
+//!   (x, y) -> (y + sX) / (y - sX) is an isomorphism onto F_p^* when s
+//!   lies in F_p (split node), or onto the norm-1 subgroup of F_{p^2}^*
+//!   of order p + 1 when it does not (non-split node).
+//!
+//! Either way the curve offers no more security than the field it is
+//! defined over, which is why `Curve::is_singular` must be checked for
+//! parameters that come from an untrusted source.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::curve::Curve;
+use crate::core::extension::{ExtensionConfig, ExtensionField};
+use crate::core::field::PrimeField;
+use crate::core::group::{Group, Multiplicative};
+use crate::core::point::{CurvePoint, Point};
+
+use ark_ff::{Field as ArkField, One, PrimeField as ArkPrimeField, Zero};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+use std::marker::PhantomData;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SingularError {
+    #[error("the curve is non-singular")]
+    NonSingular,
+    #[error("base or target is not on the curve")]
+    NotInGroup,
+    #[error("the singular point itself is not a group element")]
+    SingularPoint,
+    #[error("base point is the point at infinity")]
+    TrivialBase,
+}
+
+/// Shape of a singular cubic, with alpha the repeated root
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Singularity<F> {
+    Cusp { alpha: F },
+    /// `split` when the tangent slopes s = +-sqrt(3 alpha) lie in F_p
+    Node { alpha: F, split: bool },
+}
+
+impl<F: Copy> Singularity<F> {
+    pub fn alpha(&self) -> F {
+        match self {
+            Singularity::Cusp { alpha } | Singularity::Node { alpha, .. } => *alpha,
+        }
+    }
+}
+
+/// None for a proper elliptic curve
+pub fn singularity<C: Curve>() -> Option<Singularity<C::BaseField>> {
+    if !C::is_singular() {
+        return None;
+    }
+    let (a, b) = (C::a(), C::b());
+    if a.is_zero() {
+        // then b = 0 as well: x^3 has the triple root 0
+        return Some(Singularity::Cusp { alpha: a });
+    }
+    // (x - alpha)^2 (x + 2 alpha) = x^3 - 3 alpha^2 x + 2 alpha^3
+    let two = C::BaseField::from_u64(2);
+    let three = C::BaseField::from_u64(3);
+    let alpha = -(three * b) * (two * a).inverse().expect("a != 0");
+    let split = (three * alpha).sqrt().is_some();
+    Some(Singularity::Node { alpha, split })
+}
+
+/// F_p[s] / (s^2 - 3 alpha) for the non-split node of `C`
+pub struct NodeExtension<C> {
+    _curve: PhantomData<C>,
+}
+
+impl<C: Curve + 'static> ExtensionConfig for NodeExtension<C> {
+    type BaseField = C::BaseField;
+
+    fn modulus() -> Vec<C::BaseField> {
+        let alpha = singularity::<C>()
+            .map(|singularity| singularity.alpha())
+            .unwrap_or_else(C::BaseField::zero);
+        vec![-(C::BaseField::from_u64(3) * alpha), C::BaseField::zero()]
+    }
+}
+
+/// P and Q mapped into the field group behind the singular cubic
+pub enum SingularImage<C: Curve + 'static> {
+    /// F_p^+
+    Additive { base: C::BaseField, target: C::BaseField },
+    /// F_p^*
+    Multiplicative {
+        base: Multiplicative<C::BaseField>,
+        target: Multiplicative<C::BaseField>,
+    },
+    /// norm-1 subgroup of F_{p^2}^*
+    Quadratic {
+        base: Multiplicative<ExtensionField<NodeExtension<C>>>,
+        target: Multiplicative<ExtensionField<NodeExtension<C>>>,
+    },
+}
+
+/// (X, y) with X = x - alpha, or None for the point at infinity
+type Shifted<F> = Option<(F, F)>;
+
+fn shifted<C: Curve>(
+    point: &CurvePoint<C>,
+    alpha: C::BaseField,
+) -> Result<Shifted<C::BaseField>, SingularError> {
+    match &point.inner {
+        Point::Infinity => Ok(None),
+        Point::Affine { x, y } if *x == alpha && y.is_zero() => Err(SingularError::SingularPoint),
+        Point::Affine { x, y } => Ok(Some((*x - alpha, *y))),
+    }
+}
+
+/// Applies the isomorphism of the module documentation to P and Q.
+pub fn reduce<C: Curve + 'static>(
+    p: &CurvePoint<C>,
+    q: &CurvePoint<C>,
+) -> Result<SingularImage<C>, SingularError> {
+    let singularity = singularity::<C>().ok_or(SingularError::NonSingular)?;
+    if !p.is_element() || !q.is_element() {
+        return Err(SingularError::NotInGroup);
+    }
+    if p.is_identity() {
+        return Err(SingularError::TrivialBase);
+    }
+    let alpha = singularity.alpha();
+    let (p, q) = (shifted(p, alpha)?, shifted(q, alpha)?);
+
+    let image = match singularity {
+        Singularity::Cusp { .. } => {
+            // y != 0 away from the cusp
+            let map = |point: Shifted<C::BaseField>| match point {
+                None => C::BaseField::zero(),
+                Some((x, y)) => x * y.inverse().expect("y != 0"),
+            };
+            SingularImage::Additive { base: map(p), target: map(q) }
+        }
+        Singularity::Node { split: true, .. } => {
+            let s = (C::BaseField::from_u64(3) * alpha).sqrt().expect("split node");
+            // y = +-sX only at the node itself
+            let map = |point: Shifted<C::BaseField>| match point {
+                None => Multiplicative(C::BaseField::one()),
+                Some((x, y)) => Multiplicative(
+                    (y + s * x) * (y - s * x).inverse().expect("not the node"),
+                ),
+            };
+            SingularImage::Multiplicative { base: map(p), target: map(q) }
+        }
+        Singularity::Node { split: false, .. } => {
+            let s = ExtensionField::<NodeExtension<C>>::generator();
+            let map = |point: Shifted<C::BaseField>| match point {
+                None => Multiplicative(ExtensionField::one()),
+                Some((x, y)) => {
+                    let y = ExtensionField::from_base(y);
+                    let sx = s.scale(&x);
+                    let denominator = y.sub(&sx).inverse().expect("not the node");
+                    Multiplicative(y.add(&sx).mul(&denominator))
+                }
+            };
+            SingularImage::Quadratic { base: map(p), target: map(q) }
+        }
+    };
+    Ok(image)
+}
+
+/// Logarithms on singular cubics: a division for the cusp, and the
+/// solver `S` in F_p^* or F_{p^2}^* for a node, e.g.
+/// `SingularCurveAttack<PollardsRho>`.
+pub struct SingularCurveAttack<S> {
+    _solver: PhantomData<S>,
+}
+
+impl<C, S> DiscreteLog<CurvePoint<C>> for SingularCurveAttack<S>
+where
+    C: Curve + 'static,
+    S: DiscreteLog<Multiplicative<C::BaseField>>
+        + DiscreteLog<Multiplicative<ExtensionField<NodeExtension<C>>>>,
+{
+    fn solve_with_order(
+            p: &CurvePoint<C>,
+            q: &CurvePoint<C>,
+            order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        let mut run = SolverRun::new(config);
+        let image = match reduce(p, q) {
+            Ok(image) => image,
+            Err(SingularError::NotInGroup | SingularError::SingularPoint) => {
+                return Err(DlpError::NotInGroup);
+            }
+            Err(_) => return Ok(run.exhausted(Exhaustion::SearchSpace)),
+        };
+        run.add_group_operations(2);
+
+        let outcome = match image {
+            SingularImage::Additive { base, target } => {
+                // k = target / base in F_p, which is the logarithm modulo p
+                let k: BigUint = (target * base.inverse().expect("base != 0"))
+                    .into_bigint()
+                    .into();
+                run.step();
+                return Ok(verify(p, q, k % order, run));
+            }
+            SingularImage::Multiplicative { base, target } => {
+                S::solve_with_order(&base, &target, order, &run.nested_config())?
+            }
+            SingularImage::Quadratic { base, target } => {
+                S::solve_with_order(&base, &target, order, &run.nested_config())?
+            }
+        };
+        run.absorb(outcome.stats());
+        match outcome {
+            DlpOutcome::Found { logarithm, .. } => Ok(verify(p, q, logarithm, run)),
+            DlpOutcome::Exhausted { reason, .. } => Ok(run.exhausted(reason)),
+            DlpOutcome::Cancelled { .. } => Ok(DlpOutcome::Cancelled { stats: run.stats() }),
+        }
+    }
+}
+
+/// Q may lie outside the subgroup generated by P
+fn verify<C: Curve>(
+    p: &CurvePoint<C>,
+    q: &CurvePoint<C>,
+    k: BigUint,
+    mut run: SolverRun<'_>,
+) -> DlpOutcome<BigUint> {
+    run.add_group_operations(pow_cost(&k));
+    if p.pow(&k) == *q {
+        run.found(k)
+    } else {
+        run.exhausted(Exhaustion::SearchSpace)
+    }
+}
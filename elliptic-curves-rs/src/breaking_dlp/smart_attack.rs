@@ -0,0 +1,233 @@
+//! Smart's attack on anomalous curves
+//!
+//! A curve is anomalous when #E(F_p) = p. Then the points lifted to the
+//! p-adic curve E(Q_p) satisfy p * P' in E_1, the kernel of reduction,
+//! and the p-adic elliptic logarithm psi: E_1 -> pZ_p, psi(x, y) = -x/y,
+//! is a group homomorphism. It turns the ECDLP into a division:
+//!
+//! ```text
+//! k = psi(p * Q') / psi(p * P') (mod p)
+//! ```
+//!
+//! Only precision modulo p^2 is needed, so the whole attack is one scalar
+//! multiplication over Z/p^2 Z per point, i.e. polynomial time, whatever
+//! the size of p. It fails only for the canonical lift, where psi(p * P')
+//! vanishes; a different lift of the coefficient a avoids it.
+
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverRun,
+    pow_cost,
+};
+use crate::core::curve::Curve;
+use crate::core::group::Group;
+use crate::core::point::{CurvePoint, Point};
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+use num_traits::Zero;
+use thiserror::Error;
+
+/// Number of lifts y^2 = x^3 + (a + r p) x + b tried before giving up
+const MAX_LIFTS: u64 = 8;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SmartAttackError {
+    #[error("curve is not anomalous: order {order} != field prime {modulus}")]
+    NotAnomalous { order: BigUint, modulus: BigUint },
+    #[error("base or target is not on the curve")]
+    NotInGroup,
+    #[error("base point is the point at infinity")]
+    TrivialBase,
+    #[error("every lift of the curve was canonical")]
+    CanonicalLift,
+}
+
+/// #E(F_p) = p (the order of the generator is the whole group for the
+/// curves in this crate)
+pub fn is_anomalous<C: Curve>() -> bool {
+    let modulus: BigUint = <C::BaseField as ArkPrimeField>::MODULUS.into();
+    let order: BigUint = C::order().into();
+    modulus == order
+}
+
+/// Affine point over Z/p^2 Z
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LiftedPoint {
+    Infinity,
+    Affine { x: BigUint, y: BigUint },
+}
+
+/// y^2 = x^3 + a x + b over Z/p^2 Z
+struct LiftedCurve {
+    p: BigUint,
+    p2: BigUint,
+    a: BigUint,
+}
+
+/// A non-unit denominator: both points reduce to the same point mod p
+#[derive(Debug)]
+struct NotInvertible;
+
+impl LiftedCurve {
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p2 - b % &self.p2) % &self.p2
+    }
+
+    fn inverse(&self, value: &BigUint) -> Result<BigUint, NotInvertible> {
+        value.modinv(&self.p2).ok_or(NotInvertible)
+    }
+
+    /// Hensel lift of (x, y) in E(F_p): keep x and solve
+    /// (y + t p)^2 = x^3 + a x + b (mod p^2) for t.
+    fn lift(&self, x: &BigUint, y: &BigUint, b: &BigUint) -> LiftedPoint {
+        let rhs = (x * x * x + &self.a * x + b) % &self.p2;
+        // rhs - y^2 is divisible by p since the point is on E(F_p)
+        let t = self.sub(&rhs, &(y * y)) / &self.p;
+        let two_y_inv = (BigUint::from(2u32) * y)
+            .modinv(&self.p)
+            .expect("the order is odd, so y != 0");
+        let t = (t * two_y_inv) % &self.p;
+        LiftedPoint::Affine { x: x.clone(), y: y + t * &self.p }
+    }
+
+    /// Fails when the points are distinct mod p^2 but equal (or
+    /// opposite) mod p, i.e. when their difference lies in E_1.
+    fn add(&self, p: &LiftedPoint, q: &LiftedPoint) -> Result<LiftedPoint, NotInvertible> {
+        use LiftedPoint::*;
+        let (x1, y1, x2, y2) = match (p, q) {
+            (Infinity, _) => return Ok(q.clone()),
+            (_, Infinity) => return Ok(p.clone()),
+            (Affine { x: x1, y: y1 }, Affine { x: x2, y: y2 }) => (x1, y1, x2, y2),
+        };
+        let m = if x1 == x2 && y1 == y2 {
+            let numerator = (BigUint::from(3u32) * x1 * x1 + &self.a) % &self.p2;
+            numerator * self.inverse(&(BigUint::from(2u32) * y1))?
+        } else {
+            self.sub(y2, y1) * self.inverse(&self.sub(x2, x1))?
+        } % &self.p2;
+        let x3 = self.sub(&self.sub(&(&m * &m), x1), x2);
+        let y3 = self.sub(&(&m * self.sub(x1, &x3)), y1);
+        Ok(Affine { x: x3, y: y3 })
+    }
+
+    fn mul(&self, point: &LiftedPoint, k: &BigUint) -> Result<LiftedPoint, NotInvertible> {
+        let mut result = LiftedPoint::Infinity;
+        for i in (0..k.bits()).rev() {
+            result = self.add(&result, &result)?;
+            if k.bit(i) {
+                result = self.add(&result, point)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// psi(p * P') / p mod p, or None if it vanishes.
+    ///
+    /// p * P' = (p - 1) * P' + P' where both summands reduce to -P and P,
+    /// so x2 - x1 = p u and the slope is lambda = N / (p u). The sum has
+    /// -x/y = 1/lambda (1 + O(p^2)) = p u / N, hence psi / p = u / N.
+    fn elliptic_log(&self, point: &LiftedPoint) -> Option<BigUint> {
+        let almost = self.mul(point, &(&self.p - 1u32)).ok()?;
+        let (LiftedPoint::Affine { x: x1, y: y1 }, LiftedPoint::Affine { x: x2, y: y2 })
+            = (&almost, point)
+        else {
+            return None;
+        };
+        let u = self.sub(x2, x1) / &self.p;
+        let n = self.sub(y2, y1) % &self.p;
+        let log = (u * n.modinv(&self.p)?) % &self.p;
+        (!log.is_zero()).then_some(log)
+    }
+}
+
+fn coordinates<C: Curve>(point: &CurvePoint<C>) -> Option<(BigUint, BigUint)> {
+    match &point.inner {
+        Point::Infinity => None,
+        Point::Affine { x, y } => Some((x.into_bigint().into(), y.into_bigint().into())),
+    }
+}
+
+/// Solves k * P = Q on an anomalous curve.
+pub fn smart_attack<C: Curve>(
+    p: &CurvePoint<C>,
+    q: &CurvePoint<C>,
+) -> Result<BigUint, SmartAttackError> {
+    let modulus: BigUint = <C::BaseField as ArkPrimeField>::MODULUS.into();
+    if !is_anomalous::<C>() {
+        return Err(SmartAttackError::NotAnomalous { order: C::order().into(), modulus });
+    }
+    if !p.is_element() || !q.is_element() {
+        return Err(SmartAttackError::NotInGroup);
+    }
+    let Some((px, py)) = coordinates(p) else {
+        return Err(SmartAttackError::TrivialBase);
+    };
+    let Some((qx, qy)) = coordinates(q) else {
+        return Ok(BigUint::zero());
+    };
+
+    let a: BigUint = C::a().into_bigint().into();
+    let b: BigUint = C::b().into_bigint().into();
+    let p2 = &modulus * &modulus;
+    for r in 0..MAX_LIFTS {
+        // r = 0 lifts the coefficients as integers, which can happen to
+        // be the canonical lift; the others move a by a multiple of p
+        let curve = LiftedCurve {
+            a: (&a + &modulus * r) % &p2,
+            p: modulus.clone(),
+            p2: p2.clone(),
+        };
+        let lifted_p = curve.lift(&px, &py, &b);
+        let lifted_q = curve.lift(&qx, &qy, &b);
+
+        let (Some(log_p), Some(log_q)) =
+            (curve.elliptic_log(&lifted_p), curve.elliptic_log(&lifted_q))
+        else {
+            continue;
+        };
+        let k = (log_q * log_p.modinv(&modulus).expect("modulus is prime")) % &modulus;
+        if p.pow(&k) == *q {
+            return Ok(k);
+        }
+    }
+    Err(SmartAttackError::CanonicalLift)
+}
+
+pub struct SmartAttack;
+
+impl<C: Curve> DiscreteLog<CurvePoint<C>> for SmartAttack {
+    /// Non-anomalous curves exhaust the search space right away; call
+    /// `smart_attack` directly to see why.
+    fn solve_with_order(
+            p: &CurvePoint<C>,
+            q: &CurvePoint<C>,
+            _order: &BigUint,
+            config: &SolverConfig,
+        ) -> Result<DlpOutcome<BigUint>, DlpError> {
+        let mut run = SolverRun::new(config);
+        if let Some(outcome) = run.check() {
+            return Ok(outcome);
+        }
+        let result = smart_attack(p, q);
+        run.step();
+
+        match result {
+            Ok(k) => {
+                // two scalar multiplications over Z/p^2 Z and the check
+                let modulus: BigUint = <C::BaseField as ArkPrimeField>::MODULUS.into();
+                run.add_group_operations(2 * pow_cost(&modulus) + pow_cost(&k));
+                Ok(run.found(k))
+            }
+            Err(SmartAttackError::NotInGroup) => Err(DlpError::NotInGroup),
+            Err(SmartAttackError::TrivialBase) if q.is_identity() => {
+                Ok(run.found(BigUint::zero()))
+            }
+            Err(_) => Ok(run.exhausted(Exhaustion::SearchSpace)),
+        }
+    }
+}
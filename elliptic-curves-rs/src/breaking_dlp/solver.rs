@@ -0,0 +1,347 @@
+//! Shared run-time controls for Discrete Logarithm solvers
+//!
+//! Every solver in `breaking_dlp` is driven by a `SolverConfig` (step
+//! budget, wall-clock timeout, progress observer and cancellation token)
+//! and reports a `DlpOutcome` together with `SolverStats`, so that long
+//! experiments can be stopped, monitored and compared from outside.
+
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use num_bigint::BigUint;
+use thiserror::Error;
+
+use crate::breaking_dlp::checkpoint::{CheckpointConfig, CheckpointError};
+
+/// Errors that prevent a solver from starting or from saving its state.
+#[derive(Debug, Error)]
+pub enum DlpError {
+    #[error("base or target is not an element of the group")]
+    NotInGroup,
+    #[error(transparent)]
+    Checkpoint(#[from] CheckpointError),
+}
+
+/// Counters collected while a solver runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SolverStats {
+    /// Number of iterations of the solver's main loop
+    pub steps: u64,
+    /// Number of point additions/doublings/scalar multiplications issued
+    pub group_operations: u64,
+    /// Largest number of group elements held in memory at once
+    pub stored_elements: u64,
+    /// Wall-clock time spent inside `solve`
+    pub elapsed: Duration,
+}
+
+/// Why a solver gave up without finding the logarithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exhaustion {
+    /// `SolverConfig::max_steps` was reached
+    StepBudget,
+    /// `SolverConfig::timeout` elapsed
+    Timeout,
+    /// The algorithm itself ran out of candidates
+    SearchSpace,
+}
+
+/// Structured result of `DiscreteLog::solve`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DlpOutcome<F> {
+    Found { logarithm: F, stats: SolverStats },
+    Exhausted { reason: Exhaustion, stats: SolverStats },
+    Cancelled { stats: SolverStats },
+}
+
+impl<F> DlpOutcome<F> {
+    pub fn stats(&self) -> &SolverStats {
+        match self {
+            Self::Found { stats, .. }
+            | Self::Exhausted { stats, .. }
+            | Self::Cancelled { stats } => stats,
+        }
+    }
+
+    pub fn logarithm(&self) -> Option<&F> {
+        match self {
+            Self::Found { logarithm, .. } => Some(logarithm),
+            _ => None,
+        }
+    }
+
+    pub fn is_found(&self) -> bool {
+        matches!(self, Self::Found { .. })
+    }
+
+    pub fn map<T>(self, f: impl FnOnce(F) -> T) -> DlpOutcome<T> {
+        match self {
+            Self::Found { logarithm, stats } => {
+                DlpOutcome::Found { logarithm: f(logarithm), stats }
+            }
+            Self::Exhausted { reason, stats } => DlpOutcome::Exhausted { reason, stats },
+            Self::Cancelled { stats } => DlpOutcome::Cancelled { stats },
+        }
+    }
+}
+
+/// Receives a snapshot of the statistics every
+/// `SolverConfig::progress_interval` steps.
+pub trait ProgressObserver: Send + Sync {
+    fn on_progress(&self, stats: &SolverStats);
+}
+
+impl<T> ProgressObserver for T
+where
+    T: Fn(&SolverStats) + Send + Sync,
+{
+    fn on_progress(&self, stats: &SolverStats) {
+        self(stats)
+    }
+}
+
+/// Reports the statistics of a nested run on top of those of its parent,
+/// so that step counts never go backwards across a hand-off.
+struct NestedObserver {
+    parent: SolverStats,
+    observer: Arc<dyn ProgressObserver>,
+}
+
+impl ProgressObserver for NestedObserver {
+    fn on_progress(&self, stats: &SolverStats) {
+        let mut total = self.parent;
+        total.steps += stats.steps;
+        total.group_operations += stats.group_operations;
+        total.stored_elements = total.stored_elements.max(stats.stored_elements);
+        total.elapsed += stats.elapsed;
+        self.observer.on_progress(&total);
+    }
+}
+
+/// Cloneable flag shared between a running solver and its controller.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits and hooks applied to a single `solve` call.
+///
+/// The default configuration runs without any budget, which matches the
+/// behaviour of the original solvers, so long-running tests must set at
+/// least one of `max_steps` or `timeout`.
+#[derive(Clone)]
+pub struct SolverConfig {
+    pub max_steps: Option<u64>,
+    pub timeout: Option<Duration>,
+    pub progress_interval: u64,
+    pub observer: Option<Arc<dyn ProgressObserver>>,
+    pub cancellation: Option<CancellationToken>,
+    pub checkpoint: Option<CheckpointConfig>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            max_steps: None,
+            timeout: None,
+            progress_interval: 10_000,
+            observer: None,
+            cancellation: None,
+            checkpoint: None,
+        }
+    }
+}
+
+impl fmt::Debug for SolverConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SolverConfig")
+            .field("max_steps", &self.max_steps)
+            .field("timeout", &self.timeout)
+            .field("progress_interval", &self.progress_interval)
+            .field("observer", &self.observer.is_some())
+            .field("cancellation", &self.cancellation)
+            .field("checkpoint", &self.checkpoint)
+            .finish()
+    }
+}
+
+impl SolverConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_progress_interval(mut self, interval: u64) -> Self {
+        self.progress_interval = interval.max(1);
+        self
+    }
+
+    pub fn with_observer<O>(mut self, observer: O) -> Self
+    where
+        O: ProgressObserver + 'static,
+    {
+        self.observer = Some(Arc::new(observer));
+        self
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Periodically saves the solver state to `checkpoint.path`; the
+    /// state is also saved when the run stops without a result.
+    pub fn with_checkpoint(mut self, checkpoint: CheckpointConfig) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+}
+
+/// Book-keeping helper used inside the solvers' main loops.
+///
+/// Call `check` before each iteration, which returns `Some(outcome)` when
+/// the run must stop because of the budget, the timeout or a
+/// cancellation, and `step` after it.
+pub(crate) struct SolverRun<'a> {
+    config: &'a SolverConfig,
+    started: Instant,
+    stats: SolverStats,
+}
+
+impl<'a> SolverRun<'a> {
+    pub(crate) fn new(config: &'a SolverConfig) -> Self {
+        Self::resume(config, SolverStats::default())
+    }
+
+    /// Continue counting from previously recorded statistics.
+    pub(crate) fn resume(config: &'a SolverConfig, stats: SolverStats) -> Self {
+        Self {
+            config,
+            started: Instant::now(),
+            stats,
+        }
+    }
+
+    /// Whether the last `step` crossed a checkpoint interval.
+    pub(crate) fn checkpoint_due(&self) -> bool {
+        match &self.config.checkpoint {
+            Some(checkpoint) => self.stats.steps.is_multiple_of(checkpoint.interval.max(1)),
+            None => false,
+        }
+    }
+
+    pub(crate) fn checkpoint_path(&self) -> Option<&'a Path> {
+        self.config.checkpoint.as_ref().map(|c| c.path.as_path())
+    }
+
+    pub(crate) fn set_stored_elements(&mut self, count: u64) {
+        self.stats.stored_elements = self.stats.stored_elements.max(count);
+    }
+
+    pub(crate) fn add_group_operations(&mut self, count: u64) {
+        self.stats.group_operations += count;
+    }
+
+    /// Accounts for the work of a nested solver run (e.g. Pohlig-Hellman).
+    pub(crate) fn absorb(&mut self, stats: &SolverStats) {
+        self.stats.steps += stats.steps;
+        self.stats.group_operations += stats.group_operations;
+        self.set_stored_elements(stats.stored_elements);
+    }
+
+    /// A copy of the configuration for a nested solver run, with the
+    /// remaining step and time budget and without checkpointing. The
+    /// observer keeps seeing totals that include this run's work so far.
+    pub(crate) fn nested_config(&self) -> SolverConfig {
+        let mut config = self.config.clone();
+        config.checkpoint = None;
+        config.observer = self.config.observer.clone().map(|observer| {
+            Arc::new(NestedObserver { parent: self.stats(), observer }) as Arc<dyn ProgressObserver>
+        });
+        config.max_steps = self
+            .config
+            .max_steps
+            .map(|max| max.saturating_sub(self.stats.steps));
+        config.timeout = self
+            .config
+            .timeout
+            .map(|timeout| timeout.saturating_sub(self.stats().elapsed));
+        config
+    }
+
+    pub(crate) fn stats(&self) -> SolverStats {
+        let mut stats = self.stats;
+        stats.elapsed += self.started.elapsed();
+        stats
+    }
+
+    /// Checks cancellation and budgets before the next iteration.
+    pub(crate) fn check<F>(&mut self) -> Option<DlpOutcome<F>> {
+        if let Some(token) = &self.config.cancellation
+            && token.is_cancelled()
+        {
+            return Some(DlpOutcome::Cancelled { stats: self.stats() });
+        }
+        if let Some(max_steps) = self.config.max_steps
+            && self.stats.steps >= max_steps
+        {
+            return Some(self.exhausted(Exhaustion::StepBudget));
+        }
+        if let Some(timeout) = self.config.timeout
+            && self.stats.elapsed + self.started.elapsed() >= timeout
+        {
+            return Some(self.exhausted(Exhaustion::Timeout));
+        }
+        None
+    }
+
+    /// Records one finished iteration and notifies the observer.
+    pub(crate) fn step(&mut self) {
+        self.stats.steps += 1;
+        if self.stats.steps.is_multiple_of(self.config.progress_interval.max(1))
+            && let Some(observer) = &self.config.observer
+        {
+            let stats = self.stats();
+            observer.on_progress(&stats);
+        }
+    }
+
+    pub(crate) fn found<F>(&self, logarithm: F) -> DlpOutcome<F> {
+        DlpOutcome::Found { logarithm, stats: self.stats() }
+    }
+
+    pub(crate) fn exhausted<F>(&self, reason: Exhaustion) -> DlpOutcome<F> {
+        DlpOutcome::Exhausted { reason, stats: self.stats() }
+    }
+}
+
+/// Number of group operations of a square-and-multiply exponentiation.
+pub(crate) fn pow_cost(exponent: &BigUint) -> u64 {
+    exponent.bits() + exponent.count_ones()
+}
@@ -0,0 +1,165 @@
+use crate::breaking_dlp::brute_force::BruteForce;
+use crate::breaking_dlp::checkpoint::{
+    CHECKPOINT_VERSION,
+    Checkpoint,
+    CheckpointConfig,
+    CheckpointError,
+};
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::{DiscreteLog, ResumableDiscreteLog};
+use crate::breaking_dlp::solver::{DlpError, DlpOutcome, Exhaustion, SolverConfig, SolverStats};
+use crate::core::curve::Curve;
+use crate::curves::toy::toy::{
+    FrToy16,
+    FrToy20,
+    FrToy28,
+    Toy16Curve,
+    Toy20Curve,
+    Toy28Curve,
+};
+
+use num_bigint::BigUint;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// A checkpoint file in the temp directory, removed when the test ends
+/// however it ends
+struct TempCheckpoint(PathBuf);
+
+impl TempCheckpoint {
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempCheckpoint {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn checkpoint_path(name: &str) -> TempCheckpoint {
+    TempCheckpoint(std::env::temp_dir().join(format!(
+        "ecc-checkpoint-{}-{}.txt",
+        name,
+        std::process::id(),
+    )))
+}
+
+#[test]
+fn test_pollards_rho_resume_from_checkpoint() {
+    let file = checkpoint_path("rho");
+    let path = file.path();
+    let g = Toy28Curve::generator();
+    let k = FrToy28::from(123_456_789u64);
+    let q = g.mul_scalar(&k);
+
+    let config = SolverConfig::new()
+        .with_max_steps(50)
+        .with_checkpoint(CheckpointConfig::new(path, 20));
+    let outcome = PollardsRho::solve(&g, &q, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+
+    let saved = Checkpoint::read(path).unwrap();
+    assert_eq!(saved.stats.steps, 50);
+    assert_eq!(saved.records("partition").count(), 20);
+    // the partition and the two walkers
+    assert_eq!(saved.stats.stored_elements, 22);
+
+    let outcome = PollardsRho::resume(path, &g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    assert!(outcome.stats().steps > 50);
+    assert_eq!(outcome.stats().stored_elements, 22);
+}
+
+#[test]
+fn test_brute_force_resume_from_checkpoint() {
+    let file = checkpoint_path("brute-force");
+    let path = file.path();
+    let g = Toy16Curve::generator();
+    let k = FrToy16::from(4242u64);
+    let q = g.mul_scalar(&k);
+
+    let config = SolverConfig::new()
+        .with_max_steps(10)
+        .with_checkpoint(CheckpointConfig::new(path, 1000));
+    let outcome = BruteForce::solve(&g, &q, &config).unwrap();
+    if outcome.is_found() {
+        // the random start landed within 10 steps of the answer
+        assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+        return;
+    }
+    assert_eq!(Checkpoint::read(path).unwrap().stats.steps, 10);
+
+    let outcome = BruteForce::resume(path, &g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+}
+
+#[test]
+fn test_checkpoint_rejects_other_problems() {
+    let file = checkpoint_path("mismatch");
+    let path = file.path();
+    let g = Toy16Curve::generator();
+    let q = g.mul_scalar(&FrToy16::from(777u64));
+    let config = SolverConfig::new()
+        .with_max_steps(1)
+        .with_checkpoint(CheckpointConfig::new(path, 1));
+    PollardsRho::solve(&g, &q, &config).unwrap();
+
+    // different target point
+    let other_q = g.double();
+    let err = PollardsRho::resume(path, &g, &other_q, &config).unwrap_err();
+    assert!(matches!(err, DlpError::Checkpoint(CheckpointError::TargetMismatch)));
+
+    // different group
+    let g20 = Toy20Curve::generator();
+    let q20 = g20.mul_scalar(&FrToy20::from(777u64));
+    let err = PollardsRho::resume(path, &g20, &q20, &config).unwrap_err();
+    assert!(matches!(err, DlpError::Checkpoint(CheckpointError::GroupMismatch)));
+
+    // different solver
+    let err = BruteForce::resume(path, &g, &q, &config).unwrap_err();
+    assert!(matches!(
+        err,
+        DlpError::Checkpoint(CheckpointError::SolverMismatch { .. })
+    ));
+
+    // unknown format version
+    let text = fs::read_to_string(path).unwrap();
+    let version = format!("version {}\n", CHECKPOINT_VERSION);
+    fs::write(path, text.replace(&version, "version 99\n")).unwrap();
+    let err = PollardsRho::resume(path, &g, &q, &config).unwrap_err();
+    assert!(matches!(
+        err,
+        DlpError::Checkpoint(CheckpointError::UnsupportedVersion { found: 99, .. })
+    ));
+}
+
+#[test]
+fn test_checkpoint_text_round_trip() {
+    let g = Toy16Curve::generator();
+    let q = g.double();
+    let order = BigUint::from(41233u32);
+    let stats = SolverStats {
+        steps: 1234,
+        group_operations: 3702,
+        stored_elements: 22,
+        elapsed: Duration::from_millis(5321),
+    };
+    let mut checkpoint = Checkpoint::new("pollards_rho", &g, &q, &order, stats);
+    checkpoint.push("tortoise", &[BigUint::from(1u32), BigUint::from(2u32)]);
+    checkpoint.push("hare", &[BigUint::from(3u32), BigUint::from(41232u32)]);
+
+    let parsed = Checkpoint::from_text(&checkpoint.to_text()).unwrap();
+    assert_eq!(parsed, checkpoint);
+    assert_eq!(parsed.stats.stored_elements, 22);
+    assert!(matches!(
+        Checkpoint::from_text("not a checkpoint"),
+        Err(CheckpointError::Malformed(_))
+    ));
+}
@@ -0,0 +1,72 @@
+use crate::breaking_dlp::complexity::{Algorithm, Experiment, fit, run_once};
+use crate::breaking_dlp::solver::SolverConfig;
+
+#[test]
+fn test_run_once_records_a_found_run() {
+    let record = run_once(Algorithm::PollardsRho, 20, &SolverConfig::new()).unwrap();
+    assert!(record.found);
+    assert_eq!(record.bits, 20);
+    assert!(record.steps > 0);
+    assert!(record.group_operations >= record.steps);
+    assert!(record.memory_bytes > record.stored_elements);
+
+    assert!(run_once(Algorithm::PollardsRho, 17, &SolverConfig::new()).is_none());
+}
+
+#[test]
+fn test_baby_step_giant_step_is_square_root() {
+    let report = Experiment::new(28, 3)
+        .with_algorithms(&[Algorithm::BabyStepGiantStep])
+        .run();
+    assert_eq!(report.records.len(), 4 * 3);
+    assert!(report.records.iter().all(|r| r.found));
+
+    let fit = &report.fits[0];
+    assert_eq!(fit.algorithm, Algorithm::BabyStepGiantStep);
+    assert!((fit.exponent - 0.5).abs() < 0.1, "exponent {}", fit.exponent);
+    // m baby steps and at most m giant steps
+    assert!(fit.steps_per_sqrt_n >= 1.0 && fit.steps_per_sqrt_n <= 2.1);
+}
+
+#[test]
+fn test_brute_force_is_linear() {
+    let report = Experiment::new(20, 4)
+        .with_algorithms(&[Algorithm::BruteForce])
+        .run();
+    let fit = fit(&report.records, Algorithm::BruteForce).unwrap();
+    // the random start makes every run a uniform sample of [0, n)
+    assert!(fit.steps_per_n < 1.0);
+    assert!(fit.steps_per_sqrt_n > fit.steps_per_n);
+}
+
+#[test]
+fn test_step_budget_marks_runs_not_found() {
+    let report = Experiment::new(28, 1)
+        .with_algorithms(&[Algorithm::BruteForce])
+        .with_max_steps(10)
+        .run();
+    // brute force is capped at 24 bits
+    assert_eq!(report.records.len(), 3);
+    assert!(report.records.iter().all(|r| r.steps <= 10));
+    assert!(fit(&report.records, Algorithm::Kangaroo).is_none());
+}
+
+#[test]
+fn test_report_formats() {
+    let report = Experiment::new(20, 2)
+        .with_algorithms(&[Algorithm::Kangaroo, Algorithm::PollardsRho])
+        .run();
+
+    let csv = report.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 1 + report.records.len());
+    assert!(lines[0].starts_with("algorithm,bits,order,steps"));
+    assert!(lines.iter().skip(1).all(|line| line.split(',').count() == 9));
+    assert!(lines[1].starts_with("kangaroo,16,41233,"));
+
+    let json = report.to_json();
+    assert!(json.starts_with("{\n  \"runs\": ["));
+    assert_eq!(json.matches("\"algorithm\"").count(), report.records.len() + report.fits.len());
+    assert!(json.contains("\"order\":\"649633\""));
+    assert!(json.contains("\"exponent\":"));
+}
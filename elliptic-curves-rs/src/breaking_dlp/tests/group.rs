@@ -0,0 +1,153 @@
+use crate::breaking_dlp::baby_step_giant_step::BabyStepGiantStep;
+use crate::breaking_dlp::brute_force::BruteForce;
+use crate::breaking_dlp::pohlig_hellman::PohligHellman;
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig, SolverStats};
+use crate::core::curve::Curve;
+use crate::core::group::{Group, Multiplicative};
+use crate::core::point::CurvePoint;
+use crate::curves::toy::toy::{
+    FqToy24,
+    FqToy56,
+    FrToy24,
+    Toy24Curve,
+};
+use crate::curves::weak::weak::{FqSupersingular, FrSupersingular, SupersingularCurve};
+
+use num_bigint::BigUint;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_bsgs_on_curve() {
+    let g = Toy24Curve::generator();
+    let k = FrToy24::from(8_765_432u64);
+    let q = g.mul_scalar(&k);
+
+    let outcome = BabyStepGiantStep::solve(&g, &q, &SolverConfig::default()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    // at most 2 * ceil(sqrt(n)) steps
+    assert!(outcome.stats().steps <= 2 * 3008);
+}
+
+#[test]
+fn test_bsgs_reports_missing_logarithm() {
+    // 7 generates F_p^*, while 7^2 only generates the subgroup of index 2
+    let g = Multiplicative(FqToy24::from(7u64)).pow(&BigUint::from(2u32));
+    let q = Multiplicative(FqToy24::from(7u64));
+    let order = <Multiplicative<FqToy24> as Group>::order() / 2u32;
+
+    let outcome = BabyStepGiantStep::solve_with_order(&g, &q, &order, &SolverConfig::default())
+        .unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::SearchSpace, .. }
+    ));
+}
+
+#[test]
+fn test_same_solvers_on_multiplicative_group() {
+    // F_p^* of the 24-bit toy field, generated by 7
+    let g = Multiplicative(FqToy24::from(7u64));
+    let k = BigUint::from(5_555_555u32);
+    let q = g.pow(&k);
+    let config = SolverConfig::default();
+
+    let outcome = BabyStepGiantStep::solve(&g, &q, &config).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+
+    // p - 1 is composite, so rho has to handle non-invertible b2 - b1
+    let outcome = PollardsRho::solve(&g, &q, &config).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+
+    let small = g.pow(&BigUint::from(100u32));
+    let outcome = BruteForce::solve(&g, &small, &config.clone().with_max_steps(10_000_000))
+        .unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(100u32)));
+}
+
+#[test]
+fn test_pohlig_hellman_on_smooth_multiplicative_group() {
+    // p - 1 = 2 * 3 * 97 * 113 * 55057 * 12863113 for the 56-bit field,
+    // so the work is about sqrt(12863113) instead of sqrt(2^56)
+    let g = Multiplicative(FqToy56::from(3u64));
+    let k = BigUint::from(31_415_926_535_897_932u64);
+    let q = g.pow(&k);
+
+    let outcome = PohligHellman::<BabyStepGiantStep>::solve(&g, &q, &SolverConfig::default())
+        .unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+    assert!(outcome.stats().steps < 20_000);
+
+    let outcome = PohligHellman::<PollardsRho>::solve(&g, &q, &SolverConfig::default())
+        .unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+}
+
+#[test]
+fn test_pohlig_hellman_progress_is_cumulative() {
+    let g = Multiplicative(FqToy56::from(3u64));
+    let q = g.pow(&BigUint::from(31_415_926_535_897_932u64));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let observer_seen = Arc::clone(&seen);
+    let config = SolverConfig::new()
+        .with_progress_interval(100)
+        .with_observer(move |stats: &SolverStats| observer_seen.lock().unwrap().push(stats.steps));
+
+    let outcome = PohligHellman::<BabyStepGiantStep>::solve(&g, &q, &config).unwrap();
+    // the sub-solvers report totals, not their own fresh counters
+    let steps = seen.lock().unwrap().clone();
+    assert!(!steps.is_empty());
+    assert!(steps.is_sorted());
+    assert!(steps.last() <= Some(&outcome.stats().steps));
+}
+
+#[test]
+fn test_pohlig_hellman_on_prime_order_curve() {
+    // a prime order leaves a single subproblem as hard as the original
+    let g = Toy24Curve::generator();
+    let k = FrToy24::from(1_234_567u64);
+    let q = g.mul_scalar(&k);
+
+    let outcome = PohligHellman::<BabyStepGiantStep>::solve(&g, &q, &SolverConfig::default())
+        .unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    assert!(outcome.stats().steps > 3000);
+}
+
+#[test]
+fn test_pohlig_hellman_respects_budget() {
+    let g = Toy24Curve::generator();
+    let q = g.mul_scalar(&FrToy24::from(1_234_567u64));
+    let config = SolverConfig::new().with_max_steps(100);
+
+    let outcome = PohligHellman::<BabyStepGiantStep>::solve(&g, &q, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+    assert_eq!(outcome.stats().steps, 100);
+}
+
+#[test]
+fn test_pow_outside_the_prime_order_subgroup() {
+    // #E = 4n with n odd: T = (0, 0) has order 2 and P = G + T order 2n,
+    // so exponents must not be reduced modulo n
+    let g = SupersingularCurve::generator();
+    let zero = FqSupersingular::from(0u64);
+    let t = CurvePoint::<SupersingularCurve>::new(zero, zero);
+    let n = <CurvePoint<SupersingularCurve> as Group>::order();
+    assert_eq!(t.pow(&n), t);
+    assert!(t.pow(&BigUint::from(2u32)).is_identity());
+
+    let p = g.op(&t);
+    let k = &n + 3u32;
+    let q = p.pow(&k);
+    assert_eq!(q, g.mul_scalar(&FrSupersingular::from(3u64)));
+
+    let order = &n * 2u32;
+    let outcome = BabyStepGiantStep::solve_with_order(&p, &q, &order, &SolverConfig::default())
+        .unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+}
@@ -0,0 +1,74 @@
+use crate::breaking_dlp::index_calculus::{IndexCalculus, factor_base, smoothness_bound};
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig};
+use crate::core::curve::Curve;
+use crate::core::group::{Group, Multiplicative};
+use crate::curves::toy::toy::{FqToy32, FqToy40, FrToy32, Toy32Curve};
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_factor_base() {
+    assert_eq!(factor_base(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(factor_base(1000).len(), 168);
+    assert!(smoothness_bound(&BigUint::from(2784333193u64)) >= 30);
+}
+
+#[test]
+fn test_index_calculus_in_multiplicative_group() {
+    // 5 generates F_p^* for the 32-bit toy field
+    let g = Multiplicative(FqToy32::from(5u64));
+    let k = BigUint::from(2_718_281_828u64);
+    let h = g.pow(&k);
+
+    let outcome = IndexCalculus::solve(&g, &h, &SolverConfig::default()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+}
+
+#[test]
+fn test_index_calculus_needs_full_group_order() {
+    // a subgroup order is rejected: the factor base lives in all of F_p^*
+    let g = Multiplicative(FqToy40::from(7u64)).pow(&BigUint::from(2u32));
+    let h = g.pow(&BigUint::from(12345u32));
+    let order = <Multiplicative<FqToy40> as Group>::order() / 2u32;
+
+    let outcome = IndexCalculus::solve_with_order(&g, &h, &order, &SolverConfig::default())
+        .unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::SearchSpace, .. }
+    ));
+}
+
+#[test]
+fn test_index_calculus_against_rho_on_curve() {
+    // same reporting format for F_p^* and a curve of the same size
+    let k = BigUint::from(1_000_000_007u64);
+    let g = Multiplicative(FqToy32::from(5u64));
+    let h = g.pow(&k);
+    let field = IndexCalculus::solve(&g, &h, &SolverConfig::default()).unwrap();
+
+    let p = Toy32Curve::generator();
+    let q = p.mul_scalar(&FrToy32::from(1_000_000_007u64));
+    let curve = PollardsRho::solve(&p, &q, &SolverConfig::default()).unwrap();
+
+    assert_eq!(field.logarithm(), Some(&k));
+    assert_eq!(curve.logarithm(), field.logarithm());
+    // sqrt(2^32) = 65536 rho steps are far more than index calculus needs
+    assert!(field.stats().steps < 65_536);
+}
+
+#[test]
+fn test_index_calculus_respects_budget() {
+    let g = Multiplicative(FqToy32::from(5u64));
+    let h = g.pow(&BigUint::from(2_718_281_828u64));
+    let config = SolverConfig::new().with_max_steps(50);
+
+    let outcome = IndexCalculus::solve(&g, &h, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+    assert_eq!(outcome.stats().steps, 50);
+}
@@ -0,0 +1,66 @@
+use crate::breaking_dlp::checkpoint::{Checkpoint, CheckpointConfig};
+use crate::breaking_dlp::kangaroo::Kangaroo;
+use crate::breaking_dlp::{DiscreteLog, ResumableDiscreteLog};
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig};
+use crate::core::curve::Curve;
+use crate::core::group::{Group, Multiplicative};
+use crate::curves::toy::toy::{FqToy24, FrToy24, FrToy28, Toy24Curve, Toy28Curve};
+
+use num_bigint::BigUint;
+
+use std::fs;
+
+#[test]
+fn test_kangaroo_on_toy_curve() {
+    let g = Toy24Curve::generator();
+    for k in [0u64, 1, 4_521_003, 9_044_046] {
+        let q = g.mul_scalar(&FrToy24::from(k));
+        let outcome = Kangaroo::solve(&g, &q, &SolverConfig::new()).unwrap();
+        assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+        assert!(outcome.stats().stored_elements > 0);
+    }
+}
+
+#[test]
+fn test_kangaroo_on_multiplicative_group() {
+    // 7 generates F_p^* of the 24-bit toy field
+    let g = Multiplicative(FqToy24::from(7u64));
+    let k = BigUint::from(1_234_567u64);
+    let h = g.pow(&k);
+    let outcome = Kangaroo::solve(&g, &h, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&k));
+}
+
+#[test]
+fn test_kangaroo_resume_from_checkpoint() {
+    let path = std::env::temp_dir().join(format!(
+        "ecc-checkpoint-kangaroo-{}.txt",
+        std::process::id(),
+    ));
+    let g = Toy28Curve::generator();
+    let k = FrToy28::from(98_765_432u64);
+    let q = g.mul_scalar(&k);
+
+    let config = SolverConfig::new()
+        .with_max_steps(200)
+        .with_checkpoint(CheckpointConfig::new(&path, 50));
+    let outcome = Kangaroo::solve(&g, &q, &config).unwrap();
+    if outcome.is_found() {
+        assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+        return;
+    }
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+    let saved = Checkpoint::read(&path).unwrap();
+    assert_eq!(saved.stats.steps, 200);
+    assert!(saved.record("tame").is_ok());
+    assert!(saved.record("wild").is_ok());
+
+    let outcome = Kangaroo::resume(&path, &g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    assert!(outcome.stats().steps > 200);
+
+    fs::remove_file(&path).unwrap();
+}
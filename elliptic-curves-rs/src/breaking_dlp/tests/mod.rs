@@ -1 +1,10 @@
-pub mod secp256k1;
\ No newline at end of file
+pub mod checkpoint;
+pub mod complexity;
+pub mod group;
+pub mod index_calculus;
+pub mod kangaroo;
+pub mod mov;
+pub mod secp256k1;
+pub mod singular;
+pub mod smart_attack;
+pub mod solver;
@@ -0,0 +1,95 @@
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::baby_step_giant_step::BabyStepGiantStep;
+use crate::breaking_dlp::mov::{Mov, MovError, curve_embedding_degree, embedding_degree, reduce};
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig};
+use crate::core::curve::Curve;
+use crate::core::extension::ExtensionConfig;
+use crate::core::group::Group;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::curves::toy::toy::{FqToy16, FrToy16, Toy16Curve};
+use crate::curves::weak::weak::{
+    FrSupersingular,
+    SupersingularCurve,
+    SupersingularExtension,
+};
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_embedding_degree() {
+    // 7 = -1 (mod 8), 2^3 = 1 (mod 7)
+    let p = BigUint::from(7u32);
+    assert_eq!(embedding_degree(&p, &BigUint::from(2u32), 10), Some(1));
+    assert_eq!(embedding_degree(&p, &BigUint::from(8u32), 10), Some(2));
+    assert_eq!(embedding_degree(&BigUint::from(2u32), &p, 10), Some(3));
+    assert_eq!(embedding_degree(&BigUint::from(3u32), &p, 5), None);
+
+    assert_eq!(curve_embedding_degree::<SupersingularCurve>(6), Some(2));
+    assert_eq!(curve_embedding_degree::<Toy16Curve>(6), None);
+    assert_eq!(curve_embedding_degree::<Secp256k1Curve>(100), None);
+}
+
+#[test]
+fn test_reduction_preserves_the_logarithm() {
+    let g = SupersingularCurve::generator();
+    let order = BigUint::from(SupersingularCurve::order());
+    let k = BigUint::from(777_777u64);
+    let q = g.pow(&k);
+
+    let reduction = reduce::<_, SupersingularExtension>(&g, &q, &order).unwrap();
+    assert_eq!(reduction.embedding_degree, 2);
+    assert!(!reduction.base.is_identity());
+    assert!(reduction.base.pow(&order).is_identity());
+    assert_eq!(reduction.base.pow(&k), reduction.target);
+}
+
+#[test]
+fn test_mov_attack_on_supersingular_curve() {
+    let g = SupersingularCurve::generator();
+    let k = FrSupersingular::from(987_654_321u64);
+    let q = g.mul_scalar(&k);
+
+    let outcome = Mov::<PollardsRho, SupersingularExtension>::solve(
+        &g,
+        &q,
+        &SolverConfig::new(),
+    ).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+
+    let outcome = Mov::<BabyStepGiantStep, SupersingularExtension>::solve(
+        &g,
+        &q.double(),
+        &SolverConfig::new(),
+    ).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k + k)));
+}
+
+#[test]
+fn test_mov_refuses_large_embedding_degree() {
+    // 41233 does not divide 41479^2 - 1
+    let g = Toy16Curve::generator();
+    let q = g.mul_scalar(&FrToy16::from(5u64));
+    let order = BigUint::from(Toy16Curve::order());
+    assert!(matches!(
+        reduce::<_, Toy16Fp2>(&g, &q, &order),
+        Err(MovError::DegreeTooLarge { max: 2 })
+    ));
+
+    let outcome = Mov::<PollardsRho, Toy16Fp2>::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::SearchSpace, .. }
+    ));
+}
+
+/// F_{p^2} = F_p[i] / (i^2 + 1) for the 16-bit toy field, p = 3 (mod 4)
+struct Toy16Fp2;
+
+impl ExtensionConfig for Toy16Fp2 {
+    type BaseField = FqToy16;
+
+    fn modulus() -> Vec<FqToy16> {
+        vec![FqToy16::from(1u64), FqToy16::from(0u64)]
+    }
+}
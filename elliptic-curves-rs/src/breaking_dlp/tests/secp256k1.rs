@@ -1,6 +1,7 @@
 use crate::breaking_dlp::brute_force::BruteForce;
 use crate::breaking_dlp::pollards_rho::PollardsRho;
 use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::SolverConfig;
 use crate::core::curve::Curve;
 use crate::curves::secp256k1::secp256k1::{
     Secp256k1Curve,
@@ -8,6 +9,7 @@ use crate::curves::secp256k1::secp256k1::{
 };
 
 use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::BigUint;
 
 #[test]
 #[ignore = "It takes a very long time"]
@@ -18,9 +20,9 @@ fn test_brute_force_in_secp256k1() {
     let g = Secp256k1Curve::generator();
     let q = g.mul_scalar(&x);
 
-    let (steps, logarithm) = BruteForce::solve(&g, &q);
-    println!("steps: {}, logarithm: {}", steps, logarithm);
-    assert_eq!(x, logarithm);
+    let outcome = BruteForce::solve(&g, &q, &SolverConfig::default()).unwrap();
+    println!("stats: {:?}", outcome.stats());
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(x)));
 }
 
 #[test]
@@ -30,7 +32,7 @@ fn test_pollards_rho_in_secp256k1() {
     let k = FrSecp256k1::from(50u64);
     let q = g.mul_scalar(&k);
 
-    let (steps, found_k) = PollardsRho::solve(&g, &q);
-    println!("Found discrete log in {} steps", steps);
-    assert_eq!(found_k, k);
+    let outcome = PollardsRho::solve(&g, &q, &SolverConfig::default()).unwrap();
+    println!("Found discrete log in {} steps", outcome.stats().steps);
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::baby_step_giant_step::BabyStepGiantStep;
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::singular::{
+    SingularCurveAttack,
+    SingularError,
+    SingularImage,
+    Singularity,
+    reduce,
+    singularity,
+};
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig};
+use crate::core::curve::Curve;
+use crate::core::group::Group;
+use crate::core::point::CurvePoint;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::curves::weak::weak::{
+    CuspCurve,
+    FqSplitNode,
+    FrCusp,
+    FrNonSplitNode,
+    FrSplitNode,
+    NonSplitNodeCurve,
+    SplitNodeCurve,
+};
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_classifies_singular_curves() {
+    assert!(!Secp256k1Curve::is_singular());
+    assert_eq!(singularity::<Secp256k1Curve>(), None);
+
+    assert!(matches!(singularity::<CuspCurve>(), Some(Singularity::Cusp { .. })));
+    assert_eq!(
+        singularity::<SplitNodeCurve>(),
+        Some(Singularity::Node { alpha: FqSplitNode::from(3909167624u64), split: true }),
+    );
+    assert!(matches!(
+        singularity::<NonSplitNodeCurve>(),
+        Some(Singularity::Node { split: false, .. })
+    ));
+}
+
+#[test]
+fn test_maps_are_homomorphisms() {
+    let g = SplitNodeCurve::generator();
+    let (a, b) = (BigUint::from(1234u32), BigUint::from(98765u32));
+    let Ok(SingularImage::Multiplicative { base, target }) = reduce(&g.pow(&a), &g.pow(&b))
+    else {
+        panic!("split node maps into F_p^*");
+    };
+    let Ok(SingularImage::Multiplicative { base: sum, .. }) = reduce(&g.pow(&(&a + &b)), &g)
+    else {
+        panic!("split node maps into F_p^*");
+    };
+    assert_eq!(base.op(&target), sum);
+
+    let g = NonSplitNodeCurve::generator();
+    let Ok(SingularImage::Quadratic { base, .. }) = reduce(&g, &g) else {
+        panic!("non-split node maps into F_{{p^2}}^*");
+    };
+    // norm-1 subgroup of order p + 1
+    let p_plus_one = BigUint::from(6438400874u64);
+    assert!(base.pow(&p_plus_one).is_identity());
+    assert!(base.pow(&BigUint::from(NonSplitNodeCurve::order())).is_identity());
+}
+
+#[test]
+fn test_cusp_logarithm_is_a_division() {
+    let g = CuspCurve::generator();
+    let k = FrCusp::from(0x00c0_ffee_1234_5678u64);
+    let q = g.mul_scalar(&k);
+    let outcome = SingularCurveAttack::<PollardsRho>::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    assert_eq!(outcome.stats().steps, 1);
+}
+
+#[test]
+fn test_node_logarithms() {
+    let g = SplitNodeCurve::generator();
+    let k = FrSplitNode::from(2_000_000_011u64);
+    let q = g.mul_scalar(&k);
+    let outcome = SingularCurveAttack::<PollardsRho>::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+
+    let g = NonSplitNodeCurve::generator();
+    let k = FrNonSplitNode::from(3_000_000_019u64);
+    let q = g.mul_scalar(&k);
+    let outcome =
+        SingularCurveAttack::<BabyStepGiantStep>::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+}
+
+#[test]
+fn test_rejects_non_singular_curves_and_the_singular_point() {
+    let g = Secp256k1Curve::generator();
+    let q = g.mul_scalar(&FrSecp256k1::from(7u64));
+    assert!(matches!(reduce(&g, &q), Err(SingularError::NonSingular)));
+    let outcome = SingularCurveAttack::<PollardsRho>::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::SearchSpace, .. }
+    ));
+
+    let alpha = singularity::<SplitNodeCurve>().unwrap().alpha();
+    let node = CurvePoint::<SplitNodeCurve>::new(alpha, FqSplitNode::from(0u64));
+    assert!(SplitNodeCurve::is_on_curve(&node.inner));
+    let g = SplitNodeCurve::generator();
+    assert!(matches!(reduce(&g, &node), Err(SingularError::SingularPoint)));
+}
@@ -0,0 +1,62 @@
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::smart_attack::{SmartAttack, SmartAttackError, is_anomalous, smart_attack};
+use crate::breaking_dlp::solver::{DlpOutcome, Exhaustion, SolverConfig};
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::curves::toy::toy::Toy56Curve;
+use crate::curves::weak::weak::{AnomalousCurve, FrAnomalous, PointAnomalous};
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_detects_anomalous_curves() {
+    assert!(is_anomalous::<AnomalousCurve>());
+    assert!(!is_anomalous::<Secp256k1Curve>());
+    assert!(!is_anomalous::<Toy56Curve>());
+}
+
+#[test]
+fn test_smart_attack_on_anomalous_curve() {
+    let g = AnomalousCurve::generator();
+    for k in [1u64, 2, 0xdead_beef_cafe, 36_028_805_887_801_710] {
+        let q = g.mul_scalar(&FrAnomalous::from(k));
+        assert_eq!(smart_attack(&g, &q), Ok(BigUint::from(k)));
+    }
+
+    // any base point, not only the generator
+    let p = g.mul_scalar(&FrAnomalous::from(987_654_321u64));
+    let q = p.mul_scalar(&FrAnomalous::from(123_456_789_012u64));
+    assert_eq!(smart_attack(&p, &q), Ok(BigUint::from(123_456_789_012u64)));
+
+    assert_eq!(smart_attack(&g, &PointAnomalous::infinity()), Ok(BigUint::from(0u32)));
+    assert_eq!(
+        smart_attack(&PointAnomalous::infinity(), &g),
+        Err(SmartAttackError::TrivialBase),
+    );
+}
+
+#[test]
+fn test_smart_attack_as_discrete_log_solver() {
+    let g = AnomalousCurve::generator();
+    let k = FrAnomalous::from(31_415_926_535_897u64);
+    let q = g.mul_scalar(&k);
+    let outcome = SmartAttack::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert_eq!(outcome.logarithm(), Some(&BigUint::from(k)));
+    assert_eq!(outcome.stats().steps, 1);
+}
+
+#[test]
+fn test_smart_attack_fails_cleanly_on_secp256k1() {
+    let g = Secp256k1Curve::generator();
+    let q = g.mul_scalar(&FrSecp256k1::from(42u64));
+    assert!(matches!(
+        smart_attack(&g, &q),
+        Err(SmartAttackError::NotAnomalous { .. })
+    ));
+
+    let outcome = SmartAttack::solve(&g, &q, &SolverConfig::new()).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::SearchSpace, .. }
+    ));
+}
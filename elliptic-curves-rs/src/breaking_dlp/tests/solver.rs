@@ -0,0 +1,104 @@
+use crate::breaking_dlp::brute_force::BruteForce;
+use crate::breaking_dlp::pollards_rho::PollardsRho;
+use crate::breaking_dlp::DiscreteLog;
+use crate::breaking_dlp::solver::{
+    CancellationToken,
+    DlpError,
+    DlpOutcome,
+    Exhaustion,
+    SolverConfig,
+    SolverStats,
+};
+use crate::core::curve::Curve;
+use crate::core::field::PrimeField;
+use crate::core::point::CurvePoint;
+use crate::curves::secp256k1::secp256k1::{
+    FqSecp256k1,
+    FrSecp256k1,
+    Secp256k1Curve,
+};
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+fn test_step_budget_exhausts() {
+    let g = Secp256k1Curve::generator();
+    let q = g.mul_scalar(&FrSecp256k1::from(123_456_789u64));
+    let config = SolverConfig::new().with_max_steps(50);
+
+    let outcome = BruteForce::solve(&g, &q, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+    assert_eq!(outcome.stats().steps, 50);
+    assert!(outcome.stats().group_operations > 50);
+
+    let outcome = PollardsRho::solve(&g, &q, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::StepBudget, .. }
+    ));
+    assert_eq!(outcome.stats().steps, 50);
+}
+
+#[test]
+fn test_timeout_exhausts() {
+    let g = Secp256k1Curve::generator();
+    let q = g.double();
+    let config = SolverConfig::new().with_timeout(Duration::from_millis(20));
+
+    let outcome = PollardsRho::solve(&g, &q, &config).unwrap();
+    assert!(matches!(
+        outcome,
+        DlpOutcome::Exhausted { reason: Exhaustion::Timeout, .. }
+    ));
+    assert!(outcome.stats().elapsed >= Duration::from_millis(20));
+}
+
+#[test]
+fn test_cancellation_from_observer() {
+    let g = Secp256k1Curve::generator();
+    let q = g.double();
+    let token = CancellationToken::new();
+    let seen: Arc<Mutex<Vec<SolverStats>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let observer_token = token.clone();
+    let observer_seen = Arc::clone(&seen);
+    let config = SolverConfig::new()
+        .with_progress_interval(10)
+        .with_cancellation(token)
+        .with_observer(move |stats: &SolverStats| {
+            observer_seen.lock().unwrap().push(*stats);
+            if stats.steps >= 30 {
+                observer_token.cancel();
+            }
+        });
+
+    let outcome = BruteForce::solve(&g, &q, &config).unwrap();
+    assert!(matches!(outcome, DlpOutcome::Cancelled { .. }));
+    assert_eq!(outcome.stats().steps, 30);
+
+    let steps: Vec<u64> = seen.lock().unwrap().iter().map(|s| s.steps).collect();
+    assert_eq!(steps, vec![10, 20, 30]);
+}
+
+#[test]
+fn test_element_not_in_group() {
+    let g = Secp256k1Curve::generator();
+    let bogus = CurvePoint::<Secp256k1Curve>::new(
+        FqSecp256k1::from_u64(1),
+        FqSecp256k1::from_u64(1),
+    );
+    let config = SolverConfig::default();
+
+    assert!(matches!(
+        BruteForce::solve(&g, &bogus, &config),
+        Err(DlpError::NotInGroup),
+    ));
+    assert!(matches!(
+        PollardsRho::solve(&bogus, &g, &config),
+        Err(DlpError::NotInGroup),
+    ));
+}
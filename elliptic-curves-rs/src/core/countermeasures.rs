@@ -0,0 +1,54 @@
+//! Fault-attack countermeasures for scalar multiplication
+//!
+//! A fault in a scalar multiplication either leaves the curve (a flipped
+//! bit, or a corrupted base point that moves the computation onto
+//! y^2 = x^3 + ax + b') or stays on it with a wrong multiple (a skipped
+//! addition or doubling). `mul_scalar_checked` catches both before the
+//! result is released:
+//!
+//! - the input and the output must satisfy the curve equation, the only
+//!   place where b appears at all;
+//! - the result must be coherent with a second, independent computation:
+//!
+//! ```text
+//! kP + (n - k)P = nP = O
+//! ```
+//!
+//! k and n - k have different bits, so one fault cannot hit both
+//! multiplications the same way. Only built with the
+//! `fault-countermeasures` feature.
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FaultError {
+    #[error("the input point is not on the curve")]
+    InputNotOnCurve,
+    #[error("the result is not on the curve")]
+    NotOnCurve,
+    #[error("kP + (n - k)P is not the point at infinity")]
+    Incoherent,
+    #[error("the signature does not verify under the public key")]
+    InvalidSignature,
+}
+
+impl<C: Curve> CurvePoint<C> {
+    /// `mul_scalar` with the on-curve and coherence checks above
+    pub fn mul_scalar_checked(&self, k: &C::ScalarField) -> Result<Self, FaultError> {
+        if !C::is_on_curve(&self.inner) {
+            return Err(FaultError::InputNotOnCurve);
+        }
+        let result = self.mul_scalar(k);
+        if !C::is_on_curve(&result.inner) {
+            return Err(FaultError::NotOnCurve);
+        }
+        let complement = self.mul_scalar(&-*k);
+        if !result.add(&complement).inner.is_infinity() {
+            return Err(FaultError::Incoherent);
+        }
+        Ok(result)
+    }
+}
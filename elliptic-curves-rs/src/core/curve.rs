@@ -1,11 +1,12 @@
 use crate::core::field::{PrimeField};
 use crate::core::point::{Point, CurvePoint};
 
-use ark_ff::{PrimeField as ArkPrimeField};
+use ark_ff::{BigInteger, Field as ArkField, PrimeField as ArkPrimeField, Zero};
 
 /// PrimeField를 상속받는 Curve
 /// Curve (BaseField) -> PrimeField -> Field
-/// 아래 추상 메서드를 각 타원 곡선에서 세부 구현
+/// 곡선 파라미터(a, b, generator, order)는 각 타원 곡선에서 세부 구현하고,
+/// short Weierstrass 곡선 y^2 = x^3 + ax + b 의 점 연산은 기본 구현을 공유한다.
 pub trait Curve {
     // Curve를 구현할 때 BaseField라는 이름으로 구체적인 타입을 하나 정해야 하는데,
     // 그 타입은 반드시 PrimeField trait과 ark_ff::PrimeField(=ArkPrimeField) 두
@@ -25,24 +26,117 @@ pub trait Curve {
 
     fn order() -> <Self::ScalarField as ArkPrimeField>::BigInt;
 
-    fn is_on_curve(p: &Point<Self::BaseField>) -> bool;
+    /// -16(4a^3 + 27b^2), zero exactly when x^3 + ax + b has a repeated root
+    fn discriminant() -> Self::BaseField {
+        let (a, b) = (Self::a(), Self::b());
+        let inner = Self::BaseField::from_u64(4) * a.square() * a
+            + Self::BaseField::from_u64(27) * b.square();
+        -(Self::BaseField::from_u64(16) * inner)
+    }
+
+    /// A singular "curve" (a cusp or a node) is not an elliptic curve and
+    /// its group maps into F_p^+ or F_p^* (see `breaking_dlp::singular`).
+    fn is_singular() -> bool {
+        Self::discriminant().is_zero()
+    }
+
+    fn is_on_curve(p: &Point<Self::BaseField>) -> bool {
+        // y^2 = x^3 + ax + b
+        match (p.x(), p.y()) {
+            (Some(x), Some(y)) => {
+                let left_term = y.square();
+                let right_term = x.square() * x + Self::a() * x + Self::b();
+                left_term == right_term
+            }
+            _ => true,
+        }
+    }
 
     fn add_point(
         p: &Point<Self::BaseField>,
         q: &Point<Self::BaseField>,
-    ) -> Point<Self::BaseField>;
+    ) -> Point<Self::BaseField> {
+        use Point::*;
+
+        match (p, q) {
+            (Infinity, _) => q.clone(),
+            (_, Infinity) => p.clone(),
+            (Affine { x: x1, y: y1 }, Affine { x: x2, y: y2 }) => {
+                // P + (-P) = infinity
+                if x1 == x2 && (*y1 + y2).is_zero() {
+                    return Infinity;
+                }
+
+                // P + P = 2P
+                if x1 == x2 && y1 == y2 {
+                    return Self::double_point(p);
+                }
+
+                // P + Q (P != Q)
+                let m = {
+                    let numerator = *y1 - y2;
+                    let denominator = (*x1 - x2).inverse()
+                        .expect("denominator is zero.");
+                    numerator * denominator
+                };
+                let x_r = m.square() - x1 - x2;
+                let y_r = m * (*x1 - x_r) - y1;
+
+                Affine { x: x_r, y: y_r }
+            }
+        }
+    }
 
-    fn double_point(p: &Point<Self::BaseField>) -> Point<Self::BaseField>;
+    fn double_point(p: &Point<Self::BaseField>) -> Point<Self::BaseField> {
+        use Point::*;
+        match p {
+            Infinity => Infinity,
+            // a point of order two: its tangent is vertical
+            Affine { y, .. } if y.is_zero() => Infinity,
+            Affine { x, y } => {
+                let two = Self::BaseField::from_u64(2);
+                let three = Self::BaseField::from_u64(3);
+                let numerator = three * x.square() + Self::a();
+                let denominator = (two * y).inverse()
+                    .expect("denominator is zero.");
+                let m = numerator * denominator;
+                let x_r = m.square() - x - x;
+                let y_r = m * (*x - x_r) - y;
+                Affine { x: x_r, y: y_r }
+            }
+        }
+    }
 
     fn mul_scalar(
         p: &Point<Self::BaseField>,
         scalar: &Self::ScalarField,
-    ) -> Point<Self::BaseField>;
+    ) -> Point<Self::BaseField> {
+        // double-and-add from the most significant bit
+        let mut result = Point::infinity();
+        for bit in scalar.into_bigint().to_bits_be() {
+            result = Self::double_point(&result);
+            if bit {
+                result = Self::add_point(&result, p);
+            }
+        }
+        result
+    }
 
     fn subtract_point(
         p: &Point<Self::BaseField>,
         q: &Point<Self::BaseField>,
-    ) -> Point<Self::BaseField>;
+    ) -> Point<Self::BaseField> {
+        let neg_q = Self::negate_point(q);
+        Self::add_point(p, &neg_q)
+    }
 
-    fn negate_point(p: &Point<Self::BaseField>) -> Point<Self::BaseField>;
+    fn negate_point(p: &Point<Self::BaseField>) -> Point<Self::BaseField> {
+        match p {
+            Point::Infinity => Point::Infinity,
+            Point::Affine { x, y } => Point::Affine {
+                x: *x,
+                y: -*y,
+            },
+        }
+    }
 }
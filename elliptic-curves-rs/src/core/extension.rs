@@ -0,0 +1,232 @@
+//! Extension fields F_{p^k} = F_p[x] / (f(x))
+//!
+//! Pairings on a curve over F_p take their values in F_{p^k}, where k is
+//! the embedding degree, so the MOV attack needs arithmetic there. An
+//! element is a polynomial of degree < k over the prime field, and the
+//! monic irreducible f is fixed per type by an `ExtensionConfig`, the
+//! same way `MontConfig` fixes the prime of a prime field.
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+use rand::Rng;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+pub trait ExtensionConfig: 'static {
+    type BaseField: ArkPrimeField;
+
+    /// Lower coefficients [c_0, ..., c_(k-1)] of the monic irreducible
+    /// modulus f(x) = x^k + c_(k-1) x^(k-1) + ... + c_0
+    fn modulus() -> Vec<Self::BaseField>;
+
+    fn degree() -> usize {
+        Self::modulus().len()
+    }
+
+    /// p^k
+    fn size() -> BigUint {
+        let p: BigUint = <Self::BaseField as ArkPrimeField>::MODULUS.into();
+        p.pow(Self::degree() as u32)
+    }
+}
+
+/// c_0 + c_1 x + ... + c_(k-1) x^(k-1)
+pub struct ExtensionField<P: ExtensionConfig> {
+    coeffs: Vec<P::BaseField>,
+    _config: PhantomData<P>,
+}
+
+impl<P: ExtensionConfig> ExtensionField<P> {
+    /// Reduces an arbitrary polynomial modulo f
+    pub fn from_coeffs(mut coeffs: Vec<P::BaseField>) -> Self {
+        let modulus = P::modulus();
+        let k = modulus.len();
+        // x^k = -(c_0 + ... + c_(k-1) x^(k-1)), from the top down
+        while coeffs.len() > k {
+            let top = coeffs.pop().expect("longer than k");
+            let shift = coeffs.len() - k;
+            for (i, c) in modulus.iter().enumerate() {
+                coeffs[shift + i] -= top * c;
+            }
+        }
+        coeffs.resize(k, P::BaseField::zero());
+        Self { coeffs, _config: PhantomData }
+    }
+
+    pub fn from_base(value: P::BaseField) -> Self {
+        Self::from_coeffs(vec![value])
+    }
+
+    pub fn zero() -> Self {
+        Self::from_coeffs(Vec::new())
+    }
+
+    pub fn one() -> Self {
+        Self::from_base(P::BaseField::one())
+    }
+
+    /// The class of x, a root of f
+    pub fn generator() -> Self {
+        Self::from_coeffs(vec![P::BaseField::zero(), P::BaseField::one()])
+    }
+
+    pub fn random<R: Rng>(rng: &mut R) -> Self {
+        let p: BigUint = <P::BaseField as ArkPrimeField>::MODULUS.into();
+        let coeffs = (0..P::degree())
+            .map(|_| P::BaseField::from(rng.gen_biguint_below(&p)))
+            .collect();
+        Self::from_coeffs(coeffs)
+    }
+
+    pub fn coeffs(&self) -> &[P::BaseField] {
+        &self.coeffs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+
+    pub fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let coeffs = self.coeffs.iter().zip(&other.coeffs).map(|(a, b)| *a + b).collect();
+        Self { coeffs, _config: PhantomData }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let coeffs = self.coeffs.iter().zip(&other.coeffs).map(|(a, b)| *a - b).collect();
+        Self { coeffs, _config: PhantomData }
+    }
+
+    pub fn neg(&self) -> Self {
+        let coeffs = self.coeffs.iter().map(|c| -*c).collect();
+        Self { coeffs, _config: PhantomData }
+    }
+
+    /// Schoolbook product followed by the reduction modulo f
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut product = vec![P::BaseField::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in other.coeffs.iter().enumerate() {
+                product[i + j] += *a * b;
+            }
+        }
+        Self::from_coeffs(product)
+    }
+
+    pub fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    pub fn scale(&self, factor: &P::BaseField) -> Self {
+        let coeffs = self.coeffs.iter().map(|c| *c * factor).collect();
+        Self { coeffs, _config: PhantomData }
+    }
+
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = Self::one();
+        for i in (0..exponent.bits()).rev() {
+            result = result.square();
+            if exponent.bit(i) {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+
+    /// a^(p^k - 2) = a^(-1) by Lagrange
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.pow(&(P::size() - 2u32)))
+    }
+
+    /// Euler's criterion a^((q - 1) / 2) = 1 with q = p^k
+    pub fn is_square(&self) -> bool {
+        self.is_zero() || self.pow(&((P::size() - 1u32) >> 1)).is_one()
+    }
+
+    /// Tonelli-Shanks in F_q^*, with a random non-residue
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if !self.is_square() {
+            return None;
+        }
+
+        // q - 1 = 2^s * t with t odd
+        let q_minus_one = P::size() - 1u32;
+        let s = q_minus_one.trailing_zeros().expect("q is odd");
+        let t = &q_minus_one >> s;
+
+        let mut rng = rand::thread_rng();
+        let z = loop {
+            let z = Self::random(&mut rng);
+            if !z.is_square() {
+                break z;
+            }
+        };
+
+        let mut m = s;
+        let mut c = z.pow(&t);
+        let mut u = self.pow(&t);
+        let mut root = self.pow(&((&t + 1u32) >> 1));
+        while !u.is_one() {
+            // smallest i with u^(2^i) = 1
+            let mut i = 0;
+            let mut power = u.clone();
+            while !power.is_one() {
+                power = power.square();
+                i += 1;
+            }
+            let b = c.pow(&(BigUint::one() << (m - i - 1)));
+            m = i;
+            c = b.square();
+            u = u.mul(&c);
+            root = root.mul(&b);
+        }
+        Some(root)
+    }
+
+    /// Concatenated big-endian coefficients, lowest degree first
+    pub fn to_bytes(&self) -> Vec<u8> {
+        use ark_ff::BigInteger;
+        self.coeffs.iter().flat_map(|c| c.into_bigint().to_bytes_be()).collect()
+    }
+}
+
+// derive would require P: Clone/PartialEq/Debug on the marker type as well
+impl<P: ExtensionConfig> Clone for ExtensionField<P> {
+    fn clone(&self) -> Self {
+        Self { coeffs: self.coeffs.clone(), _config: PhantomData }
+    }
+}
+
+impl<P: ExtensionConfig> PartialEq for ExtensionField<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<P: ExtensionConfig> Eq for ExtensionField<P> {}
+
+impl<P: ExtensionConfig> fmt::Debug for ExtensionField<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms: Vec<String> = self.coeffs
+            .iter()
+            .enumerate()
+            .map(|(i, c)| match i {
+                0 => format!("{}", c),
+                1 => format!("{}*x", c),
+                _ => format!("{}*x^{}", c, i),
+            })
+            .collect();
+        write!(f, "ExtensionField({})", terms.join(" + "))
+    }
+}
@@ -0,0 +1,187 @@
+//! Abstract finite cyclic groups
+//!
+//! The DLP algorithms in `breaking_dlp` only need the group law, so they
+//! are written against `Group`. `CurvePoint<C>` is one implementation and
+//! `Multiplicative<F>` (the group F_p^*) is another, which lets the same
+//! solver attack both for a comparison at equal group size. F_{p^k}^* is
+//! where the MOV attack moves a curve's logarithm.
+
+use crate::core::curve::Curve;
+use crate::core::extension::{ExtensionConfig, ExtensionField};
+use crate::core::point::{CurvePoint, Point};
+
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField};
+use num_bigint::BigUint;
+
+use std::fmt::Debug;
+
+pub trait Group: Clone + PartialEq + Debug {
+    fn identity() -> Self;
+
+    /// The group law: point addition or field multiplication
+    fn op(&self, other: &Self) -> Self;
+
+    fn inverse(&self) -> Self;
+
+    /// Repeated application of `op`, i.e. kP or g^k
+    fn pow(&self, exponent: &BigUint) -> Self {
+        let mut result = Self::identity();
+        for i in (0..exponent.bits()).rev() {
+            result = result.op(&result);
+            if exponent.bit(i) {
+                result = result.op(self);
+            }
+        }
+        result
+    }
+
+    /// Canonical, injective byte encoding used for hashing and tables
+    fn encode(&self) -> Vec<u8>;
+
+    /// Membership test, e.g. `is_on_curve`
+    fn is_element(&self) -> bool;
+
+    /// Order of the group (for curves, of the subgroup generated by G)
+    fn order() -> BigUint;
+
+    /// Canonical description of the group parameters
+    fn description() -> String;
+
+    fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+}
+
+impl<C: Curve> Group for CurvePoint<C> {
+    fn identity() -> Self {
+        CurvePoint::infinity()
+    }
+
+    fn op(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    fn inverse(&self) -> Self {
+        CurvePoint { inner: C::negate_point(&self.inner) }
+    }
+
+    /// Not reduced modulo n: the solvers also run on points outside the
+    /// subgroup generated by G (MOV, singular and invalid-curve inputs)
+    fn pow(&self, exponent: &BigUint) -> Self {
+        self.mul_biguint(exponent)
+    }
+
+    /// SEC1 uncompressed encoding, 0x00 for the point at infinity
+    fn encode(&self) -> Vec<u8> {
+        match &self.inner {
+            Point::Infinity => vec![0x00],
+            Point::Affine { x, y } => {
+                let mut bytes = vec![0x04];
+                bytes.extend(x.into_bigint().to_bytes_be());
+                bytes.extend(y.into_bigint().to_bytes_be());
+                bytes
+            }
+        }
+    }
+
+    fn is_element(&self) -> bool {
+        C::is_on_curve(&self.inner)
+    }
+
+    fn order() -> BigUint {
+        C::order().into()
+    }
+
+    fn description() -> String {
+        format!(
+            "curve p={} a={} b={} G={} n={}",
+            <C::BaseField as ArkPrimeField>::MODULUS,
+            C::a(),
+            C::b(),
+            hex(&C::generator().encode()),
+            C::order(),
+        )
+    }
+}
+
+/// The multiplicative group F^* of a field: F_p^* of order p - 1 for a
+/// prime field, F_{p^k}^* of order p^k - 1 for an `ExtensionField`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multiplicative<F>(pub F);
+
+impl<F: ArkPrimeField> Group for Multiplicative<F> {
+    fn identity() -> Self {
+        Multiplicative(F::one())
+    }
+
+    fn op(&self, other: &Self) -> Self {
+        Multiplicative(self.0 * other.0)
+    }
+
+    fn inverse(&self) -> Self {
+        Multiplicative(self.0.inverse().expect("zero is not in F_p^*"))
+    }
+
+    fn pow(&self, exponent: &BigUint) -> Self {
+        Multiplicative(self.0.pow(exponent.to_u64_digits()))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.0.into_bigint().to_bytes_be()
+    }
+
+    fn is_element(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn order() -> BigUint {
+        let p: BigUint = F::MODULUS.into();
+        p - 1u32
+    }
+
+    fn description() -> String {
+        format!("multiplicative p={}", F::MODULUS)
+    }
+}
+
+impl<P: ExtensionConfig> Group for Multiplicative<ExtensionField<P>> {
+    fn identity() -> Self {
+        Multiplicative(ExtensionField::one())
+    }
+
+    fn op(&self, other: &Self) -> Self {
+        Multiplicative(self.0.mul(&other.0))
+    }
+
+    fn inverse(&self) -> Self {
+        Multiplicative(self.0.inverse().expect("zero is not in F_{p^k}^*"))
+    }
+
+    fn pow(&self, exponent: &BigUint) -> Self {
+        Multiplicative(self.0.pow(exponent))
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    fn is_element(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    fn order() -> BigUint {
+        P::size() - 1u32
+    }
+
+    fn description() -> String {
+        format!(
+            "multiplicative p={} modulus={:?}",
+            <P::BaseField as ArkPrimeField>::MODULUS,
+            P::modulus(),
+        )
+    }
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
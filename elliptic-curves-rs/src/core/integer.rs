@@ -0,0 +1,171 @@
+//! Integer helpers on `BigUint` shared by the DLP algorithms
+//!
+//! Group orders such as p - 1 are composite, so their arithmetic cannot be
+//! expressed with the compile-time prime fields of `ark-ff`.
+
+use num_bigint::{BigUint, RandBigInt};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use rand::thread_rng;
+
+/// Miller-Rabin with random bases; deterministic for the small primes.
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    let two = BigUint::from(2u32);
+    if *n < two {
+        return false;
+    }
+    for small in [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let small = BigUint::from(small);
+        if *n == small {
+            return true;
+        }
+        if (n % &small).is_zero() {
+            return false;
+        }
+    }
+
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    let mut rng = thread_rng();
+    'witness: for _ in 0..32 {
+        let a = rng.gen_biguint_range(&two, &n_minus_one);
+        let mut x = a.modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Prime factorisation as sorted `(prime, exponent)` pairs, using trial
+/// division for small factors and Pollard's rho (Brent) for the rest.
+pub fn factorize(n: &BigUint) -> Vec<(BigUint, u32)> {
+    let mut factors: Vec<BigUint> = Vec::new();
+    let mut rest = n.clone();
+
+    for small in 2u32..1000 {
+        let small = BigUint::from(small);
+        while !rest.is_zero() && (&rest % &small).is_zero() {
+            factors.push(small.clone());
+            rest /= &small;
+        }
+    }
+
+    let mut stack = Vec::new();
+    if rest > BigUint::one() {
+        stack.push(rest);
+    }
+    while let Some(m) = stack.pop() {
+        if is_probable_prime(&m) {
+            factors.push(m);
+            continue;
+        }
+        let d = pollard_brent(&m);
+        stack.push(&m / &d);
+        stack.push(d);
+    }
+
+    factors.sort();
+    let mut grouped: Vec<(BigUint, u32)> = Vec::new();
+    for f in factors {
+        match grouped.last_mut() {
+            Some((last, e)) if *last == f => *e += 1,
+            _ => grouped.push((f, 1)),
+        }
+    }
+    grouped
+}
+
+/// Finds a non-trivial factor of a composite odd `n`.
+fn pollard_brent(n: &BigUint) -> BigUint {
+    let mut rng = thread_rng();
+    loop {
+        let c = rng.gen_biguint_below(n);
+        let mut y = rng.gen_biguint_below(n);
+        let f = |x: &BigUint| (x * x + &c) % n;
+
+        let mut r = 1u64;
+        let mut g = BigUint::one();
+        while g.is_one() {
+            let x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                let diff = if x > y { &x - &y } else { &y - &x };
+                g = diff.gcd(n);
+                y = f(&y);
+                k += 1;
+            }
+            r *= 2;
+        }
+        if &g != n {
+            return g;
+        }
+    }
+}
+
+/// Chinese remainder theorem for `x = r_i mod m_i` with pairwise coprime
+/// moduli. Returns `(x, prod m_i)`.
+pub fn crt(residues: &[(BigUint, BigUint)]) -> Option<(BigUint, BigUint)> {
+    let mut x = BigUint::zero();
+    let mut m = BigUint::one();
+    for (r_i, m_i) in residues {
+        // x + m * t = r_i (mod m_i)
+        let m_inv = (&m % m_i).modinv(m_i)?;
+        let diff = mod_sub(&(r_i % m_i), &(&x % m_i), m_i);
+        let t = (diff * m_inv) % m_i;
+        x += &m * t;
+        m *= m_i;
+    }
+    Some((x % &m, m))
+}
+
+/// (a - b) mod m for already reduced a, b
+pub fn mod_sub(a: &BigUint, b: &BigUint, m: &BigUint) -> BigUint {
+    if a >= b {
+        (a - b) % m
+    } else {
+        (m - (b - a) % m) % m
+    }
+}
+
+/// All solutions x in [0, n) of `a * x = b (mod n)`, as long as there are
+/// at most `limit` of them.
+pub fn solve_linear_congruence(
+    a: &BigUint,
+    b: &BigUint,
+    n: &BigUint,
+    limit: u64,
+) -> Vec<BigUint> {
+    let a = a % n;
+    let b = b % n;
+    let d = a.gcd(n);
+    if d.is_zero() || !(&b % &d).is_zero() || d > BigUint::from(limit) {
+        return Vec::new();
+    }
+    let n_d = n / &d;
+    let x0 = match (&a / &d).modinv(&n_d) {
+        Some(inv) => (inv * (&b / &d)) % &n_d,
+        // n / d = 1: every x is a solution
+        None => BigUint::zero(),
+    };
+    let mut solutions = Vec::new();
+    let mut x = x0;
+    while x < *n {
+        solutions.push(x.clone());
+        x += &n_d;
+    }
+    solutions
+}
+
@@ -0,0 +1,78 @@
+//! x-only Montgomery ladder for short Weierstrass curves
+//!
+//! A point is kept as (X : Z) with x = X / Z, Z = 0 for infinity, and y is
+//! never computed. The ladder keeps R1 - R0 = P, so every addition is a
+//! differential one with the known difference x(P):
+//!
+//! ```text
+//! x(2R)      = ((x^2 - a)^2 - 8bx) / (4(x^3 + ax + b))
+//! x(R + S)   = 2((x_R + x_S)(x_R x_S + a) + 2b) / (x_R - x_S)^2 - x(R - S)
+//! ```
+//!
+//! Unlike the affine formulas these use b, but not y. An x whose
+//! x^3 + ax + b is not a square belongs to no point of the curve: it is the
+//! x-coordinate of a point of the quadratic twist d y^2 = x^3 + ax + b,
+//! and the same formulas compute the twist's multiples without noticing.
+
+use crate::core::curve::Curve;
+use crate::core::field::PrimeField;
+
+use ark_ff::{Field as ArkField, One, Zero};
+use num_bigint::BigUint;
+
+/// (X : Z) with x = X / Z
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Projective<F> {
+    x: F,
+    z: F,
+}
+
+/// x^3 + ax + b
+pub fn rhs<C: Curve>(x: &C::BaseField) -> C::BaseField {
+    x.square() * x + C::a() * x + C::b()
+}
+
+/// x is the x-coordinate of a point of the quadratic twist, not of `C`
+pub fn is_on_twist<C: Curve>(x: &C::BaseField) -> bool {
+    rhs::<C>(x).legendre().is_qnr()
+}
+
+fn double<C: Curve>(r: &Projective<C::BaseField>) -> Projective<C::BaseField> {
+    let (a, b) = (C::a(), C::b());
+    let (xx, zz) = (r.x.square(), r.z.square());
+    let x = (xx - a * zz).square() - C::BaseField::from_u64(8) * b * r.x * zz * r.z;
+    let z = C::BaseField::from_u64(4) * r.z * (xx * r.x + a * r.x * zz + b * zz * r.z);
+    Projective { x, z }
+}
+
+/// R + S from x(R - S) = `difference`, which is never infinity in the
+/// ladder
+fn add<C: Curve>(
+    r: &Projective<C::BaseField>,
+    s: &Projective<C::BaseField>,
+    difference: &C::BaseField,
+) -> Projective<C::BaseField> {
+    let two = C::BaseField::from_u64(2);
+    let zz = r.z * s.z;
+    let u = (r.x * s.z - s.x * r.z).square();
+    let sum = (r.x * s.z + s.x * r.z) * (r.x * s.x + C::a() * zz)
+        + two * C::b() * zz.square();
+    Projective { x: two * sum - *difference * u, z: u }
+}
+
+/// x(kP) for P = (x, _) on `C` or on its twist, None for infinity
+pub fn ladder<C: Curve>(x: &C::BaseField, k: &BigUint) -> Option<C::BaseField> {
+    let mut r0 = Projective { x: C::BaseField::one(), z: C::BaseField::zero() };
+    let mut r1 = Projective { x: *x, z: C::BaseField::one() };
+    for i in (0..k.bits()).rev() {
+        // constant shape: one addition and one doubling per bit
+        if k.bit(i) {
+            r0 = add::<C>(&r0, &r1, x);
+            r1 = double::<C>(&r1);
+        } else {
+            r1 = add::<C>(&r0, &r1, x);
+            r0 = double::<C>(&r0);
+        }
+    }
+    r0.z.inverse().map(|z_inv| r0.x * z_inv)
+}
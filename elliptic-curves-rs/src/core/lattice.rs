@@ -0,0 +1,343 @@
+//! Lattice reduction: LLL over exact rationals, and BKZ on top of it
+//!
+//! A basis is a list of linearly independent integer rows b_0, ..., b_(n-1)
+//! with the Gram-Schmidt data
+//!
+//! ```text
+//! b*_i = b_i - sum_(j < i) mu_(i,j) b*_j,    B_i = <b*_i, b*_i>
+//! ```
+//!
+//! LLL stops when |mu_(i,j)| <= 1/2 and the Lovász condition
+//! B_i >= (delta - mu_(i,i-1)^2) B_(i-1) hold everywhere. The mu_(i,j) and
+//! B_i are rationals with the common denominators d_i = B_0 ... B_(i-1), so
+//! LLL keeps the integers d_i and d_(j+1) mu_(i,j) instead and updates them
+//! exactly on every size reduction and swap (Cohen, Algorithm 2.6.7): no
+//! rounding error can ever break the reduction.
+//!
+//! BKZ improves LLL block by block: it enumerates the shortest vector of
+//! the lattice projected on b*_k, ..., b*_(k+beta-1) and, if it beats b*_k,
+//! makes it the k-th basis row. The enumeration only searches, so it runs
+//! on f64 copies of the exact data; the basis itself stays exact.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use thiserror::Error;
+
+/// Tours after which BKZ stops even if some block still improves
+const MAX_BKZ_TOURS: usize = 16;
+/// A block vector must be shorter than this fraction of B_k to be used
+const BKZ_IMPROVEMENT: f64 = 0.99;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LatticeError {
+    #[error("the basis rows are linearly dependent")]
+    Dependent,
+    #[error("the basis rows have different lengths")]
+    Ragged,
+}
+
+/// Rows of an integer lattice basis
+pub type Basis = Vec<Vec<BigInt>>;
+
+fn dot(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn rational(value: BigInt) -> BigRational {
+    BigRational::from_integer(value)
+}
+
+/// Exact mu_(i,j) for j < i and B_i
+pub struct GramSchmidt {
+    pub mu: Vec<Vec<BigRational>>,
+    pub norms: Vec<BigRational>,
+}
+
+/// Gram-Schmidt data from the Gram matrix, without the b*_i themselves
+pub fn gram_schmidt(basis: &[Vec<BigInt>]) -> Result<GramSchmidt, LatticeError> {
+    let n = basis.len();
+    let mut mu = vec![vec![BigRational::zero(); n]; n];
+    let mut norms: Vec<BigRational> = Vec::with_capacity(n);
+    for i in 0..n {
+        for j in 0..i {
+            let mut value = rational(dot(&basis[i], &basis[j]));
+            for l in 0..j {
+                value -= &mu[j][l] * &mu[i][l] * &norms[l];
+            }
+            mu[i][j] = value / &norms[j];
+        }
+        let mut norm = rational(dot(&basis[i], &basis[i]));
+        for j in 0..i {
+            norm -= &mu[i][j] * &mu[i][j] * &norms[j];
+        }
+        if norm.is_zero() {
+            return Err(LatticeError::Dependent);
+        }
+        norms.push(norm);
+    }
+    Ok(GramSchmidt { mu, norms })
+}
+
+fn check_shape(basis: &[Vec<BigInt>]) -> Result<(), LatticeError> {
+    match basis.first() {
+        Some(first) if basis.iter().any(|row| row.len() != first.len()) => {
+            Err(LatticeError::Ragged)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Integral Gram-Schmidt data: d_0 = 1, d_(i+1) = B_0 ... B_i and
+/// lambda_(i,j) = d_(j+1) mu_(i,j), all of them integers
+struct Integral {
+    lambda: Vec<Vec<BigInt>>,
+    d: Vec<BigInt>,
+}
+
+impl Integral {
+    fn new(basis: &[Vec<BigInt>]) -> Result<Self, LatticeError> {
+        let n = basis.len();
+        let mut lambda = vec![vec![BigInt::zero(); n]; n];
+        let mut d = vec![BigInt::one()];
+        for i in 0..n {
+            for j in 0..=i {
+                let mut u = dot(&basis[i], &basis[j]);
+                for l in 0..j {
+                    u = (&d[l + 1] * u - &lambda[i][l] * &lambda[j][l]) / &d[l];
+                }
+                if j < i {
+                    lambda[i][j] = u;
+                } else if u.is_zero() {
+                    return Err(LatticeError::Dependent);
+                } else {
+                    d.push(u);
+                }
+            }
+        }
+        Ok(Integral { lambda, d })
+    }
+
+    /// B_i / B_k as a float, which stays in range even when the B_i do not
+    fn norm_ratio(&self, i: usize, k: usize) -> f64 {
+        ratio(&(&self.d[i + 1] * &self.d[k]), &(&self.d[i] * &self.d[k + 1]))
+    }
+
+    fn mu(&self, i: usize, j: usize) -> f64 {
+        ratio(&self.lambda[i][j], &self.d[j + 1])
+    }
+}
+
+/// a / b for b > 0, rounded through a 64-bit quotient
+fn ratio(a: &BigInt, b: &BigInt) -> f64 {
+    let shift = b.bits() as i64 - a.bits() as i64 + 64;
+    let quotient = if shift >= 0 { (a << shift as u64) / b } else { a / (b << (-shift) as u64) };
+    quotient.to_f64().unwrap_or(0.0) * 2f64.powi(-shift as i32)
+}
+
+/// LLL with delta = 99/100
+pub fn lll(basis: &mut [Vec<BigInt>]) -> Result<(), LatticeError> {
+    lll_with_delta(basis, &BigRational::new(99.into(), 100.into()))
+}
+
+/// LLL with the Lovász parameter 1/4 < delta <= 1, on the integral
+/// representation of Cohen's Algorithm 2.6.7: the same steps as over the
+/// rationals, but without a gcd on every operation
+pub fn lll_with_delta(basis: &mut [Vec<BigInt>], delta: &BigRational) -> Result<(), LatticeError> {
+    check_shape(basis)?;
+    let n = basis.len();
+    if n < 2 {
+        return Ok(());
+    }
+    let Integral { mut lambda, mut d } = Integral::new(basis)?;
+    let (p, q) = (delta.numer(), delta.denom());
+
+    let mut k = 1;
+    while k < n {
+        size_reduce(basis, &mut lambda, &d, k, k - 1);
+        // B_k >= (delta - mu^2) B_(k-1), multiplied by d_k d_(k-1)
+        let lovasz = q * (&d[k + 1] * &d[k - 1] + &lambda[k][k - 1] * &lambda[k][k - 1]);
+        if lovasz < p * &d[k] * &d[k] {
+            swap(basis, &mut lambda, &mut d, k);
+            k = k.saturating_sub(1).max(1);
+        } else {
+            for l in (0..k - 1).rev() {
+                size_reduce(basis, &mut lambda, &d, k, l);
+            }
+            k += 1;
+        }
+    }
+    Ok(())
+}
+
+/// b_k -= round(mu_(k,l)) b_l
+fn size_reduce(
+    basis: &mut [Vec<BigInt>],
+    lambda: &mut [Vec<BigInt>],
+    d: &[BigInt],
+    k: usize,
+    l: usize,
+) {
+    let denominator = &d[l + 1];
+    if (&lambda[k][l] << 1u32).abs() <= *denominator {
+        return;
+    }
+    // round(lambda / d) = floor((2 lambda + d) / 2d)
+    let q = Integer::div_floor(&((&lambda[k][l] << 1u32) + denominator), &(denominator << 1u32));
+    let (head, tail) = basis.split_at_mut(k);
+    for (x, y) in tail[0].iter_mut().zip(&head[l]) {
+        *x -= &q * y;
+    }
+    lambda[k][l] -= &q * denominator;
+    let (head, tail) = lambda.split_at_mut(k);
+    for (x, y) in tail[0][..l].iter_mut().zip(&head[l][..l]) {
+        *x -= &q * y;
+    }
+}
+
+/// Exchanges b_(k-1) and b_k and updates lambda and d accordingly
+fn swap(basis: &mut [Vec<BigInt>], lambda: &mut [Vec<BigInt>], d: &mut [BigInt], k: usize) {
+    basis.swap(k, k - 1);
+    let (head, tail) = lambda.split_at_mut(k);
+    head[k - 1][..k - 1].swap_with_slice(&mut tail[0][..k - 1]);
+    let m = lambda[k][k - 1].clone();
+    let b = (&d[k - 1] * &d[k + 1] + &m * &m) / &d[k];
+    for row in lambda.iter_mut().skip(k + 1) {
+        let t = row[k].clone();
+        row[k] = (&d[k + 1] * &row[k - 1] - &m * &t) / &d[k];
+        row[k - 1] = (&b * t + &m * &row[k]) / &d[k + 1];
+    }
+    d[k] = b;
+}
+
+/// Schnorr-Euchner enumeration of the shortest nonzero vector of the
+/// lattice generated by the projections of b_k, ..., b_(h-1) orthogonal to
+/// b_0, ..., b_(k-1), given as integer coefficients on those rows, if it
+/// is shorter than `radius`
+fn enumerate(mu: &[Vec<f64>], norms: &[f64], radius: f64) -> Option<Vec<i64>> {
+    let d = norms.len();
+    let mut search = Enumeration {
+        mu,
+        norms,
+        x: vec![0; d],
+        best: radius,
+        best_x: None,
+    };
+    search.level(d - 1, 0.0, true);
+    search.best_x
+}
+
+struct Enumeration<'a> {
+    mu: &'a [Vec<f64>],
+    norms: &'a [f64],
+    x: Vec<i64>,
+    best: f64,
+    best_x: Option<Vec<i64>>,
+}
+
+impl Enumeration<'_> {
+    /// Tries x_i in order of increasing |x_i - c_i|, with `partial` the
+    /// squared length contributed by the levels above i
+    fn level(&mut self, i: usize, partial: f64, zero_above: bool) {
+        let center = -(i + 1..self.x.len())
+            .map(|j| self.x[j] as f64 * self.mu[j][i])
+            .sum::<f64>();
+        let start = center.round() as i64;
+        let direction = if center >= start as f64 { 1 } else { -1 };
+
+        for step in 0i64.. {
+            // v and -v have the same length: only x_i >= 0 on the top
+            // nonzero level
+            let candidate = if zero_above {
+                step
+            } else if step % 2 == 1 {
+                start + direction * ((step + 1) / 2)
+            } else {
+                start - direction * (step / 2)
+            };
+            let diff = candidate as f64 - center;
+            let length = partial + diff * diff * self.norms[i];
+            if length >= self.best {
+                break;
+            }
+            self.x[i] = candidate;
+            if i > 0 {
+                self.level(i - 1, length, zero_above && candidate == 0);
+            } else if !(zero_above && candidate == 0) {
+                self.best = length;
+                self.best_x = Some(self.x.clone());
+            }
+        }
+        self.x[i] = 0;
+    }
+}
+
+/// Rewrites rows k.. so that sum x_i b_(k+i) becomes a single row, moved to
+/// position k, by Euclid's algorithm on pairs of coefficients
+fn insert(basis: &mut [Vec<BigInt>], k: usize, mut x: Vec<i64>) {
+    loop {
+        let nonzero: Vec<usize> = (0..x.len()).filter(|&i| x[i] != 0).collect();
+        let [i, j, ..] = nonzero[..] else {
+            break;
+        };
+        // x_i b_i + x_j b_j = (x_i - q x_j) b_i + x_j (b_j + q b_i)
+        let (big, small) = if x[i].abs() >= x[j].abs() { (i, j) } else { (j, i) };
+        let q = Integer::div_floor(&x[big], &x[small]);
+        x[big] -= q * x[small];
+        let (q, source) = (BigInt::from(q), basis[k + big].clone());
+        for (target, value) in basis[k + small].iter_mut().zip(&source) {
+            *target += &q * value;
+        }
+    }
+    let t = x.iter().position(|&c| c != 0).expect("nonzero vector");
+    if x[t] < 0 {
+        for value in basis[k + t].iter_mut() {
+            *value = -value.clone();
+        }
+    }
+    basis[k..=k + t].rotate_right(1);
+}
+
+/// BKZ with block size `block_size` (2 is LLL)
+pub fn bkz(basis: &mut [Vec<BigInt>], block_size: usize) -> Result<(), LatticeError> {
+    lll(basis)?;
+    let n = basis.len();
+    if block_size < 2 || n < 2 {
+        return Ok(());
+    }
+
+    for _ in 0..MAX_BKZ_TOURS {
+        let mut improved = false;
+        for k in 0..n - 1 {
+            let h = (k + block_size).min(n);
+            // lengths relative to B_k
+            let gso = Integral::new(basis)?;
+            let block_norms: Vec<f64> = (k..h).map(|i| gso.norm_ratio(i, k)).collect();
+            let block_mu: Vec<Vec<f64>> = (k..h)
+                .map(|i| (k..h).map(|j| if j < i { gso.mu(i, j) } else { 0.0 }).collect())
+                .collect();
+
+            let radius = BKZ_IMPROVEMENT;
+            let Some(x) = enumerate(&block_mu, &block_norms, radius) else {
+                continue;
+            };
+            // the first basis row of the block is already the shortest
+            if x.iter().skip(1).all(|&c| c == 0) {
+                continue;
+            }
+            insert(basis, k, x);
+            lll(basis)?;
+            improved = true;
+        }
+        if !improved {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// The shortest row, by Euclidean norm
+pub fn shortest_row(basis: &[Vec<BigInt>]) -> Option<&Vec<BigInt>> {
+    basis.iter().min_by_key(|row| dot(row, row))
+}
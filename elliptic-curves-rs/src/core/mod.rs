@@ -1,4 +1,16 @@
+#[cfg(feature = "fault-countermeasures")]
+pub mod countermeasures;
 pub mod curve;
+pub mod extension;
 pub mod field;
+pub mod group;
+pub mod integer;
+pub mod ladder;
+pub mod lattice;
+pub mod msm;
+pub mod pairing;
 pub mod point;
-pub mod traits;
\ No newline at end of file
+pub mod traits;
+
+#[cfg(test)]
+mod tests;
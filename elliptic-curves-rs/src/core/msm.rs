@@ -0,0 +1,68 @@
+//! Multi-scalar multiplication with Pippenger's bucket method
+//!
+//! Computes k_1 P_1 + ... + k_m P_m with far fewer additions than m
+//! separate double-and-add runs. The scalars are cut into c-bit windows;
+//! for each window every point is added once into the bucket of its digit,
+//! and the running sum
+//!
+//! ```text
+//! sum_{d=1}^{2^c - 1} d * B_d = B_top + (B_top + B_{top-1}) + ...
+//! ```
+//!
+//! weights the buckets. The windows are then combined from the top with c
+//! doublings between them. About (bits / c) (m + 2^c) additions in total,
+//! instead of roughly 1.5 * bits * m.
+
+use crate::core::curve::Curve;
+use crate::core::point::{CurvePoint, Point};
+
+use ark_ff::{BigInteger, PrimeField};
+
+/// Window width: about ln(m) bits, at least 2
+fn window_bits(len: usize) -> usize {
+    if len < 4 {
+        2
+    } else {
+        (len.ilog2() as usize * 69 / 100) + 2
+    }
+}
+
+/// k_1 P_1 + ... + k_m P_m. Panics if the slices have different lengths.
+pub fn multi_scalar_mul<C: Curve>(
+    points: &[CurvePoint<C>],
+    scalars: &[C::ScalarField],
+) -> CurvePoint<C> {
+    assert_eq!(points.len(), scalars.len(), "one scalar per point");
+    let c = window_bits(points.len());
+    let bits: Vec<Vec<bool>> = scalars
+        .iter()
+        .map(|k| k.into_bigint().to_bits_le())
+        .collect();
+    let max_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+
+    let mut result = Point::infinity();
+    for window in (0..max_bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            result = C::double_point(&result);
+        }
+
+        let mut buckets = vec![Point::infinity(); (1 << c) - 1];
+        for (point, bits) in points.iter().zip(&bits) {
+            let digit = (0..c)
+                .filter(|i| bits.get(window * c + i).copied().unwrap_or(false))
+                .fold(0usize, |digit, i| digit | (1 << i));
+            if digit != 0 {
+                buckets[digit - 1] = C::add_point(&buckets[digit - 1], &point.inner);
+            }
+        }
+
+        let mut running = Point::infinity();
+        let mut sum = Point::infinity();
+        for bucket in buckets.iter().rev() {
+            running = C::add_point(&running, bucket);
+            sum = C::add_point(&sum, &running);
+        }
+        result = C::add_point(&result, &sum);
+    }
+    CurvePoint { inner: result }
+}
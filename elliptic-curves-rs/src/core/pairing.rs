@@ -0,0 +1,179 @@
+//! Reduced Tate pairing through Miller's algorithm
+//!
+//! For P in E(F_p)[n] and R in E(F_{p^k}), where k is the embedding
+//! degree of n, the Miller function f_(n,P) has divisor n(P) - n(O), and
+//!
+//! ```text
+//! t(P, R) = f_(n,P)(R)^((p^k - 1) / n)
+//! ```
+//!
+//! is bilinear, with values in the n-th roots of unity of F_{p^k}^*.
+//! f_(n,P) is built in double-and-add order out of the lines that the
+//! group law draws anyway, each one divided by the vertical line through
+//! the resulting point.
+
+use crate::core::curve::Curve;
+use crate::core::extension::{ExtensionConfig, ExtensionField};
+use crate::core::field::PrimeField;
+use crate::core::point::{CurvePoint, Point};
+
+use ark_ff::{Field as ArkField, Zero};
+use num_bigint::BigUint;
+use rand::Rng;
+
+use std::fmt;
+
+/// Affine point of E(F_{p^k}) for a curve defined over F_p
+pub struct ExtensionPoint<P: ExtensionConfig> {
+    pub x: ExtensionField<P>,
+    pub y: ExtensionField<P>,
+}
+
+impl<P: ExtensionConfig> ExtensionPoint<P> {
+    /// E(F_p) is a subgroup of E(F_{p^k})
+    pub fn embed<C>(point: &CurvePoint<C>) -> Option<Self>
+    where
+        C: Curve,
+        P: ExtensionConfig<BaseField = C::BaseField>,
+    {
+        let (x, y) = (point.inner.x()?, point.inner.y()?);
+        Some(Self { x: ExtensionField::from_base(x), y: ExtensionField::from_base(y) })
+    }
+
+    /// A uniformly random x with a square right-hand side, and one of
+    /// its two y
+    pub fn random<C, R>(rng: &mut R) -> Self
+    where
+        C: Curve,
+        P: ExtensionConfig<BaseField = C::BaseField>,
+        R: Rng,
+    {
+        loop {
+            let x = ExtensionField::random(rng);
+            if let Some(y) = rhs::<C, P>(&x).sqrt() {
+                return Self { x, y };
+            }
+        }
+    }
+
+    pub fn is_on_curve<C>(&self) -> bool
+    where
+        C: Curve,
+        P: ExtensionConfig<BaseField = C::BaseField>,
+    {
+        self.y.square() == rhs::<C, P>(&self.x)
+    }
+}
+
+impl<P: ExtensionConfig> Clone for ExtensionPoint<P> {
+    fn clone(&self) -> Self {
+        Self { x: self.x.clone(), y: self.y.clone() }
+    }
+}
+
+impl<P: ExtensionConfig> fmt::Debug for ExtensionPoint<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtensionPoint").field("x", &self.x).field("y", &self.y).finish()
+    }
+}
+
+/// x^3 + a x + b
+fn rhs<C, P>(x: &ExtensionField<P>) -> ExtensionField<P>
+where
+    C: Curve,
+    P: ExtensionConfig<BaseField = C::BaseField>,
+{
+    x.square()
+        .mul(x)
+        .add(&x.scale(&C::a()))
+        .add(&ExtensionField::from_base(C::b()))
+}
+
+/// Line through T (and U, or tangent at T) evaluated at R, together
+/// with the vertical line through the third intersection T + U.
+/// Returns (line, vertical, T + U).
+fn line<C, P>(
+    t: (C::BaseField, C::BaseField),
+    u: (C::BaseField, C::BaseField),
+    r: &ExtensionPoint<P>,
+) -> (ExtensionField<P>, ExtensionField<P>, Point<C::BaseField>)
+where
+    C: Curve,
+    P: ExtensionConfig<BaseField = C::BaseField>,
+{
+    let (x_t, y_t) = t;
+    let (x_u, y_u) = u;
+    let x_r_minus_x_t = r.x.sub(&ExtensionField::from_base(x_t));
+
+    let slope = if x_t != x_u {
+        (y_u - y_t) * (x_u - x_t).inverse().expect("x_t != x_u")
+    } else if y_t == y_u && !y_t.is_zero() {
+        (C::BaseField::from_u64(3) * x_t * x_t + C::a())
+            * (C::BaseField::from_u64(2) * y_t).inverse().expect("y_t != 0")
+    } else {
+        // U = -T: the line itself is vertical and T + U = O
+        return (x_r_minus_x_t, ExtensionField::one(), Point::Infinity);
+    };
+
+    let value = r.y
+        .sub(&ExtensionField::from_base(y_t))
+        .sub(&x_r_minus_x_t.scale(&slope));
+    let sum = C::add_point(&Point::new(x_t, y_t), &Point::new(x_u, y_u));
+    let vertical = match sum.x() {
+        Some(x) => r.x.sub(&ExtensionField::from_base(x)),
+        None => ExtensionField::one(),
+    };
+    (value, vertical, sum)
+}
+
+/// f_(n,P)(R), or None when R happens to lie on one of the lines
+pub fn miller_loop<C, P>(
+    p: &CurvePoint<C>,
+    r: &ExtensionPoint<P>,
+    n: &BigUint,
+) -> Option<ExtensionField<P>>
+where
+    C: Curve,
+    P: ExtensionConfig<BaseField = C::BaseField>,
+{
+    let base = (p.inner.x()?, p.inner.y()?);
+    // numerator and denominator are kept apart to invert only once
+    let mut numerator = ExtensionField::one();
+    let mut denominator = ExtensionField::one();
+    let mut t = p.inner.clone();
+
+    for i in (0..n.bits().saturating_sub(1)).rev() {
+        let current = (t.x()?, t.y()?);
+        let (l, v, doubled) = line::<C, P>(current, current, r);
+        numerator = numerator.square().mul(&l);
+        denominator = denominator.square().mul(&v);
+        t = doubled;
+
+        if n.bit(i) {
+            let current = (t.x()?, t.y()?);
+            let (l, v, sum) = line::<C, P>(current, base, r);
+            numerator = numerator.mul(&l);
+            denominator = denominator.mul(&v);
+            t = sum;
+        }
+    }
+
+    if numerator.is_zero() {
+        return None;
+    }
+    Some(numerator.mul(&denominator.inverse()?))
+}
+
+/// Reduced Tate pairing t(P, R) for P of order n dividing p^k - 1
+pub fn tate_pairing<C, P>(
+    p: &CurvePoint<C>,
+    r: &ExtensionPoint<P>,
+    n: &BigUint,
+) -> Option<ExtensionField<P>>
+where
+    C: Curve,
+    P: ExtensionConfig<BaseField = C::BaseField>,
+{
+    let f = miller_loop(p, r, n)?;
+    Some(f.pow(&((P::size() - 1u32) / n)))
+}
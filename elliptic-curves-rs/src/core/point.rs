@@ -1,6 +1,9 @@
 use crate::core::field::Field;
 use crate::core::curve::Curve;
 
+use ark_ff::{BigInteger, Field as ArkField, PrimeField as ArkPrimeField};
+use num_bigint::BigUint;
+
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,7 +40,6 @@ impl<F: Field> Point<F> {
     }
 }
 
-#[derive(Debug, Eq)]
 pub struct CurvePoint<C: Curve> {
     pub inner: Point<C::BaseField>,
 }
@@ -70,6 +72,63 @@ impl<C: Curve> CurvePoint<C> {
         let p = C::mul_scalar(&self.inner, scalar);
         CurvePoint { inner: p }
     }
+
+    /// The point with x-coordinate `x` whose y has the given parity, if
+    /// x^3 + ax + b is a square
+    pub fn lift_x(x: C::BaseField, odd_y: bool) -> Option<Self> {
+        let rhs = x * x * x + C::a() * x + C::b();
+        let y = ArkField::sqrt(&rhs)?;
+        let y = if y.into_bigint().is_odd() == odd_y { y } else { -y };
+        Some(CurvePoint::new(x, y))
+    }
+
+    /// Bytes of a SEC1 compressed point: the prefix and x
+    pub fn compressed_len() -> usize {
+        1 + (C::BaseField::MODULUS_BIT_SIZE as usize).div_ceil(8)
+    }
+
+    /// SEC1 compressed encoding 0x02 / 0x03 (even / odd y) followed by x,
+    /// None for the point at infinity
+    pub fn to_compressed(&self) -> Option<Vec<u8>> {
+        let (x, y) = (self.inner.x()?, self.inner.y()?);
+        let x = x.into_bigint().to_bytes_be();
+        let mut bytes = vec![if y.into_bigint().is_odd() { 0x03 } else { 0x02 }];
+        bytes.extend_from_slice(&x[x.len() + 1 - Self::compressed_len()..]);
+        Some(bytes)
+    }
+
+    /// Inverse of `to_compressed`; rejects other prefixes, x >= p and x
+    /// that belongs to no point of the curve
+    pub fn from_compressed(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::compressed_len() {
+            return None;
+        }
+        let odd_y = match bytes[0] {
+            0x02 => false,
+            0x03 => true,
+            _ => return None,
+        };
+        let x = BigUint::from_bytes_be(&bytes[1..]);
+        if x >= C::BaseField::MODULUS.into() {
+            return None;
+        }
+        // y = 0 has no odd twin
+        Self::lift_x(C::BaseField::from(x), odd_y)
+            .filter(|point| point.to_compressed().is_some_and(|encoded| encoded[0] == bytes[0]))
+    }
+
+    /// kP without reducing k modulo the order, for points that may lie
+    /// outside the subgroup generated by `C::generator()`
+    pub fn mul_biguint(&self, k: &BigUint) -> Self {
+        let mut result = Point::infinity();
+        for i in (0..k.bits()).rev() {
+            result = C::double_point(&result);
+            if k.bit(i) {
+                result = C::add_point(&result, &self.inner);
+            }
+        }
+        CurvePoint { inner: result }
+    }
 }
 
 impl<C: Curve> Clone for CurvePoint<C> {
@@ -86,6 +145,17 @@ impl<C: Curve> PartialEq for CurvePoint<C> {
     }
 }
 
+impl<C: Curve> Eq for CurvePoint<C> {}
+
+// derive(Debug)는 C: Debug를 요구하므로 직접 구현
+impl<C: Curve> fmt::Debug for CurvePoint<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CurvePoint")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
 impl<C: Curve> fmt::Display for CurvePoint<C>
 where
     C::BaseField: fmt::Display,
@@ -0,0 +1,277 @@
+use crate::core::curve::Curve;
+use crate::core::extension::ExtensionConfig;
+use crate::core::group::{Group, Multiplicative};
+use crate::core::integer::{
+    crt,
+    factorize,
+    is_probable_prime,
+    solve_linear_congruence,
+};
+use crate::core::ladder::{is_on_twist, ladder};
+use crate::core::lattice::{
+    GramSchmidt,
+    LatticeError,
+    bkz,
+    gram_schmidt,
+    lll,
+    shortest_row,
+};
+use crate::core::msm::multi_scalar_mul;
+use crate::core::pairing::{ExtensionPoint, tate_pairing};
+use crate::curves::toy::toy::{FqToy16, FrToy16, PointToy16, Toy16Curve};
+use crate::curves::weak::weak::{
+    Fq2Supersingular,
+    FqSupersingular,
+    SupersingularCurve,
+    SupersingularExtension,
+};
+
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::Signed;
+
+fn big(n: u64) -> BigUint {
+    BigUint::from(n)
+}
+
+#[test]
+fn test_primality_and_factorization() {
+    assert!(is_probable_prime(&big(41233)));
+    assert!(is_probable_prime(&big(46575771467050153)));
+    assert!(!is_probable_prime(&big(41479 * 41233)));
+
+    // p - 1 of the 56-bit toy field
+    let factors = factorize(&big(46575771388594806));
+    assert_eq!(factors, vec![
+        (big(2), 1),
+        (big(3), 1),
+        (big(97), 1),
+        (big(113), 1),
+        (big(55057), 1),
+        (big(12863113), 1),
+    ]);
+    assert_eq!(factorize(&big(1 << 10)), vec![(big(2), 10)]);
+}
+
+#[test]
+fn test_crt_and_linear_congruence() {
+    let residues = [(big(2), big(3)), (big(3), big(5)), (big(2), big(7))];
+    assert_eq!(crt(&residues), Some((big(23), big(105))));
+
+    // 6x = 4 (mod 10): x = 4 or 9
+    assert_eq!(solve_linear_congruence(&big(6), &big(4), &big(10), 16), vec![big(4), big(9)]);
+    // 6x = 3 (mod 10) has no solution
+    assert!(solve_linear_congruence(&big(6), &big(3), &big(10), 16).is_empty());
+}
+
+#[test]
+fn test_curve_point_group_laws() {
+    let g = Toy16Curve::generator();
+    let k = big(1234);
+
+    assert_eq!(g.pow(&k), g.mul_scalar(&FrToy16::from(1234u64)));
+    assert_eq!(g.op(&g.inverse()), Group::identity());
+    assert!(g.pow(&<Toy16Curve as Curve>::order().into()).is_identity());
+    assert_eq!(g.encode().len(), 1 + 2 * 8);
+    assert_eq!(PointToy16::identity().encode(), vec![0x00]);
+}
+
+#[test]
+fn test_multiplicative_group_laws() {
+    let g = Multiplicative(FqToy16::from(3u64));
+    let order = <Multiplicative<FqToy16> as Group>::order();
+    assert_eq!(order, big(41478));
+
+    // 3 generates F_41479^*, so only g^(p-1) is the identity
+    assert!(g.pow(&order).is_identity());
+    for (prime, _) in factorize(&order) {
+        assert!(!g.pow(&(&order / prime)).is_identity());
+    }
+    assert_eq!(g.op(&g.inverse()), Multiplicative::identity());
+    assert!(!Multiplicative(FqToy16::from(0u64)).is_element());
+}
+
+#[test]
+fn test_extension_field_arithmetic() {
+    let i = Fq2Supersingular::generator();
+    let minus_one = Fq2Supersingular::from_base(-FqSupersingular::from(1u64));
+    assert_eq!(i.square(), minus_one);
+    assert_eq!(i.pow(&big(4)), Fq2Supersingular::one());
+
+    let mut rng = rand::thread_rng();
+    let a = Fq2Supersingular::random(&mut rng);
+    let b = Fq2Supersingular::random(&mut rng);
+    assert_eq!(a.mul(&b).mul(&b.inverse().unwrap()), a);
+    assert_eq!(a.add(&b).sub(&b), a);
+    assert_eq!(a.pow(&SupersingularExtension::size()), a);
+    assert!(Fq2Supersingular::zero().inverse().is_none());
+
+    let root = a.square().sqrt().unwrap();
+    assert!(root == a || root == a.neg());
+    // every element of F_p is a square in F_{p^2}
+    assert!(Fq2Supersingular::from_base(FqSupersingular::from(5u64)).is_square());
+
+    let g = Multiplicative(a);
+    assert!(g.pow(&<Multiplicative<Fq2Supersingular> as Group>::order()).is_identity());
+    assert_eq!(g.op(&g.inverse()), Multiplicative::identity());
+}
+
+#[test]
+fn test_tate_pairing_is_bilinear() {
+    let n = BigUint::from(SupersingularCurve::order());
+    let p = SupersingularCurve::generator();
+    let r = ExtensionPoint::<SupersingularExtension>::random::<SupersingularCurve, _>(
+        &mut rand::thread_rng(),
+    );
+    assert!(r.is_on_curve::<SupersingularCurve>());
+    assert!(ExtensionPoint::<SupersingularExtension>::embed(&p)
+        .unwrap()
+        .is_on_curve::<SupersingularCurve>());
+
+    let e = tate_pairing(&p, &r, &n).unwrap();
+    assert!(!e.is_one());
+    assert!(e.pow(&n).is_one());
+
+    let (a, b) = (big(1234), big(56789));
+    let pa = p.pow(&a);
+    let pab = pa.pow(&b);
+    assert_eq!(tate_pairing(&pa, &r, &n).unwrap(), e.pow(&a));
+    assert_eq!(tate_pairing(&pab, &r, &n).unwrap(), e.pow(&(&a * &b)));
+    // t(P1 + P2, R) = t(P1, R) t(P2, R)
+    assert_eq!(
+        tate_pairing(&pa.add(&pab), &r, &n).unwrap(),
+        e.pow(&a).mul(&e.pow(&(&a * &b))),
+    );
+}
+
+#[test]
+fn test_multi_scalar_mul_matches_naive_sum() {
+    let g = Toy16Curve::generator();
+    for len in [0usize, 1, 3, 17, 40] {
+        let mut points: Vec<PointToy16> = (0..len as u64).map(|i| g.mul_scalar(&FrToy16::from(7 * i + 3))).collect();
+        let mut scalars: Vec<FrToy16> = (0..len as u64).map(|i| FrToy16::from(i * i * 977 + 5)).collect();
+        if len > 2 {
+            // zero scalars, repeated points and a point at infinity
+            scalars[1] = FrToy16::from(0u64);
+            scalars[2] = -FrToy16::from(1u64);
+        }
+        if len > 3 {
+            points[3] = PointToy16::infinity();
+            points[0] = points[len - 1].clone();
+        }
+
+        let expected = points
+            .iter()
+            .zip(&scalars)
+            .fold(PointToy16::infinity(), |sum, (point, k)| sum.add(&point.mul_scalar(k)));
+        assert_eq!(multi_scalar_mul(&points, &scalars), expected, "len {len}");
+    }
+}
+
+#[test]
+fn test_ladder_matches_affine_multiplication() {
+    let g = Toy16Curve::generator();
+    let x = g.inner.x().unwrap();
+    for k in [0u64, 1, 2, 3, 5, 1000, 41232, 41233, 41234] {
+        let expected = g.mul_biguint(&big(k)).inner.x();
+        assert_eq!(ladder::<Toy16Curve>(&x, &big(k)), expected);
+    }
+
+    // y^2 = x^3 + x has points of order two and four
+    let g = SupersingularCurve::generator();
+    let x = g.inner.x().unwrap();
+    assert_eq!(ladder::<SupersingularCurve>(&x, &big(1115307497)), None);
+    assert_eq!(
+        ladder::<SupersingularCurve>(&FqSupersingular::from(0u64), &big(2)),
+        None
+    );
+    assert!(!is_on_twist::<SupersingularCurve>(&x));
+}
+
+#[test]
+fn test_ladder_on_the_twist() {
+    // #E' = 2 * 41480 - 41233 = 41727 = 3 * 7 * 1987 for Toy16
+    let x = (0u64..)
+        .map(FqToy16::from)
+        .find(is_on_twist::<Toy16Curve>)
+        .unwrap();
+    assert_eq!(ladder::<Toy16Curve>(&x, &big(41727)), None);
+    let sevenfold = ladder::<Toy16Curve>(&x, &big(3 * 1987));
+    if let Some(x7) = sevenfold {
+        assert_eq!(ladder::<Toy16Curve>(&x7, &big(7)), None);
+        assert!(is_on_twist::<Toy16Curve>(&x7));
+    }
+}
+
+fn basis(rows: &[&[i64]]) -> Vec<Vec<BigInt>> {
+    rows.iter().map(|row| row.iter().map(|&x| BigInt::from(x)).collect()).collect()
+}
+
+fn norm(row: &[BigInt]) -> BigInt {
+    row.iter().map(|x| x * x).sum()
+}
+
+#[test]
+fn test_lll_reduces() {
+    let mut b = basis(&[&[1, 1, 1], &[-1, 0, 2], &[3, 5, 6]]);
+    lll(&mut b).unwrap();
+    assert_eq!(b, basis(&[&[0, 1, 0], &[1, 0, 1], &[-1, 0, 2]]));
+
+    // a random-looking basis of a lattice that contains (1, 0, 0, 0, 3)
+    let mut b = basis(&[
+        &[1, 0, 0, 0, 123456],
+        &[0, 1, 0, 0, 654321],
+        &[0, 0, 1, 0, 111111],
+        &[0, 0, 0, 1, 999999],
+        &[0, 0, 0, 0, 1000003],
+    ]);
+    lll(&mut b).unwrap();
+    let GramSchmidt { mu, norms } = gram_schmidt(&b).unwrap();
+    let half = BigRational::new(1.into(), 2.into());
+    let delta = BigRational::new(99.into(), 100.into());
+    for i in 1..b.len() {
+        assert!((0..i).all(|j| mu[i][j].abs() <= half));
+        let mu_sq = &mu[i][i - 1] * &mu[i][i - 1];
+        assert!(norms[i] >= (&delta - mu_sq) * &norms[i - 1]);
+    }
+
+    let mut dependent = basis(&[&[1, 2], &[2, 4]]);
+    assert_eq!(lll(&mut dependent), Err(LatticeError::Dependent));
+    let mut ragged = basis(&[&[1, 2], &[3]]);
+    assert_eq!(lll(&mut ragged), Err(LatticeError::Ragged));
+}
+
+#[test]
+fn test_bkz_no_worse_than_lll() {
+    // a knapsack lattice: 2^12 * a_i in the last column
+    let weights = [
+        366_521i64, 1_005_743, 847_267, 132_113, 698_417, 521_651, 259_829, 945_593, 432_869,
+        776_303,
+    ];
+    let target: i64 = [0, 2, 3, 5, 7, 8].iter().map(|&i| weights[i]).sum();
+    let mut rows: Vec<Vec<i64>> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, &w)| {
+            let mut row = vec![0; weights.len() + 1];
+            row[i] = 2;
+            row[weights.len()] = w << 12;
+            row
+        })
+        .collect();
+    let mut last = vec![1; weights.len() + 1];
+    last[weights.len()] = target << 12;
+    rows.push(last);
+    let rows: Vec<&[i64]> = rows.iter().map(Vec::as_slice).collect();
+
+    let mut reduced = basis(&rows);
+    lll(&mut reduced).unwrap();
+    let mut stronger = basis(&rows);
+    bkz(&mut stronger, 6).unwrap();
+
+    let lll_best = norm(shortest_row(&reduced).unwrap());
+    let bkz_best = norm(shortest_row(&stronger).unwrap());
+    assert!(bkz_best <= lll_best);
+    // the subset sum gives a +-1 vector of length sqrt(10)
+    assert_eq!(bkz_best, BigInt::from(10));
+}
@@ -1 +1,4 @@
-pub mod secp256k1;
\ No newline at end of file
+pub mod p256;
+pub mod secp256k1;
+pub mod toy;
+pub mod weak;
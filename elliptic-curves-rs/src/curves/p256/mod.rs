@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+pub mod p256;
+
+#[cfg(test)]
+mod tests;
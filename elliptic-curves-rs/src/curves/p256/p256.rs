@@ -0,0 +1,95 @@
+use crate::core::field::{Field, PrimeField};
+use crate::core::curve::Curve;
+use crate::core::point::{Point, CurvePoint};
+
+use ark_ff::{
+    fields::{MontBackend, MontConfig},
+    Field as ArkField,
+    PrimeField as ArkPrimeField,
+    Fp256,
+};
+
+use std::str::FromStr;
+
+// Struct related to the NIST P-256 (secp256r1) prime 2^256 - 2^224 + 2^192 + 2^96 - 1
+#[derive(MontConfig, PartialEq, Debug)]
+#[modulus = "115792089210356248762697446949407573530086143415290314195533631308867097853951"]
+#[generator = "6"]
+pub struct P256Curve;
+pub type FqP256 = Fp256<MontBackend<P256Curve, 4>>;
+pub type PointP256 = CurvePoint<P256Curve>;
+
+// Struct related to P-256 subgroup order (the cofactor is 1)
+#[derive(MontConfig, PartialEq, Debug)]
+#[modulus = "115792089210356248762697446949407573529996955224135760342422259061068512044369"]
+#[generator = "7"]
+pub struct P256ScalarConfig;
+pub type FrP256 = Fp256<MontBackend<P256ScalarConfig, 4>>;
+
+impl Field for FqP256 {
+    fn zero() -> Self {
+        ark_ff::Zero::zero()
+    }
+
+    fn one() -> Self {
+        ark_ff::One::one()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+
+    fn inv(&self) -> Self {
+        self.inverse().unwrap()
+    }
+
+    fn neg(&self) -> Self {
+        -*self
+    }
+}
+
+impl PrimeField for FqP256 {
+    fn from_u64(n: u64) -> Self {
+        FqP256::from(n)
+    }
+}
+
+impl Curve for P256Curve {
+    type BaseField = FqP256;
+    type ScalarField = FrP256;
+
+    // a = -3
+    fn a() -> FqP256 {
+        -FqP256::from_u64(3)
+    }
+
+    fn b() -> FqP256 {
+        FqP256::from_str(
+            "41058363725152142129326129780047268409114441015993725554835256314039467401291"
+        ).unwrap()
+    }
+
+    fn generator() -> CurvePoint<Self> {
+        let x = FqP256::from_str(
+            "48439561293906451759052585252797914202762949526041747995844080717082404635286"
+        ).unwrap();
+        let y = FqP256::from_str(
+            "36134250956749795798585127919587881956611106672985015071877198253568414405109"
+        ).unwrap();
+
+        let inner_affine = Point::new(x, y);
+        CurvePoint { inner: inner_affine }
+    }
+
+    fn order() -> <Self::ScalarField as ArkPrimeField>::BigInt {
+        FrP256::MODULUS
+    }
+}
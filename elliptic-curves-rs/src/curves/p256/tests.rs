@@ -0,0 +1,41 @@
+use super::p256::{FqP256, FrP256, P256Curve, PointP256};
+use crate::core::curve::Curve;
+use crate::core::field::PrimeField;
+
+use ark_ff::BigInt;
+
+use std::str::FromStr;
+
+#[test]
+fn test_check_parameters() {
+    assert_eq!(P256Curve::a(), -FqP256::from_u64(3));
+    assert!(!P256Curve::is_singular());
+    assert!(P256Curve::is_on_curve(&P256Curve::generator().inner));
+
+    let order = P256Curve::order();
+    let expected_order = BigInt::from_str(
+        "115792089210356248762697446949407573529996955224135760342422259061068512044369"
+    ).unwrap();
+    assert_eq!(order, expected_order);
+
+    // the cofactor is 1: nG = O
+    let n: num_bigint::BigUint = order.into();
+    assert!(P256Curve::generator().mul_biguint(&n).inner.is_infinity());
+}
+
+#[test]
+fn test_scalar_mul() {
+    // 2G from the NIST test vectors
+    let two_g = PointP256::new(
+        FqP256::from_str(
+            "56515219790691171413109057904011688695424810155802929973526481321309856242040"
+        ).unwrap(),
+        FqP256::from_str(
+            "3377031843712258259223711451491452598088675519751548567112458094635497583569"
+        ).unwrap(),
+    );
+    let g = P256Curve::generator();
+    assert_eq!(g.double(), two_g);
+    assert_eq!(g.mul_scalar(&FrP256::from(2u64)), two_g);
+    assert_eq!(two_g.subtract(&g), g);
+}
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod secp256k1;
 
 #[cfg(test)]
@@ -7,11 +7,9 @@ use ark_ff::{
     Field as ArkField,
     PrimeField as ArkPrimeField,
     Fp256,
-    Zero,
 };
 
 use std::str::FromStr;
-use std::u64;
 
 // Struct related to secp256k1 prime
 #[derive(MontConfig, PartialEq, Debug)]
@@ -96,107 +94,4 @@ impl Curve for Secp256k1Curve {
     fn order() -> <Self::ScalarField as ArkPrimeField>::BigInt {
         FrSecp256k1::MODULUS
     }
-
-    fn is_on_curve(p: &Point<FqSecp256k1>) -> bool {
-        let a = Secp256k1Curve::a();
-        let b = Secp256k1Curve::b();
-        // y^2 = x^3 + ax + b
-        match (p.x(), p.y()) {
-            (Some(x), Some(y)) => {
-                let left_term = y.square();
-                let right_term = x.pow([3u64]) + a * x + b;
-                left_term == right_term
-            }
-            _ => true,
-        }
-    }
-
-    fn add_point(
-        p: &Point<FqSecp256k1>,
-        q: &Point<FqSecp256k1>,
-    ) -> Point<FqSecp256k1> {
-        use Point::*;
-
-        match (p, q) {
-            (Infinity, _) => q.clone(),
-            (_, Infinity) => p.clone(),
-            (Affine { x: x1, y: y1 }, Affine { x: x2, y:y2 }) => {
-                // P + (-P) = infinity
-                if x1 == x2 && (y1 + y2).is_zero() {
-                    return Infinity;
-                }
-
-                // P + P = 2P
-                if x1 == x2 && y1 == y2 {
-                    return Self::double_point(p);
-                }
-
-                // P + Q (P != Q)
-                let m = {
-                    let numerator = y1 - y2;
-                    let denominator = (x1 - x2).inverse()
-                        .expect("denominator is zero.");
-                    numerator * denominator
-                };
-                let x_r = m.square() - x1 - x2;
-                let y_r = m * (x1 - &x_r) - y1;
-
-                Point::Affine { x: x_r, y: y_r }
-            }
-        }
-    }
-
-    fn double_point(p: &Point<FqSecp256k1>) -> Point<FqSecp256k1> {
-        use Point::*;
-        match p {
-            Infinity => Infinity,
-            Affine { x, y } => {
-                let two = FqSecp256k1::from_u64(2);
-                let three = FqSecp256k1::from_u64(3);
-                let numerator = three * x.square() + Self::a();
-                let denominator = (two * y).inverse()
-                    .expect("denominator is zero.");
-                let m = numerator * denominator;
-                let x_r = m.square() - x - x;
-                let y_r = m * (x - &x_r) - y;
-                Affine { x: x_r, y: y_r }
-            }
-        }
-    }
-
-    fn mul_scalar(
-        p: &Point<FqSecp256k1>,
-        scalar: &FrSecp256k1,
-    ) -> Point<FqSecp256k1> {
-        let mut result = Point::infinity();
-        let acc = p.clone();
-        let bits = scalar.into_bigint().0;
-        for limb in bits.iter().rev() {
-            for i in (0..64).rev() {
-                result = Self::double_point(&result);
-                if (limb >> i) & 1 == 1 {
-                    result = Self::add_point(&result, &acc);
-                }
-            }
-        }
-        result
-    }
-
-    fn subtract_point(
-            p: &Point<FqSecp256k1>,
-            q: &Point<FqSecp256k1>,
-        ) -> Point<FqSecp256k1> {
-        let neg_q = Self::negate_point(q);
-        Self::add_point(p, &neg_q)
-    }
-
-    fn negate_point(p: &Point<FqSecp256k1>) -> Point<FqSecp256k1> {
-        match p {
-            Point::Infinity => Point::Infinity,
-            Point::Affine { x, y } => Point::Affine {
-                x: *x,
-                y: y.neg()
-            },
-        }
-    }
 }
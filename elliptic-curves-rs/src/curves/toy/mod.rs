@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+pub mod toy;
+
+#[cfg(test)]
+mod tests;
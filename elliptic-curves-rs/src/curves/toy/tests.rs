@@ -0,0 +1,40 @@
+use super::toy::*;
+use crate::core::curve::Curve;
+
+use ark_ff::{One, PrimeField as ArkPrimeField};
+
+fn check_group_order<C: Curve>() {
+    let g = C::generator();
+    assert!(C::is_on_curve(&g.inner));
+    assert!(!g.inner.is_infinity());
+
+    // (n - 1) * G = -G, so (n - 1) * G + G = infinity
+    let n_minus_one = -C::ScalarField::one();
+    let almost = g.mul_scalar(&n_minus_one);
+    assert_eq!(almost.inner, C::negate_point(&g.inner));
+    assert!(almost.add(&g).inner.is_infinity());
+}
+
+#[test]
+fn test_toy_curves_have_prime_order_generators() {
+    check_group_order::<Toy16Curve>();
+    check_group_order::<Toy20Curve>();
+    check_group_order::<Toy24Curve>();
+    check_group_order::<Toy28Curve>();
+    check_group_order::<Toy32Curve>();
+    check_group_order::<Toy40Curve>();
+    check_group_order::<Toy48Curve>();
+    check_group_order::<Toy56Curve>();
+}
+
+#[test]
+fn test_toy_scalar_mul_matches_addition() {
+    let g = Toy16Curve::generator();
+    let mut acc = PointToy16::infinity();
+    for k in 1..=20u64 {
+        acc = acc.add(&g);
+        assert_eq!(acc, g.mul_scalar(&FrToy16::from(k)));
+        assert!(Toy16Curve::is_on_curve(&acc.inner));
+    }
+    assert_eq!(Toy16Curve::order(), FrToy16::MODULUS);
+}
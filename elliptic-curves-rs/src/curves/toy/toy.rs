@@ -0,0 +1,230 @@
+//! Toy curves y^2 = x^3 + 7 over small prime fields
+//!
+//! They share the shape of secp256k1 (a = 0, b = 7) but their prime
+//! field and prime group order are only 16 to 56 bits wide, so the
+//! algorithms in `breaking_dlp` can actually finish on them. Every curve
+//! has prime order, therefore any point except infinity generates it.
+
+use crate::core::field::{Field, PrimeField};
+use crate::core::curve::Curve;
+use crate::core::point::{Point, CurvePoint};
+
+use ark_ff::{
+    fields::{MontBackend, MontConfig},
+    Field as ArkField,
+    PrimeField as ArkPrimeField,
+    Fp64,
+};
+
+macro_rules! toy_curve {
+    (
+        $(#[$meta:meta])*
+        curve: $curve:ident,
+        base_field: $fq:ident,
+        scalar_config: $scalar_config:ident,
+        scalar_field: $fr:ident,
+        point: $point:ident,
+        modulus: $modulus:tt,
+        modulus_generator: $modulus_generator:tt,
+        order: $order:tt,
+        order_generator: $order_generator:tt,
+        coefficients: ($a:expr, $b:expr),
+        generator: ($gx:expr, $gy:expr),
+    ) => {
+        $(#[$meta])*
+        #[derive(MontConfig, PartialEq, Debug)]
+        #[modulus = $modulus]
+        #[generator = $modulus_generator]
+        pub struct $curve;
+        pub type $fq = Fp64<MontBackend<$curve, 1>>;
+        pub type $point = CurvePoint<$curve>;
+
+        #[derive(MontConfig, PartialEq, Debug)]
+        #[modulus = $order]
+        #[generator = $order_generator]
+        pub struct $scalar_config;
+        pub type $fr = Fp64<MontBackend<$scalar_config, 1>>;
+
+        impl Field for $fq {
+            fn zero() -> Self {
+                ark_ff::Zero::zero()
+            }
+
+            fn one() -> Self {
+                ark_ff::One::one()
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                *self + *other
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                self - other
+            }
+
+            fn mul(&self, other: &Self) -> Self {
+                self * other
+            }
+
+            fn inv(&self) -> Self {
+                self.inverse().unwrap()
+            }
+
+            fn neg(&self) -> Self {
+                -*self
+            }
+        }
+
+        impl PrimeField for $fq {
+            fn from_u64(n: u64) -> Self {
+                <$fq>::from(n)
+            }
+        }
+
+        impl Curve for $curve {
+            type BaseField = $fq;
+            type ScalarField = $fr;
+
+            fn a() -> $fq {
+                <$fq>::from_u64($a)
+            }
+
+            fn b() -> $fq {
+                <$fq>::from_u64($b)
+            }
+
+            fn generator() -> CurvePoint<Self> {
+                let x = <$fq>::from_u64($gx);
+                let y = <$fq>::from_u64($gy);
+                CurvePoint { inner: Point::new(x, y) }
+            }
+
+            fn order() -> <Self::ScalarField as ArkPrimeField>::BigInt {
+                <$fr>::MODULUS
+            }
+        }
+    };
+}
+
+// other modules under `curves` reuse it for curves with special structure
+pub(crate) use toy_curve;
+
+toy_curve! {
+    /// 16-bit field, order 41233
+    curve: Toy16Curve,
+    base_field: FqToy16,
+    scalar_config: Toy16ScalarConfig,
+    scalar_field: FrToy16,
+    point: PointToy16,
+    modulus: "41479",
+    modulus_generator: "3",
+    order: "41233",
+    order_generator: "5",
+    coefficients: (0, 7),
+    generator: (23313, 11947),
+}
+
+toy_curve! {
+    /// 20-bit field, order 649633
+    curve: Toy20Curve,
+    base_field: FqToy20,
+    scalar_config: Toy20ScalarConfig,
+    scalar_field: FrToy20,
+    point: PointToy20,
+    modulus: "648289",
+    modulus_generator: "7",
+    order: "649633",
+    order_generator: "7",
+    coefficients: (0, 7),
+    generator: (282008, 537153),
+}
+
+toy_curve! {
+    /// 24-bit field, order 9044047
+    curve: Toy24Curve,
+    base_field: FqToy24,
+    scalar_config: Toy24ScalarConfig,
+    scalar_field: FrToy24,
+    point: PointToy24,
+    modulus: "9038377",
+    modulus_generator: "7",
+    order: "9044047",
+    order_generator: "3",
+    coefficients: (0, 7),
+    generator: (8938343, 4867126),
+}
+
+toy_curve! {
+    /// 28-bit field, order 197855839
+    curve: Toy28Curve,
+    base_field: FqToy28,
+    scalar_config: Toy28ScalarConfig,
+    scalar_field: FrToy28,
+    point: PointToy28,
+    modulus: "197866783",
+    modulus_generator: "3",
+    order: "197855839",
+    order_generator: "3",
+    coefficients: (0, 7),
+    generator: (55626835, 111230592),
+}
+
+toy_curve! {
+    /// 32-bit field, order 2784376579
+    curve: Toy32Curve,
+    base_field: FqToy32,
+    scalar_config: Toy32ScalarConfig,
+    scalar_field: FrToy32,
+    point: PointToy32,
+    modulus: "2784333193",
+    modulus_generator: "5",
+    order: "2784376579",
+    order_generator: "3",
+    coefficients: (0, 7),
+    generator: (2525491855, 2299240288),
+}
+
+toy_curve! {
+    /// 40-bit field, order 744041241607
+    curve: Toy40Curve,
+    base_field: FqToy40,
+    scalar_config: Toy40ScalarConfig,
+    scalar_field: FrToy40,
+    point: PointToy40,
+    modulus: "744041962249",
+    modulus_generator: "7",
+    order: "744041241607",
+    order_generator: "6",
+    coefficients: (0, 7),
+    generator: (298069599836, 206217731204),
+}
+
+toy_curve! {
+    /// 48-bit field, order 160211325739897
+    curve: Toy48Curve,
+    base_field: FqToy48,
+    scalar_config: Toy48ScalarConfig,
+    scalar_field: FrToy48,
+    point: PointToy48,
+    modulus: "160211301158809",
+    modulus_generator: "7",
+    order: "160211325739897",
+    order_generator: "7",
+    coefficients: (0, 7),
+    generator: (119377857455940, 125588289277988),
+}
+
+toy_curve! {
+    /// 56-bit field, order 46575771467050153
+    curve: Toy56Curve,
+    base_field: FqToy56,
+    scalar_config: Toy56ScalarConfig,
+    scalar_field: FrToy56,
+    point: PointToy56,
+    modulus: "46575771388594807",
+    modulus_generator: "3",
+    order: "46575771467050153",
+    order_generator: "5",
+    coefficients: (0, 7),
+    generator: (33837762177431707, 40863518909461301),
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+pub mod weak;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,44 @@
+use super::weak::*;
+use crate::core::curve::Curve;
+
+use ark_ff::{One, PrimeField as ArkPrimeField};
+
+#[test]
+fn test_anomalous_curve_has_p_points() {
+    let g = AnomalousCurve::generator();
+    assert!(AnomalousCurve::is_on_curve(&g.inner));
+
+    // (p - 1) * G = -G
+    let almost = g.mul_scalar(&-FrAnomalous::one());
+    assert_eq!(almost.inner, AnomalousCurve::negate_point(&g.inner));
+    assert!(almost.add(&g).inner.is_infinity());
+    assert_eq!(AnomalousCurve::order(), FqAnomalous::MODULUS);
+}
+
+#[test]
+fn test_supersingular_curve_generator_has_order_n() {
+    let g = SupersingularCurve::generator();
+    assert!(SupersingularCurve::is_on_curve(&g.inner));
+
+    let almost = g.mul_scalar(&-FrSupersingular::one());
+    assert_eq!(almost.inner, SupersingularCurve::negate_point(&g.inner));
+    assert!(almost.add(&g).inner.is_infinity());
+}
+
+#[test]
+fn test_singular_curve_generators_have_order_n() {
+    fn check<C: Curve>() {
+        let g = C::generator();
+        assert!(C::is_singular());
+        assert!(C::is_on_curve(&g.inner));
+        let almost = g.mul_scalar(&-C::ScalarField::one());
+        assert_eq!(almost.inner, C::negate_point(&g.inner));
+        assert!(almost.add(&g).inner.is_infinity());
+    }
+    check::<CuspCurve>();
+    check::<SplitNodeCurve>();
+    check::<NonSplitNodeCurve>();
+
+    assert!(!AnomalousCurve::is_singular());
+    assert!(!SupersingularCurve::is_singular());
+}
@@ -0,0 +1,118 @@
+//! Deliberately weak curves with special structure
+//!
+//! Each curve here has a property that lets a dedicated attack in
+//! `breaking_dlp` bypass the generic O(sqrt(n)) bound on the curve:
+//! Smart's attack solves the anomalous curve outright, and the MOV
+//! reduction moves the logarithm of the supersingular curve into
+//! F_{p^2}^*, where index calculus applies. The singular "curves" are
+//! not elliptic curves at all: their points form F_p^+, F_p^* or a
+//! subgroup of F_{p^2}^* in disguise.
+
+use crate::core::field::{Field, PrimeField};
+use crate::core::curve::Curve;
+use crate::core::extension::{ExtensionConfig, ExtensionField};
+use crate::core::point::{Point, CurvePoint};
+use crate::curves::toy::toy::toy_curve;
+
+use ark_ff::{
+    fields::{MontBackend, MontConfig},
+    Field as ArkField,
+    PrimeField as ArkPrimeField,
+    Fp64,
+};
+
+toy_curve! {
+    /// y^2 = x^3 + 12 over a 56-bit field with exactly p points
+    /// (trace of Frobenius 1), broken by Smart's attack
+    curve: AnomalousCurve,
+    base_field: FqAnomalous,
+    scalar_config: AnomalousScalarConfig,
+    scalar_field: FrAnomalous,
+    point: PointAnomalous,
+    modulus: "36028805887801711",
+    modulus_generator: "6",
+    order: "36028805887801711",
+    order_generator: "6",
+    coefficients: (0, 12),
+    generator: (31610267243417122, 13842490298903118),
+}
+
+toy_curve! {
+    /// y^2 = x^3 + x over a 33-bit field with p = 3 (mod 4), which is
+    /// supersingular: #E = p + 1 = 4n, so n | p^2 - 1 (embedding degree 2)
+    curve: SupersingularCurve,
+    base_field: FqSupersingular,
+    scalar_config: SupersingularScalarConfig,
+    scalar_field: FrSupersingular,
+    point: PointSupersingular,
+    modulus: "4461229987",
+    modulus_generator: "2",
+    order: "1115307497",
+    order_generator: "3",
+    coefficients: (1, 0),
+    generator: (709886057, 3591143892),
+}
+
+/// F_{p^2} = F_p[i] / (i^2 + 1) for `SupersingularCurve`; -1 is not a
+/// square modulo p = 3 (mod 4)
+pub struct SupersingularExtension;
+
+impl ExtensionConfig for SupersingularExtension {
+    type BaseField = FqSupersingular;
+
+    fn modulus() -> Vec<FqSupersingular> {
+        vec![FqSupersingular::from(1u64), FqSupersingular::from(0u64)]
+    }
+}
+
+pub type Fq2Supersingular = ExtensionField<SupersingularExtension>;
+
+toy_curve! {
+    /// The cusp y^2 = x^3 over a 56-bit field; its non-singular points
+    /// form a group isomorphic to F_p^+, of order p
+    curve: CuspCurve,
+    base_field: FqCusp,
+    scalar_config: CuspScalarConfig,
+    scalar_field: FrCusp,
+    point: PointCusp,
+    modulus: "42054750253943621",
+    modulus_generator: "2",
+    order: "42054750253943621",
+    order_generator: "2",
+    coefficients: (0, 0),
+    generator: (27041439461791832, 3031929198377585),
+}
+
+toy_curve! {
+    /// A node y^2 = (x - alpha)^2 (x + 2 alpha) whose tangent slopes at
+    /// (alpha, 0) lie in F_p (split); the group is F_p^* of order
+    /// p - 1 = 2n, and G generates the subgroup of order n
+    curve: SplitNodeCurve,
+    base_field: FqSplitNode,
+    scalar_config: SplitNodeScalarConfig,
+    scalar_field: FrSplitNode,
+    point: PointSplitNode,
+    modulus: "5110459967",
+    modulus_generator: "5",
+    order: "2555229983",
+    order_generator: "5",
+    coefficients: (4177766158, 1164701275),
+    generator: (2093912710, 4999013026),
+}
+
+toy_curve! {
+    /// A node whose tangent slopes at (alpha, 0) lie in F_{p^2} only
+    /// (non-split); the group has order p + 1 = 2n, and G generates the
+    /// subgroup of order n
+    curve: NonSplitNodeCurve,
+    base_field: FqNonSplitNode,
+    scalar_config: NonSplitNodeScalarConfig,
+    scalar_field: FrNonSplitNode,
+    point: PointNonSplitNode,
+    modulus: "6438400873",
+    modulus_generator: "5",
+    order: "3219200437",
+    order_generator: "6",
+    coefficients: (3124696648, 2051869336),
+    generator: (3349189735, 4747058822),
+}
@@ -3,3 +3,4 @@ pub mod curves;
 pub mod implementations;
 pub mod protocols;
 pub mod breaking_dlp;
+pub mod attacks;
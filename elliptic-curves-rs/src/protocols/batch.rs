@@ -0,0 +1,47 @@
+//! ECDSA와 Schnorr 배치 검증이 함께 쓰는 부분
+//!
+//! 각 서명의 검증식 V_i = O를 임의의 계수 a_i로 묶어
+//!
+//! ```text
+//! a_1 V_1 + a_2 V_2 + ... + a_u V_u = O
+//! ```
+//!
+//! 하나만 확인한다. 좌변은 한 번의 multi-scalar multiplication이다
+//! (`core::msm`). 모두 유효하면 항상 성립하고, 하나라도 틀리면 a_i를 모르는
+//! 공격자가 맞출 확률은 1/n 정도다. 실패하면 서명을 하나씩 검증해 어느 것이
+//! 틀렸는지 찾는다.
+
+use ark_ff::PrimeField;
+use ark_std::rand::thread_rng;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("signatures at {failed:?} are invalid")]
+pub struct BatchError {
+    /// 틀린 서명의 위치, 오름차순
+    pub failed: Vec<usize>,
+}
+
+/// a_1 = 1, 나머지는 0이 아닌 난수
+pub(crate) fn coefficients<F: PrimeField>(len: usize) -> Vec<F> {
+    let mut rng = thread_rng();
+    (0..len)
+        .map(|i| {
+            if i == 0 {
+                return F::one();
+            }
+            loop {
+                let a = F::rand(&mut rng);
+                if !a.is_zero() {
+                    return a;
+                }
+            }
+        })
+        .collect()
+}
+
+/// 배치 검증이 실패했을 때 하나씩 검증한 결과
+pub(crate) fn fallback(len: usize, verify: impl Fn(usize) -> bool) -> Result<(), BatchError> {
+    let failed: Vec<usize> = (0..len).filter(|&i| !verify(i)).collect();
+    if failed.is_empty() { Ok(()) } else { Err(BatchError { failed }) }
+}
@@ -0,0 +1,135 @@
+//! 서명 인코딩에 필요한 만큼의 ASN.1 DER: 음이 아닌 INTEGER들의 SEQUENCE
+//!
+//! ```text
+//! 30 len (02 len r) (02 len s)
+//! ```
+//!
+//! 파싱은 DER 규칙을 그대로 강제한다. 길이는 최소 바이트로, 정수는 불필요한
+//! 선행 0x00 없이, 음수 없이, 뒤에 남는 바이트 없이.
+
+use num_bigint::BigUint;
+use thiserror::Error;
+
+const SEQUENCE: u8 = 0x30;
+const INTEGER: u8 = 0x02;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DerError {
+    #[error("unexpected tag {0:#04x}")]
+    Tag(u8),
+    #[error("input ends early")]
+    Truncated,
+    #[error("trailing bytes after the encoding")]
+    Trailing,
+    #[error("length is not minimally encoded")]
+    NonMinimalLength,
+    #[error("integer has a superfluous leading zero byte")]
+    NonMinimalInteger,
+    #[error("integer is negative")]
+    Negative,
+    #[error("integer is empty")]
+    EmptyInteger,
+}
+
+fn encode_length(len: usize, out: &mut Vec<u8>) {
+    if len < 0x80 {
+        out.push(len as u8);
+        return;
+    }
+    let bytes = len.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&byte| byte == 0).count();
+    out.push(0x80 | (bytes.len() - skip) as u8);
+    out.extend_from_slice(&bytes[skip..]);
+}
+
+/// INTEGER: 최상위 비트가 서 있으면 0x00을 앞에 붙인다
+fn encode_integer(value: &BigUint, out: &mut Vec<u8>) {
+    let mut bytes = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0x00);
+    }
+    out.push(INTEGER);
+    encode_length(bytes.len(), out);
+    out.extend_from_slice(&bytes);
+}
+
+pub fn encode_sequence(integers: &[&BigUint]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for value in integers {
+        encode_integer(value, &mut body);
+    }
+    let mut out = vec![SEQUENCE];
+    encode_length(body.len(), &mut out);
+    out.extend(body);
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DerError> {
+        if self.bytes.len() < len {
+            return Err(DerError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn length(&mut self) -> Result<usize, DerError> {
+        let first = self.take(1)?[0];
+        if first < 0x80 {
+            return Ok(first as usize);
+        }
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > size_of::<usize>() {
+            return Err(DerError::NonMinimalLength);
+        }
+        let bytes = self.take(count)?;
+        if bytes[0] == 0 {
+            return Err(DerError::NonMinimalLength);
+        }
+        let len = bytes.iter().fold(0usize, |len, &byte| (len << 8) | byte as usize);
+        if len < 0x80 {
+            return Err(DerError::NonMinimalLength);
+        }
+        Ok(len)
+    }
+
+    /// tag와 길이를 읽고 내용을 돌려준다
+    fn element(&mut self, tag: u8) -> Result<&'a [u8], DerError> {
+        let found = self.take(1)?[0];
+        if found != tag {
+            return Err(DerError::Tag(found));
+        }
+        let len = self.length()?;
+        self.take(len)
+    }
+
+    fn integer(&mut self) -> Result<BigUint, DerError> {
+        let content = self.element(INTEGER)?;
+        match content {
+            [] => Err(DerError::EmptyInteger),
+            [first, ..] if first & 0x80 != 0 => Err(DerError::Negative),
+            [0x00, second, ..] if second & 0x80 == 0 => Err(DerError::NonMinimalInteger),
+            _ => Ok(BigUint::from_bytes_be(content)),
+        }
+    }
+}
+
+/// 정확히 `count`개의 INTEGER로 된 SEQUENCE
+pub fn decode_sequence(bytes: &[u8], count: usize) -> Result<Vec<BigUint>, DerError> {
+    let mut outer = Reader { bytes };
+    let body = outer.element(SEQUENCE)?;
+    if !outer.bytes.is_empty() {
+        return Err(DerError::Trailing);
+    }
+    let mut inner = Reader { bytes: body };
+    let integers = (0..count).map(|_| inner.integer()).collect::<Result<Vec<_>, _>>()?;
+    if !inner.bytes.is_empty() {
+        return Err(DerError::Trailing);
+    }
+    Ok(integers)
+}
@@ -0,0 +1,575 @@
+//! 딜러 없는 분산 키 생성 (Gennaro, Jarecki, Krawczyk, Rabin)
+//!
+//! n명이 각자 딜러가 되어 비밀 z_i를 Pedersen VSS로 나눠 주고, 받은 조각을
+//! 더해 공유 비밀 z = sum z_i의 t-of-n 조각 x_j를 얻는다. 누구도 z를 모른다.
+//!
+//! ```text
+//! 1. 공유:     C_ik = a_ik G + b_ik H를 방송하고 j에게 (f_i(j), f'_i(j))를 보낸다
+//! 2. 불만:     조각이 C_i와 맞지 않으면 i를 고발한다
+//! 3. 공개:     고발당한 딜러는 고발한 사람의 조각을 모두에게 공개한다
+//! 4. 자격:     고발이 t개 이상이거나 공개한 조각이 틀린 딜러는 탈락한다 (QUAL)
+//!              x_j = sum_{i in QUAL} f_i(j)
+//! 5. 추출:     QUAL의 딜러가 A_ik = a_ik G를 방송한다
+//! 6. 확인:     f_i(j) G != sum_k A_ik j^k이면 C_i에 맞는 조각을 증거로 고발한다
+//! 7. 복원:     고발이 맞은 딜러는 나머지가 조각을 공개해 f_i를 보간으로 되찾는다
+//! 결과:        Y = sum_{i in QUAL} z_i G,  Y_j = x_j G
+//! ```
+//!
+//! Pedersen commitment는 z_i에 대해 아무것도 드러내지 않으므로, 마지막 딜러가
+//! 다른 사람들의 commitment를 보고 자기 것을 골라 Y를 치우치게 할 수 없다.
+//! A_i는 자격이 정해진 다음에야 공개된다. 정직한 참가자가 t명 이상이고 나쁜
+//! 참가자가 t명 미만이면 정직한 참가자들은 같은 Y와 QUAL에 도달한다.
+//!
+//! 참가자는 라운드마다 받은 메시지를 넣으면 다음 라운드에 보낼 메시지를 내는
+//! 상태 기계다. `Network`는 참가자마다의 큐로 메시지를 배달한다.
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::protocols::frost::{Ciphersuite, Identifier, KeyPackage, PublicKeyPackage};
+use crate::protocols::secret_sharing::{
+    FeldmanCommitment, PedersenCommitment, PedersenShare, Polynomial, Share, evaluate_commitment, interpolate,
+};
+
+use ark_ff::Zero;
+use ark_std::{UniformRand, rand::thread_rng};
+use thiserror::Error;
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+
+/// 공유부터 복원까지의 라운드 수
+pub const ROUNDS: usize = 7;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DkgError {
+    #[error("need 2 <= threshold <= participants, got {threshold} of {participants}")]
+    InvalidThreshold { threshold: u16, participants: u16 },
+    #[error("participant {0} is unknown")]
+    UnknownParticipant(Identifier),
+    #[error("every dealer was disqualified")]
+    NoQualifiedDealers,
+    #[error("fewer than threshold valid shares to reconstruct dealer {0}")]
+    ReconstructionFailed(Identifier),
+    #[error("the protocol has already finished")]
+    Finished,
+}
+
+/// 방송이면 보낸 사람을 포함해 모두가 받는다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recipient {
+    All,
+    One(Identifier),
+}
+
+pub enum Payload<C: Curve> {
+    /// 1라운드 방송: 두 다항식의 Pedersen commitment
+    Commitment(PedersenCommitment<C>),
+    /// 1라운드 개인 메시지: (f_i(j), f'_i(j))
+    Share(PedersenShare<C>),
+    /// 2라운드 방송: 이 딜러의 조각이 틀렸다
+    Complaint(Identifier),
+    /// 3라운드 방송: 고발한 사람에게 보냈어야 할 조각
+    Reveal { complainer: Identifier, share: PedersenShare<C> },
+    /// 5라운드 방송: f_i 계수의 Feldman commitment
+    PublicCommitment(FeldmanCommitment<C>),
+    /// 6라운드 방송: C_i에는 맞지만 A_i에는 맞지 않는 조각
+    PublicComplaint { dealer: Identifier, share: PedersenShare<C> },
+    /// 7라운드 방송: f_i를 복원하기 위해 공개하는 조각
+    Reconstruct { dealer: Identifier, share: PedersenShare<C> },
+}
+
+impl<C: Curve> Clone for Payload<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Payload::Commitment(commitment) => Payload::Commitment(commitment.clone()),
+            Payload::Share(share) => Payload::Share(share.clone()),
+            Payload::Complaint(dealer) => Payload::Complaint(*dealer),
+            Payload::Reveal { complainer, share } => Payload::Reveal { complainer: *complainer, share: share.clone() },
+            Payload::PublicCommitment(commitment) => Payload::PublicCommitment(commitment.clone()),
+            Payload::PublicComplaint { dealer, share } => {
+                Payload::PublicComplaint { dealer: *dealer, share: share.clone() }
+            }
+            Payload::Reconstruct { dealer, share } => Payload::Reconstruct { dealer: *dealer, share: share.clone() },
+        }
+    }
+}
+
+impl<C: Curve> fmt::Debug for Payload<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Payload::Commitment(commitment) => f.debug_tuple("Commitment").field(commitment).finish(),
+            Payload::Share(share) => f.debug_tuple("Share").field(share).finish(),
+            Payload::Complaint(dealer) => f.debug_tuple("Complaint").field(dealer).finish(),
+            Payload::Reveal { complainer, share } => {
+                f.debug_struct("Reveal").field("complainer", complainer).field("share", share).finish()
+            }
+            Payload::PublicCommitment(commitment) => f.debug_tuple("PublicCommitment").field(commitment).finish(),
+            Payload::PublicComplaint { dealer, share } => {
+                f.debug_struct("PublicComplaint").field("dealer", dealer).field("share", share).finish()
+            }
+            Payload::Reconstruct { dealer, share } => {
+                f.debug_struct("Reconstruct").field("dealer", dealer).field("share", share).finish()
+            }
+        }
+    }
+}
+
+pub struct Envelope<C: Curve> {
+    pub from: Identifier,
+    pub to: Recipient,
+    pub payload: Payload<C>,
+}
+
+impl<C: Curve> Clone for Envelope<C> {
+    fn clone(&self) -> Self {
+        Envelope { from: self.from, to: self.to, payload: self.payload.clone() }
+    }
+}
+
+impl<C: Curve> fmt::Debug for Envelope<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Envelope")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+/// 참가자마다의 수신 큐
+pub struct Network<C: Curve> {
+    queues: BTreeMap<Identifier, VecDeque<Envelope<C>>>,
+}
+
+impl<C: Curve> Network<C> {
+    pub fn new(participants: impl IntoIterator<Item = Identifier>) -> Self {
+        Network { queues: participants.into_iter().map(|id| (id, VecDeque::new())).collect() }
+    }
+
+    /// 없는 참가자에게 보낸 메시지는 버린다
+    pub fn send(&mut self, envelope: Envelope<C>) {
+        match envelope.to {
+            Recipient::All => {
+                for queue in self.queues.values_mut() {
+                    queue.push_back(envelope.clone());
+                }
+            }
+            Recipient::One(id) => {
+                if let Some(queue) = self.queues.get_mut(&id) {
+                    queue.push_back(envelope);
+                }
+            }
+        }
+    }
+
+    /// `id`에게 온 메시지를 도착한 순서대로 모두 꺼낸다
+    pub fn receive(&mut self, id: Identifier) -> Vec<Envelope<C>> {
+        self.queues.get_mut(&id).map(|queue| queue.drain(..).collect()).unwrap_or_default()
+    }
+}
+
+/// 라운드마다 모든 참가자를 한 번씩 진행시키고, 보낸 메시지는 라운드가 끝난
+/// 뒤에 배달한다. 모두 정직할 때 쓴다
+pub fn run<C: Curve>(participants: &mut [Participant<C>]) -> Result<(), DkgError> {
+    let mut network = Network::new(participants.iter().map(|participant| participant.identifier()));
+    for _ in 0..ROUNDS {
+        let mut outgoing = Vec::new();
+        for participant in participants.iter_mut() {
+            let inbox = network.receive(participant.identifier());
+            outgoing.extend(participant.step(inbox)?);
+        }
+        for envelope in outgoing {
+            network.send(envelope);
+        }
+    }
+    Ok(())
+}
+
+/// 한 참가자가 얻는 것: 자기 조각 x_j, 공개키 Y, 모두의 Y_j와 QUAL
+pub struct DkgOutput<C: Curve> {
+    identifier: Identifier,
+    threshold: u16,
+    secret_share: Share<C>,
+    public_key: CurvePoint<C>,
+    verifying_shares: BTreeMap<Identifier, CurvePoint<C>>,
+    qualified: BTreeSet<Identifier>,
+}
+
+impl<C: Curve> DkgOutput<C> {
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// (j, x_j)
+    pub fn secret_share(&self) -> &Share<C> {
+        &self.secret_share
+    }
+
+    pub fn public_key(&self) -> &CurvePoint<C> {
+        &self.public_key
+    }
+
+    pub fn verifying_shares(&self) -> &BTreeMap<Identifier, CurvePoint<C>> {
+        &self.verifying_shares
+    }
+
+    /// 키에 비밀을 보탠 딜러들
+    pub fn qualified(&self) -> &BTreeSet<Identifier> {
+        &self.qualified
+    }
+
+    /// FROST 서명에 쓰는 키 조각과 공개 정보
+    pub fn to_frost(&self) -> (KeyPackage<C>, PublicKeyPackage<C>)
+    where
+        C: Ciphersuite,
+    {
+        let key_package =
+            KeyPackage::new(self.identifier, *self.secret_share.value(), self.public_key.clone(), self.threshold);
+        (key_package, PublicKeyPackage::new(self.verifying_shares.clone(), self.public_key.clone()))
+    }
+}
+
+impl<C: Curve> fmt::Debug for DkgOutput<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DkgOutput")
+            .field("identifier", &self.identifier)
+            .field("threshold", &self.threshold)
+            .field("secret_share", &self.secret_share)
+            .field("public_key", &self.public_key)
+            .field("qualified", &self.qualified)
+            .finish()
+    }
+}
+
+/// 다음 `step`이 기다리는 메시지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Start,
+    Sharing,
+    Complaining,
+    Revealing,
+    Extracting,
+    Checking,
+    Reconstructing,
+    Done,
+}
+
+/// DKG 참가자 한 명의 상태
+pub struct Participant<C: Curve> {
+    identifier: Identifier,
+    participants: u16,
+    threshold: u16,
+    phase: Phase,
+    polynomial: Polynomial<C>,
+    blinding: Polynomial<C>,
+    /// 딜러마다의 Pedersen commitment와 내가 받은 조각
+    commitments: BTreeMap<Identifier, PedersenCommitment<C>>,
+    shares: BTreeMap<Identifier, PedersenShare<C>>,
+    /// 딜러마다 고발한 참가자들
+    complaints: BTreeMap<Identifier, BTreeSet<Identifier>>,
+    qualified: BTreeSet<Identifier>,
+    public_commitments: BTreeMap<Identifier, FeldmanCommitment<C>>,
+    /// A_i가 틀려서 f_i를 복원해야 하는 딜러
+    exposed: BTreeSet<Identifier>,
+    output: Option<DkgOutput<C>>,
+}
+
+impl<C: Curve> Participant<C> {
+    /// 참가자 1..=n 중 `identifier`. FROST 키로 쓸 수 있도록 2 <= t <= n
+    pub fn new(identifier: Identifier, participants: u16, threshold: u16) -> Result<Self, DkgError> {
+        if threshold < 2 || threshold > participants {
+            return Err(DkgError::InvalidThreshold { threshold, participants });
+        }
+        if identifier.value() > participants {
+            return Err(DkgError::UnknownParticipant(identifier));
+        }
+        let mut rng = thread_rng();
+        let degree = threshold as usize - 1;
+        Ok(Participant {
+            identifier,
+            participants,
+            threshold,
+            phase: Phase::Start,
+            polynomial: Polynomial::random(C::ScalarField::rand(&mut rng), degree),
+            blinding: Polynomial::random(C::ScalarField::rand(&mut rng), degree),
+            commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            complaints: BTreeMap::new(),
+            qualified: BTreeSet::new(),
+            public_commitments: BTreeMap::new(),
+            exposed: BTreeSet::new(),
+            output: None,
+        })
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 마지막 라운드를 마치면 Some
+    pub fn output(&self) -> Option<&DkgOutput<C>> {
+        self.output.as_ref()
+    }
+
+    fn ids(&self) -> impl Iterator<Item = Identifier> + use<C> {
+        (1..=self.participants).filter_map(Identifier::new)
+    }
+
+    fn is_participant(&self, id: Identifier) -> bool {
+        id.value() <= self.participants
+    }
+
+    fn broadcast(&self, payload: Payload<C>) -> Envelope<C> {
+        Envelope { from: self.identifier, to: Recipient::All, payload }
+    }
+
+    /// 내 다항식의 `to` 조각
+    fn share_for(&self, to: Identifier) -> PedersenShare<C> {
+        let x = to.to_scalar::<C::ScalarField>();
+        let share = Share::new(x, self.polynomial.evaluate(x)).expect("identifiers are nonzero");
+        PedersenShare::new(share, self.blinding.evaluate(x))
+    }
+
+    /// `from`이 보낸 조각이 x = from인 점이고 딜러의 C에 맞는지
+    fn verifies(&self, dealer: Identifier, owner: Identifier, share: &PedersenShare<C>) -> bool {
+        *share.share().index() == owner.to_scalar::<C::ScalarField>()
+            && self.commitments.get(&dealer).is_some_and(|commitment| commitment.verify(share))
+    }
+
+    /// 한 라운드에 받은 메시지를 처리하고 다음 라운드에 보낼 메시지를 낸다.
+    /// 처음에는 빈 inbox로 부른다
+    pub fn step(&mut self, inbox: Vec<Envelope<C>>) -> Result<Vec<Envelope<C>>, DkgError> {
+        let inbox: Vec<Envelope<C>> = inbox.into_iter().filter(|envelope| self.is_participant(envelope.from)).collect();
+        let (next, outgoing) = match self.phase {
+            Phase::Start => (Phase::Sharing, self.share()),
+            Phase::Sharing => (Phase::Complaining, self.receive_shares(inbox)),
+            Phase::Complaining => (Phase::Revealing, self.receive_complaints(inbox)),
+            Phase::Revealing => (Phase::Extracting, self.receive_reveals(inbox)?),
+            Phase::Extracting => (Phase::Checking, self.receive_public_commitments(inbox)),
+            Phase::Checking => (Phase::Reconstructing, self.receive_public_complaints(inbox)),
+            Phase::Reconstructing => {
+                self.output = Some(self.receive_reconstructions(inbox)?);
+                (Phase::Done, Vec::new())
+            }
+            Phase::Done => return Err(DkgError::Finished),
+        };
+        self.phase = next;
+        Ok(outgoing)
+    }
+
+    /// 1. commitment를 방송하고 다른 참가자마다 조각을 보낸다
+    fn share(&mut self) -> Vec<Envelope<C>> {
+        let commitment = PedersenCommitment::commit(&self.polynomial, &self.blinding);
+        let mut outgoing = vec![self.broadcast(Payload::Commitment(commitment))];
+        for to in self.ids().filter(|&to| to != self.identifier) {
+            outgoing.push(Envelope {
+                from: self.identifier,
+                to: Recipient::One(to),
+                payload: Payload::Share(self.share_for(to)),
+            });
+        }
+        self.shares.insert(self.identifier, self.share_for(self.identifier));
+        outgoing
+    }
+
+    /// 2. 빠졌거나 C_i에 맞지 않는 조각의 딜러를 고발한다. 같은 종류의 메시지가
+    ///    두 번 오면 처음 것만 쓴다
+    fn receive_shares(&mut self, inbox: Vec<Envelope<C>>) -> Vec<Envelope<C>> {
+        let mut received = BTreeMap::new();
+        for envelope in inbox {
+            match envelope.payload {
+                Payload::Commitment(commitment) if envelope.to == Recipient::All => {
+                    self.commitments.entry(envelope.from).or_insert(commitment);
+                }
+                Payload::Share(share) if envelope.to == Recipient::One(self.identifier) => {
+                    received.entry(envelope.from).or_insert(share);
+                }
+                _ => {}
+            }
+        }
+        // 차수가 틀린 commitment는 commitment가 없는 것과 같다
+        let threshold = self.threshold as usize;
+        self.commitments.retain(|_, commitment| commitment.threshold() == threshold);
+
+        let mut outgoing = Vec::new();
+        for dealer in self.ids().filter(|&dealer| dealer != self.identifier && self.commitments.contains_key(&dealer)) {
+            match received.remove(&dealer) {
+                Some(share) if self.verifies(dealer, self.identifier, &share) => {
+                    self.shares.insert(dealer, share);
+                }
+                _ => outgoing.push(self.broadcast(Payload::Complaint(dealer))),
+            }
+        }
+        outgoing
+    }
+
+    /// 3. 나를 고발한 참가자의 조각을 공개한다
+    fn receive_complaints(&mut self, inbox: Vec<Envelope<C>>) -> Vec<Envelope<C>> {
+        for envelope in inbox {
+            if let (Payload::Complaint(dealer), Recipient::All) = (&envelope.payload, envelope.to)
+                && *dealer != envelope.from
+            {
+                self.complaints.entry(*dealer).or_default().insert(envelope.from);
+            }
+        }
+        let complainers = self.complaints.get(&self.identifier).cloned().unwrap_or_default();
+        complainers
+            .into_iter()
+            .map(|complainer| self.broadcast(Payload::Reveal { complainer, share: self.share_for(complainer) }))
+            .collect()
+    }
+
+    /// 4. QUAL을 정하고, QUAL에 들었으면 A_i를 방송한다
+    fn receive_reveals(&mut self, inbox: Vec<Envelope<C>>) -> Result<Vec<Envelope<C>>, DkgError> {
+        let mut reveals: BTreeMap<(Identifier, Identifier), PedersenShare<C>> = BTreeMap::new();
+        for envelope in inbox {
+            if let (Payload::Reveal { complainer, share }, Recipient::All) = (envelope.payload, envelope.to) {
+                reveals.entry((envelope.from, complainer)).or_insert(share);
+            }
+        }
+
+        for dealer in self.commitments.keys().copied().collect::<Vec<_>>() {
+            let complainers = self.complaints.get(&dealer).cloned().unwrap_or_default();
+            if complainers.len() >= self.threshold as usize {
+                continue;
+            }
+            let answered = complainers.iter().all(|&complainer| {
+                reveals.get(&(dealer, complainer)).is_some_and(|share| self.verifies(dealer, complainer, share))
+            });
+            if !answered {
+                continue;
+            }
+            self.qualified.insert(dealer);
+            // 공개된 조각이 맞으면 그것을 받은 것으로 한다
+            if complainers.contains(&self.identifier) {
+                let share = reveals.remove(&(dealer, self.identifier)).expect("answered");
+                self.shares.insert(dealer, share);
+            }
+        }
+        if self.qualified.is_empty() {
+            return Err(DkgError::NoQualifiedDealers);
+        }
+
+        if !self.qualified.contains(&self.identifier) {
+            return Ok(Vec::new());
+        }
+        Ok(vec![self.broadcast(Payload::PublicCommitment(FeldmanCommitment::commit(&self.polynomial)))])
+    }
+
+    /// f_i(j) G = sum_k A_ik j^k
+    fn matches_public_commitment(&self, dealer: Identifier, share: &Share<C>) -> bool {
+        self.public_commitments.get(&dealer).is_some_and(|commitment| commitment.verify(share))
+    }
+
+    /// 6. A_i에 맞지 않는 조각을 증거로 고발한다. A_i를 보내지 않았으면 모두가
+    ///    알고 있으므로 바로 복원한다
+    fn receive_public_commitments(&mut self, inbox: Vec<Envelope<C>>) -> Vec<Envelope<C>> {
+        for envelope in inbox {
+            if let (Payload::PublicCommitment(commitment), Recipient::All) = (envelope.payload, envelope.to)
+                && self.qualified.contains(&envelope.from)
+                && commitment.threshold() == self.threshold as usize
+            {
+                self.public_commitments.entry(envelope.from).or_insert(commitment);
+            }
+        }
+
+        let mut outgoing = Vec::new();
+        for &dealer in &self.qualified {
+            if !self.public_commitments.contains_key(&dealer) {
+                self.exposed.insert(dealer);
+            } else if dealer != self.identifier && !self.matches_public_commitment(dealer, self.shares[&dealer].share())
+            {
+                let share = self.shares[&dealer].clone();
+                outgoing.push(self.broadcast(Payload::PublicComplaint { dealer, share }));
+            }
+        }
+        outgoing
+    }
+
+    /// 7. 맞는 고발을 받은 딜러의 f_i를 복원하기 위해 내 조각을 공개한다
+    fn receive_public_complaints(&mut self, inbox: Vec<Envelope<C>>) -> Vec<Envelope<C>> {
+        for envelope in inbox {
+            if let (Payload::PublicComplaint { dealer, share }, Recipient::All) = (&envelope.payload, envelope.to)
+                && self.qualified.contains(dealer)
+                && *dealer != envelope.from
+                && self.verifies(*dealer, envelope.from, share)
+                && !self.matches_public_commitment(*dealer, share.share())
+            {
+                self.exposed.insert(*dealer);
+            }
+        }
+        self.exposed
+            .iter()
+            .filter(|&&dealer| dealer != self.identifier)
+            .map(|&dealer| self.broadcast(Payload::Reconstruct { dealer, share: self.shares[&dealer].clone() }))
+            .collect()
+    }
+
+    /// 복원한 딜러는 f_i(j)를 보간으로, 나머지는 A_i로 계산해 Y와 Y_j를 만든다
+    fn receive_reconstructions(&mut self, inbox: Vec<Envelope<C>>) -> Result<DkgOutput<C>, DkgError> {
+        let mut revealed: BTreeMap<Identifier, BTreeMap<Identifier, PedersenShare<C>>> = BTreeMap::new();
+        for envelope in inbox {
+            if let (Payload::Reconstruct { dealer, share }, Recipient::All) = (envelope.payload, envelope.to)
+                && self.exposed.contains(&dealer)
+                && dealer != envelope.from
+                && self.verifies(dealer, envelope.from, &share)
+            {
+                revealed.entry(dealer).or_default().entry(envelope.from).or_insert(share);
+            }
+        }
+
+        let mut points = BTreeMap::new();
+        for &dealer in &self.exposed {
+            let shares = revealed.remove(&dealer).unwrap_or_default();
+            if shares.len() < self.threshold as usize {
+                return Err(DkgError::ReconstructionFailed(dealer));
+            }
+            let dealer_points: Vec<(C::ScalarField, C::ScalarField)> =
+                shares.values().map(|share| (*share.share().index(), *share.share().value())).collect();
+            points.insert(dealer, dealer_points);
+        }
+
+        // A_i를 믿을 수 있는 딜러의 commitment는 계수끼리 더해 한 번에 계산한다
+        let mut public = vec![CurvePoint::<C>::infinity(); self.threshold as usize];
+        for (dealer, commitment) in &self.public_commitments {
+            if !self.exposed.contains(dealer) {
+                for (sum, point) in public.iter_mut().zip(commitment.points()) {
+                    *sum = sum.add(point);
+                }
+            }
+        }
+        let g = C::generator();
+        let evaluate = |x: C::ScalarField| -> CurvePoint<C> {
+            let exposed: C::ScalarField =
+                points.values().map(|dealer_points| interpolate(dealer_points, x).expect("distinct senders")).sum();
+            evaluate_commitment(&public, x).add(&g.mul_scalar(&exposed))
+        };
+
+        let public_key = evaluate(C::ScalarField::zero());
+        let verifying_shares = self.ids().map(|id| (id, evaluate(id.to_scalar()))).collect();
+        let value = self.qualified.iter().map(|dealer| *self.shares[dealer].share().value()).sum();
+        let index = self.identifier.to_scalar();
+        Ok(DkgOutput {
+            identifier: self.identifier,
+            threshold: self.threshold,
+            secret_share: Share::new(index, value).expect("identifiers are nonzero"),
+            public_key,
+            verifying_shares,
+            qualified: self.qualified.clone(),
+        })
+    }
+}
+
+impl<C: Curve> fmt::Debug for Participant<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Participant")
+            .field("identifier", &self.identifier)
+            .field("phase", &self.phase)
+            .field("qualified", &self.qualified)
+            .finish()
+    }
+}
@@ -0,0 +1,110 @@
+//! Dual_EC_DRBG (NIST SP 800-90A, 2006 판, additional input 없음)
+//!
+//! 상태 s는 정수이고, 블록마다
+//!
+//! ```text
+//! r = x(sQ)          출력: r의 하위 (log2 p - 16) 비트
+//! s <- x(sP)
+//! ```
+//!
+//! 를 계산한다. generate 호출이 끝나면 s <- x(sP)를 한 번 더 적용한다.
+//! P, Q 사이의 관계 Q = eP를 아는 사람은 출력 한 블록에서 다음 상태를
+//! 계산할 수 있다 (`attacks::dual_ec` 참고).
+//!
+//! 이 구현에서는 SP 800-90A의 "s <- x(tP), r <- x(sQ)" 순서를 한 단계 앞당겨,
+//! 생성 직후의 상태가 이미 첫 블록의 s가 되도록 두었다. 출력은 같다.
+
+use crate::core::curve::Curve;
+use crate::core::ladder::ladder;
+use crate::core::point::CurvePoint;
+
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+
+/// 표준에서 출력 블록마다 버리는 상위 비트 수
+pub const TRUNCATED_BITS: u32 = 16;
+
+pub struct DualEcDrbg<C: Curve> {
+    p: CurvePoint<C>,
+    q: CurvePoint<C>,
+    state: BigUint,
+    truncated_bits: u32,
+}
+
+/// x(kR), 래더로 계산. k가 R의 위수의 배수가 아니어야 한다
+fn x_of_multiple<C: Curve>(point: &CurvePoint<C>, k: &BigUint) -> BigUint {
+    let x = point.inner.x().expect("P and Q are affine");
+    ladder::<C>(&x, k)
+        .expect("the state is never a multiple of the order")
+        .into_bigint()
+        .into()
+}
+
+impl<C: Curve> DualEcDrbg<C> {
+    /// seed로 초기화하며 표준대로 16비트를 버린다
+    pub fn new(p: CurvePoint<C>, q: CurvePoint<C>, seed: &BigUint) -> Self {
+        Self::with_truncation(p, q, seed, TRUNCATED_BITS)
+    }
+
+    /// 블록마다 `truncated_bits`개의 상위 비트를 버리는 변형. 버리는 비트가
+    /// 적을수록 backdoor 공격이 빨라진다
+    pub fn with_truncation(
+        p: CurvePoint<C>,
+        q: CurvePoint<C>,
+        seed: &BigUint,
+        truncated_bits: u32,
+    ) -> Self {
+        let state = x_of_multiple(&p, seed);
+        Self::from_state(p, q, state, truncated_bits)
+    }
+
+    /// 내부 상태 s가 주어진 생성기. 상태를 복구한 공격자가 이후 출력을
+    /// 예측할 때 쓴다
+    pub fn from_state(
+        p: CurvePoint<C>,
+        q: CurvePoint<C>,
+        state: BigUint,
+        truncated_bits: u32,
+    ) -> Self {
+        DualEcDrbg { p, q, state, truncated_bits }
+    }
+
+    pub fn state(&self) -> &BigUint {
+        &self.state
+    }
+
+    pub fn truncated_bits(&self) -> u32 {
+        self.truncated_bits
+    }
+
+    /// 블록당 출력 바이트 수 (secp256k1, P-256에서 30)
+    pub fn block_len(&self) -> usize {
+        Self::block_len_for(self.truncated_bits)
+    }
+
+    pub fn block_len_for(truncated_bits: u32) -> usize {
+        (C::BaseField::MODULUS_BIT_SIZE - truncated_bits) as usize / 8
+    }
+
+    fn next_block(&mut self) -> Vec<u8> {
+        let r = x_of_multiple(&self.q, &self.state);
+        self.state = x_of_multiple(&self.p, &self.state);
+
+        let bytes = r.to_bytes_be();
+        let len = self.block_len();
+        let mut block = vec![0u8; len.saturating_sub(bytes.len())];
+        block.extend_from_slice(&bytes[bytes.len().saturating_sub(len)..]);
+        block
+    }
+
+    /// `len` 바이트를 출력하고, 마지막에 상태를 한 번 더 갱신한다
+    pub fn generate(&mut self, len: usize) -> Vec<u8> {
+        let mut output = Vec::with_capacity(len + self.block_len());
+        while output.len() < len {
+            output.extend(self.next_block());
+        }
+        output.truncate(len);
+        self.state = x_of_multiple(&self.p, &self.state);
+        output
+    }
+}
@@ -1,11 +1,28 @@
+#[cfg(feature = "fault-countermeasures")]
+use crate::core::countermeasures::FaultError;
 use crate::core::curve::Curve;
+use crate::core::ladder::{is_on_twist, ladder};
 use crate::core::point::CurvePoint;
+use crate::protocols::keys::{PublicKey, SecretKey, SharedSecret};
 
-use ark_ff::Zero;
-use ark_std::{
-    UniformRand,
-    rand::thread_rng,
-};
+use ark_ff::PrimeField as ArkPrimeField;
+use num_bigint::BigUint;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EcdhError {
+    #[error("public key is the point at infinity")]
+    Infinity,
+    #[error("public key is not on the curve")]
+    NotOnCurve,
+    #[error("public key is not in the subgroup of prime order")]
+    NotInSubgroup,
+    #[error("x-coordinate belongs to the quadratic twist")]
+    OnTwist,
+    #[cfg(feature = "fault-countermeasures")]
+    #[error("fault detected: {0}")]
+    Fault(#[from] FaultError),
+}
 
 /// ECDH 프로토콜은 상태를 가지기보단 연산을 수행하는 역할이므로, 상태를 저장하는
 /// 필드가 필요 없음. 이럴 때 PhantomData를 사용하는 것이 rust의 일반적인 패턴
@@ -21,28 +38,105 @@ pub struct Ecdh<C: Curve> {
 }
 
 impl<C: Curve> Ecdh<C> {
-    pub fn generate_keypair() -> (C::ScalarField, CurvePoint<C>) {
-        let mut rng = thread_rng();
-        let g: CurvePoint<C> = C::generator();
-
+    pub fn generate_keypair() -> (SecretKey<C>, PublicKey<C>) {
         // private key is a random integer d chosen from {1, ..., n-1}
         // (n is the order of the subgroup)
-        let private_key = loop {
-            let d = C::ScalarField::rand(&mut rng);
-            if d != C::ScalarField::zero() { break d }
-        };
+        let private_key = SecretKey::random();
         // public key is the point H = dG
-        let public_key = g.mul_scalar(&private_key);
+        let public_key = private_key.public_key();
 
         (private_key, public_key)
     }
 
+    /// 공유 비밀 x(dQ). Q는 `PublicKey`가 이미 검증했으므로 곡선 밖의 점이
+    /// 들어올 수 없다
+    pub fn diffie_hellman(
+        private_key: &SecretKey<C>,
+        other_public_key: &PublicKey<C>,
+    ) -> Result<SharedSecret<C>, EcdhError> {
+        let point = other_public_key.as_point();
+        #[cfg(feature = "fault-countermeasures")]
+        let shared = point.mul_scalar_checked(private_key.expose_scalar())?;
+        #[cfg(not(feature = "fault-countermeasures"))]
+        let shared = Self::compute_shared_secret(private_key.expose_scalar(), point);
+        shared.inner.x().map(SharedSecret::new).ok_or(EcdhError::Infinity)
+    }
+
+    /// 상대 공개키를 검증하지 않음. 덧셈 공식이 b를 쓰지 않으므로 다른 곡선
+    /// 위의 점도 그대로 계산되어 invalid curve attack에 노출된다
+    /// (`attacks::invalid_curve` 참고). 검증이 필요하면
+    /// `compute_shared_secret_checked`를 사용할 것
     pub fn compute_shared_secret(
         private_key: &C::ScalarField,
         other_public_key: &CurvePoint<C>,
     ) -> CurvePoint<C> {
-        let shared = other_public_key.mul_scalar(&private_key);
+        other_public_key.mul_scalar(private_key)
+    }
+
+    /// 무한원점, 곡선 밖의 점, `C::generator()`가 생성하는 부분군 밖의 점을
+    /// 거부한다
+    pub fn validate_public_key(public_key: &CurvePoint<C>) -> Result<(), EcdhError> {
+        if public_key.inner.is_infinity() {
+            return Err(EcdhError::Infinity);
+        }
+        if !C::is_on_curve(&public_key.inner) {
+            return Err(EcdhError::NotOnCurve);
+        }
+        let order: BigUint = C::order().into();
+        if !public_key.mul_biguint(&order).inner.is_infinity() {
+            return Err(EcdhError::NotInSubgroup);
+        }
+        Ok(())
+    }
+
+    /// `validate_public_key`를 거친 `compute_shared_secret`.
+    /// `fault-countermeasures` feature가 켜져 있으면 `mul_scalar_checked`로 계산한다
+    pub fn compute_shared_secret_checked(
+        private_key: &C::ScalarField,
+        other_public_key: &CurvePoint<C>,
+    ) -> Result<CurvePoint<C>, EcdhError> {
+        Self::validate_public_key(other_public_key)?;
+        #[cfg(feature = "fault-countermeasures")]
+        return Ok(other_public_key.mul_scalar_checked(private_key)?);
+        #[cfg(not(feature = "fault-countermeasures"))]
+        Ok(Self::compute_shared_secret(private_key, other_public_key))
+    }
+
+    /// x-only 공개키: 래더로 계산한 dG의 x좌표. d = 0 mod n이면 None
+    pub fn public_key_x(private_key: &C::ScalarField) -> Option<C::BaseField> {
+        let g = C::generator().inner.x().expect("generator is affine");
+        ladder::<C>(&g, &private_key.into_bigint().into())
+    }
+
+    /// x-only ECDH (X25519처럼 y좌표 없이 래더만 사용). 검증하지 않으므로
+    /// twist 위의 x도 그대로 계산된다 (`attacks::twist` 참고).
+    /// None은 무한원점
+    pub fn compute_shared_secret_x(
+        private_key: &C::ScalarField,
+        other_public_x: &C::BaseField,
+    ) -> Option<C::BaseField> {
+        ladder::<C>(other_public_x, &private_key.into_bigint().into())
+    }
 
-        shared
+    /// twist 위의 x좌표와 부분군 밖의 점의 x좌표를 거부한다. 따라서 공유 비밀이
+    /// 무한원점이 되는 경우도 막힌다
+    pub fn validate_public_x(public_x: &C::BaseField) -> Result<(), EcdhError> {
+        if is_on_twist::<C>(public_x) {
+            return Err(EcdhError::OnTwist);
+        }
+        let order: BigUint = C::order().into();
+        if ladder::<C>(public_x, &order).is_some() {
+            return Err(EcdhError::NotInSubgroup);
+        }
+        Ok(())
+    }
+
+    /// `validate_public_x`를 거친 `compute_shared_secret_x`
+    pub fn compute_shared_secret_x_checked(
+        private_key: &C::ScalarField,
+        other_public_x: &C::BaseField,
+    ) -> Result<C::BaseField, EcdhError> {
+        Self::validate_public_x(other_public_x)?;
+        Self::compute_shared_secret_x(private_key, other_public_x).ok_or(EcdhError::Infinity)
     }
 }
@@ -1,56 +1,608 @@
+#[cfg(feature = "fault-countermeasures")]
+use crate::core::countermeasures::FaultError;
 use crate::core::curve::Curve;
+use crate::core::msm::multi_scalar_mul;
 use crate::core::point::CurvePoint;
+use crate::protocols::batch::{self, BatchError};
+use crate::protocols::der::{self, DerError};
+use crate::protocols::keys::{KeyError, PublicKey, SecretKey};
+use crate::protocols::rfc6979::{Nonces, hash_to_scalar};
 
 use ark_ff::{BigInteger, Field, PrimeField, Zero};
 use ark_std::{
     UniformRand,
     rand::thread_rng
 };
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use sha2::digest::core_api::BlockSizeUser;
+use thiserror::Error;
+
+use std::fmt;
+
+/// 서명 nonce k를 고르는 방법
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceMode<'a> {
+    /// `thread_rng()`에서 뽑는다
+    Random,
+    /// RFC 6979, HMAC-SHA-256
+    Deterministic,
+    /// RFC 6979 3.6: 추가 엔트로피 k'을 섞는다. 같은 입력이라도 k'이 다르면
+    /// 서명이 달라지고, RNG가 나빠도 결정적 방식보다 약해지지 않는다
+    DeterministicWithEntropy(&'a [u8]),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SignatureError {
+    #[error("expected {expected} bytes, got {got}")]
+    Length { expected: usize, got: usize },
+    #[error("r or s is not below the group order")]
+    OutOfRange,
+    #[error("r or s is zero")]
+    Zero,
+    #[error("invalid DER: {0}")]
+    Der(#[from] DerError),
+}
+
+/// `Ecdsa::sign` 계열의 실패: 서명을 내보내기 전의 검사에서 잡힌 fault
+#[cfg(feature = "fault-countermeasures")]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SigningError {
+    #[error("fault detected: {0}")]
+    Fault(#[from] FaultError),
+}
+
+/// `fault-countermeasures` 없이는 서명이 실패하지 않는다
+#[cfg(not(feature = "fault-countermeasures"))]
+pub type SigningError = std::convert::Infallible;
+
+/// ECDSA 서명 (r, s), 둘 다 0이 아니다
+pub struct Signature<C: Curve> {
+    r: C::ScalarField,
+    s: C::ScalarField,
+}
+
+impl<C: Curve> Signature<C> {
+    /// r = 0 또는 s = 0이면 None
+    pub fn from_scalars(r: C::ScalarField, s: C::ScalarField) -> Option<Self> {
+        (!r.is_zero() && !s.is_zero()).then_some(Signature { r, s })
+    }
+
+    pub fn r(&self) -> &C::ScalarField {
+        &self.r
+    }
+
+    pub fn s(&self) -> &C::ScalarField {
+        &self.s
+    }
+
+    pub fn to_scalars(&self) -> (C::ScalarField, C::ScalarField) {
+        (self.r, self.s)
+    }
+
+    /// n의 바이트 길이 (secp256k1, P-256에서 32)
+    pub fn scalar_len() -> usize {
+        (C::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(8)
+    }
+
+    /// 고정 길이 r || s (secp256k1, P-256에서 64바이트)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let len = Self::scalar_len();
+        let mut bytes = Vec::with_capacity(2 * len);
+        for value in [self.r, self.s] {
+            let be = value.into_bigint().to_bytes_be();
+            bytes.extend_from_slice(&be[be.len() - len..]);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let len = Self::scalar_len();
+        if bytes.len() != 2 * len {
+            return Err(SignatureError::Length { expected: 2 * len, got: bytes.len() });
+        }
+        let (r, s) = bytes.split_at(len);
+        Self::from_biguints(BigUint::from_bytes_be(r), BigUint::from_bytes_be(s))
+    }
+
+    /// ASN.1 DER `SEQUENCE { r INTEGER, s INTEGER }`
+    pub fn to_der(&self) -> Vec<u8> {
+        let (r, s): (BigUint, BigUint) = (self.r.into(), self.s.into());
+        der::encode_sequence(&[&r, &s])
+    }
+
+    /// 표준 DER만 받는다 (BER의 긴 길이, 선행 0x00, 뒤에 붙은 바이트 거부)
+    pub fn from_der(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let mut integers = der::decode_sequence(bytes, 2)?;
+        let s = integers.pop().expect("two integers");
+        let r = integers.pop().expect("two integers");
+        Self::from_biguints(r, s)
+    }
+
+    fn from_biguints(r: BigUint, s: BigUint) -> Result<Self, SignatureError> {
+        let modulus: BigUint = C::ScalarField::MODULUS.into();
+        if r >= modulus || s >= modulus {
+            return Err(SignatureError::OutOfRange);
+        }
+        Self::from_scalars(C::ScalarField::from(r), C::ScalarField::from(s))
+            .ok_or(SignatureError::Zero)
+    }
+
+    /// s <= n/2
+    pub fn is_low_s(&self) -> bool {
+        self.s.into_bigint() <= C::ScalarField::MODULUS_MINUS_ONE_DIV_TWO
+    }
+
+    /// (r, s)와 (r, -s)는 둘 다 유효하므로, 서명을 하나로 고정하기 위해
+    /// s > n/2이면 n - s로 바꾼다 (BIP 62, BIP 146)
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() { *self } else { Signature { r: self.r, s: -self.s } }
+    }
+}
+
+impl<C: Curve> Clone for Signature<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Curve> Copy for Signature<C> {}
+
+impl<C: Curve> PartialEq for Signature<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.s == other.s
+    }
+}
+
+impl<C: Curve> Eq for Signature<C> {}
+
+impl<C: Curve> fmt::Debug for Signature<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signature").field("r", &self.r).field("s", &self.s).finish()
+    }
+}
+
+/// SEC 1 4.1.6의 recovery id. bit 0은 R = kG의 y 홀짝, bit 1은 R의 x가
+/// n 이상이어서 r = x - n이 된 경우
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    pub fn new(is_y_odd: bool, is_x_reduced: bool) -> Self {
+        RecoveryId(is_y_odd as u8 | (is_x_reduced as u8) << 1)
+    }
+
+    /// 0..4만 유효
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        (byte < 4).then_some(RecoveryId(byte))
+    }
+
+    pub fn to_byte(self) -> u8 {
+        self.0
+    }
+
+    pub fn is_y_odd(self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    pub fn is_x_reduced(self) -> bool {
+        self.0 & 2 == 2
+    }
+
+    fn of_point<C: Curve>(point: &CurvePoint<C>) -> Option<Self> {
+        let x: BigUint = point.inner.x()?.into();
+        let y = point.inner.y()?;
+        Some(Self::new(y.into_bigint().is_odd(), x >= C::ScalarField::MODULUS.into()))
+    }
+
+    /// s를 -s로 바꾸면 R도 -R이 된다
+    fn negated(self) -> Self {
+        RecoveryId(self.0 ^ 1)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RecoveryError {
+    #[error("r + n is not below the field modulus")]
+    XOutOfRange,
+    #[error("no curve point has x = r")]
+    NotOnCurve,
+    #[error("recovered an invalid public key: {0}")]
+    Key(#[from] KeyError),
+}
+
+/// `Ecdsa::verify_batch`의 (공개키, 메시지, 서명, recovery id)
+pub type BatchItem<'a, C> = (&'a PublicKey<C>, &'a [u8], &'a Signature<C>, RecoveryId);
+
+/// `Ecdsa::verifying_batch`의 (공개키, z, 서명, recovery id)
+pub type PrehashedBatchItem<'a, C> =
+    (&'a CurvePoint<C>, <C as Curve>::ScalarField, &'a Signature<C>, RecoveryId);
+
+/// 서명용 개인키 d와 공개키 dG
+pub struct SigningKey<C: Curve> {
+    secret_key: SecretKey<C>,
+    public_key: PublicKey<C>,
+}
+
+impl<C: Curve> SigningKey<C> {
+    pub fn new(secret_key: SecretKey<C>) -> Self {
+        let public_key = secret_key.public_key();
+        SigningKey { secret_key, public_key }
+    }
+
+    pub fn random() -> Self {
+        Self::new(SecretKey::random())
+    }
+
+    pub fn secret_key(&self) -> &SecretKey<C> {
+        &self.secret_key
+    }
+
+    pub fn public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+}
 
 pub struct Ecdsa<C: Curve> {
     _curve: std::marker::PhantomData<C>,
 }
 
 impl<C: Curve> Ecdsa<C> {
+    /// z = bits2int(H(m)) mod q. 해시가 q보다 길면 왼쪽 qlen 비트만 쓴다
+    pub fn hash_message<D: Digest>(message: &[u8]) -> C::ScalarField {
+        hash_to_scalar::<C>(&D::digest(message))
+    }
+
+    /// 메시지를 D로 해시해 서명한다. nonce는 같은 D를 쓰는 RFC 6979
+    pub fn sign<D: Digest + BlockSizeUser>(
+        key: &SigningKey<C>,
+        message: &[u8],
+    ) -> Result<Signature<C>, SigningError> {
+        Self::sign_prehash::<D>(key, &D::digest(message))
+    }
+
+    /// 이미 해시한 값 H(m)에 서명. D는 RFC 6979의 HMAC에만 쓰인다.
+    /// `fault-countermeasures`가 켜져 있으면 kG를 `mul_scalar_checked`로
+    /// 계산하고, 내보내기 전에 공개키로 다시 검증한다. 결정적 nonce로 같은
+    /// 메시지에 서명하는 도중 fault가 한 번만 들어가도 올바른 서명과 틀린
+    /// 서명의 쌍에서 개인키가 드러나기 때문
+    pub fn sign_prehash<D: Digest + BlockSizeUser>(
+        key: &SigningKey<C>,
+        prehash: &[u8],
+    ) -> Result<Signature<C>, SigningError> {
+        let private_key = key.secret_key.expose_scalar();
+        let z = hash_to_scalar::<C>(prehash);
+        for k in Nonces::<C, D>::new(private_key, &z, &[]) {
+            #[cfg(feature = "fault-countermeasures")]
+            let signature = Self::sign_checked(
+                &k,
+                |p| Self::signature_from_point(private_key, z, &k, p),
+                |signature| Self::verifying_message(key.public_key.as_point(), z, signature),
+            )?;
+            #[cfg(not(feature = "fault-countermeasures"))]
+            let signature = Self::signing_message_with_nonce(private_key, z, &k);
+            if let Some(signature) = signature {
+                return Ok(signature);
+            }
+        }
+        unreachable!("the nonce candidates never run out")
+    }
+
+    pub fn verify<D: Digest>(
+        public_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> bool {
+        Self::verify_prehash(public_key, &D::digest(message), signature)
+    }
+
+    pub fn verify_prehash(
+        public_key: &PublicKey<C>,
+        prehash: &[u8],
+        signature: &Signature<C>,
+    ) -> bool {
+        Self::verifying_message(public_key.as_point(), hash_to_scalar::<C>(prehash), signature)
+    }
+
+    /// 공개키 복구에 쓰는 recovery id와 함께 서명. Ethereum처럼 low-S를
+    /// 요구하는 곳에 맞춰 s를 정규화하고, 그만큼 id의 홀짝을 뒤집는다
+    pub fn sign_recoverable<D: Digest + BlockSizeUser>(
+        key: &SigningKey<C>,
+        message: &[u8],
+    ) -> Result<(Signature<C>, RecoveryId), SigningError> {
+        Self::sign_prehash_recoverable::<D>(key, &D::digest(message))
+    }
+
+    /// `sign_prehash`와 같은 검사를 거친다. 다시 검증할 때 R의 홀짝까지 본다
+    pub fn sign_prehash_recoverable<D: Digest + BlockSizeUser>(
+        key: &SigningKey<C>,
+        prehash: &[u8],
+    ) -> Result<(Signature<C>, RecoveryId), SigningError> {
+        let private_key = key.secret_key.expose_scalar();
+        let z = hash_to_scalar::<C>(prehash);
+        for k in Nonces::<C, D>::new(private_key, &z, &[]) {
+            #[cfg(feature = "fault-countermeasures")]
+            let signature = Self::sign_checked(
+                &k,
+                |p| Self::recoverable_from_point(private_key, z, &k, p),
+                |(signature, recovery_id)| {
+                    Self::verifying_message_recoverable(key.public_key.as_point(), z, signature, *recovery_id)
+                },
+            )?;
+            #[cfg(not(feature = "fault-countermeasures"))]
+            let signature = Self::signing_message_recoverable_with_nonce(private_key, z, &k);
+            if let Some(signature) = signature {
+                return Ok(signature);
+            }
+        }
+        unreachable!("the nonce candidates never run out")
+    }
+
+    /// `signing_message_with_nonce`의 recoverable 버전 (low-S로 정규화)
+    pub fn signing_message_recoverable_with_nonce(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        k: &C::ScalarField,
+    ) -> Option<(Signature<C>, RecoveryId)> {
+        let p = C::generator().mul_scalar(k);
+        Self::recoverable_from_point(private_key, z, k, &p)
+    }
+
+    /// (r, s)와 recovery id from p = kG
+    fn recoverable_from_point(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        k: &C::ScalarField,
+        p: &CurvePoint<C>,
+    ) -> Option<(Signature<C>, RecoveryId)> {
+        let signature = Self::signature_from_point(private_key, z, k, p)?;
+        let recovery_id = RecoveryId::of_point(p)?;
+        Some(if signature.is_low_s() {
+            (signature, recovery_id)
+        } else {
+            (signature.normalize_s(), recovery_id.negated())
+        })
+    }
+
+    /// nonce k 하나로 서명한다. kG는 `mul_scalar_checked`로 계산하고, `sign`이
+    /// 만든 서명은 `verify`로 다시 검증한 뒤에만 내보낸다. r 또는 s가 0이면
+    /// Ok(None)
+    #[cfg(feature = "fault-countermeasures")]
+    fn sign_checked<T>(
+        k: &C::ScalarField,
+        sign: impl FnOnce(&CurvePoint<C>) -> Option<T>,
+        verify: impl FnOnce(&T) -> bool,
+    ) -> Result<Option<T>, FaultError> {
+        let p = C::generator().mul_scalar_checked(k)?;
+        let Some(signature) = sign(&p) else {
+            return Ok(None);
+        };
+        if !verify(&signature) {
+            return Err(FaultError::InvalidSignature);
+        }
+        Ok(Some(signature))
+    }
+
+    /// R = (r (+ n), y)를 복원하면 sR = zG + rQ이므로
+    ///
+    /// ```text
+    /// Q = r^(-1) (sR - zG)
+    /// ```
+    pub fn recover_public_key(
+        z: C::ScalarField,
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<PublicKey<C>, RecoveryError> {
+        let (r, s) = signature.to_scalars();
+        let big_r = Self::recover_nonce_point(signature, recovery_id)?;
+        let r_inv = r.inverse().expect("r is nonzero");
+        let q = big_r.mul_scalar(&(r_inv * s)).subtract(&C::generator().mul_scalar(&(r_inv * z)));
+        Ok(PublicKey::from_point(q)?)
+    }
+
+    /// recovery id로 서명의 R = kG를 복원한다
+    pub fn recover_nonce_point(
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> Result<CurvePoint<C>, RecoveryError> {
+        let mut x: BigUint = signature.r.into();
+        if recovery_id.is_x_reduced() {
+            let order: BigUint = C::ScalarField::MODULUS.into();
+            x += order;
+        }
+        if x >= C::BaseField::MODULUS.into() {
+            return Err(RecoveryError::XOutOfRange);
+        }
+        CurvePoint::<C>::lift_x(C::BaseField::from(x), recovery_id.is_y_odd())
+            .ok_or(RecoveryError::NotOnCurve)
+    }
+
+    /// 메시지를 D로 해시해 `verifying_batch`로 검증
+    pub fn verify_batch<D: Digest>(
+        items: &[BatchItem<'_, C>],
+    ) -> Result<(), BatchError> {
+        let hashed: Vec<_> = items
+            .iter()
+            .map(|(public_key, message, signature, recovery_id)| {
+                (public_key.as_point(), Self::hash_message::<D>(message), *signature, *recovery_id)
+            })
+            .collect();
+        Self::verifying_batch(&hashed)
+    }
+
+    /// 배치 검증. 검증식은 x(R) mod n = r만 보므로 R을 알아야 서명끼리 묶을
+    /// 수 있다. recovery id로 R_i를 복원하면 s_i R_i = z_i G + r_i Q_i이므로
+    ///
+    /// ```text
+    /// sum (a_i s_i) R_i - (sum a_i z_i) G - sum (a_i r_i) Q_i = O
+    /// ```
+    ///
+    /// 를 한 번의 multi-scalar multiplication으로 확인한다. 실패하면
+    /// `verifying_message_recoverable`로 하나씩 검증해 틀린 서명을 찾는다
+    pub fn verifying_batch(
+        items: &[PrehashedBatchItem<'_, C>],
+    ) -> Result<(), BatchError> {
+        if !Self::batch_equation_holds(items) {
+            return batch::fallback(items.len(), |i| {
+                let (public_key, z, signature, recovery_id) = items[i];
+                Self::verifying_message_recoverable(public_key, z, signature, recovery_id)
+            });
+        }
+        Ok(())
+    }
+
+    fn batch_equation_holds(
+        items: &[PrehashedBatchItem<'_, C>],
+    ) -> bool {
+        let coefficients = batch::coefficients::<C::ScalarField>(items.len());
+        let mut points = vec![C::generator()];
+        let mut scalars = vec![C::ScalarField::zero()];
+
+        for ((public_key, z, signature, recovery_id), a) in items.iter().zip(coefficients) {
+            let Ok(big_r) = Self::recover_nonce_point(signature, *recovery_id) else {
+                return false;
+            };
+            scalars[0] -= a * z;
+            points.extend([big_r, (*public_key).clone()]);
+            scalars.extend([a * signature.s, -(a * signature.r)]);
+        }
+        multi_scalar_mul(&points, &scalars).inner.is_infinity()
+    }
+
+    /// `verifying_message`에 더해 recovery id로 복원한 R이 sR = zG + rQ를
+    /// 만족하는지, 즉 R의 홀짝까지 맞는지 본다
+    pub fn verifying_message_recoverable(
+        public_key: &CurvePoint<C>,
+        z: C::ScalarField,
+        signature: &Signature<C>,
+        recovery_id: RecoveryId,
+    ) -> bool {
+        let Ok(big_r) = Self::recover_nonce_point(signature, recovery_id) else {
+            return false;
+        };
+        let expected = C::generator().mul_scalar(&z).add(&public_key.mul_scalar(&signature.r));
+        big_r.mul_scalar(&signature.s) == expected
+    }
+
+    /// `verify`에 더해 high-S 서명을 거부한다. Bitcoin은 서명의 가변성을
+    /// 막으려고 secp256k1에서 low-S만 받는다
+    pub fn verify_strict<D: Digest>(
+        public_key: &PublicKey<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> bool {
+        signature.is_low_s() && Self::verify::<D>(public_key, message, signature)
+    }
+
+    pub fn verify_prehash_strict(
+        public_key: &PublicKey<C>,
+        prehash: &[u8],
+        signature: &Signature<C>,
+    ) -> bool {
+        signature.is_low_s() && Self::verify_prehash(public_key, prehash, signature)
+    }
+
     pub fn signing_message(
         private_key: &C::ScalarField,
         z: C::ScalarField,
-    ) -> (C::ScalarField, C::ScalarField) {
+    ) -> Signature<C> {
         let mut rng = thread_rng();
-        let generator = C::generator();
 
         loop {
             let k = C::ScalarField::rand(&mut rng);
-            let p = generator.mul_scalar(&k);
-            
-            let r = match p.inner.x() {
-                Some(x_base) => {
-                    let bytes = x_base.into_bigint().to_bytes_be();
-                    let r_candidate = C::ScalarField::from_be_bytes_mod_order(&bytes);
-                    if r_candidate.is_zero() {
-                        continue;
-                    }
-                    r_candidate
-                }
-                None => continue,
-            };
+            if let Some(signature) = Self::signing_message_with_nonce(private_key, z, &k) {
+                return signature;
+            }
+        }
+    }
+
+    /// `mode`로 고른 nonce로 서명. z는 bits2int(H(m)) mod q여야 RFC 6979의
+    /// 서명과 같아진다 (`rfc6979::hash_to_scalar`)
+    pub fn signing_message_with_mode(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        mode: NonceMode,
+    ) -> Signature<C> {
+        match mode {
+            NonceMode::Random => Self::signing_message(private_key, z),
+            NonceMode::Deterministic => Self::signing_message_rfc6979::<Sha256>(private_key, z, &[]),
+            NonceMode::DeterministicWithEntropy(extra_entropy) => {
+                Self::signing_message_rfc6979::<Sha256>(private_key, z, extra_entropy)
+            }
+        }
+    }
 
-            if let Some(k_inv) = k.inverse() {
-                let s = k_inv * (z + r * private_key);
+    /// RFC 6979 nonce로 서명. D는 HMAC_DRBG의 해시로, 보통 메시지 해시와 같다
+    pub fn signing_message_rfc6979<D: Digest + BlockSizeUser>(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        extra_entropy: &[u8],
+    ) -> Signature<C> {
+        Nonces::<C, D>::new(private_key, &z, extra_entropy)
+            .find_map(|k| Self::signing_message_with_nonce(private_key, z, &k))
+            .expect("the nonce candidates never run out")
+    }
 
-                if !s.is_zero() {
-                    return (r, s);
-                }
+    /// 주어진 nonce k로 서명. k는 서명마다 새로 뽑은 비밀이어야 하며, 같은 k를
+    /// 두 번 쓰면 개인키가 드러난다 (`attacks::ecdsa_nonce_reuse` 참고).
+    /// r 또는 s가 0이 되면 None
+    pub fn signing_message_with_nonce(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        k: &C::ScalarField,
+    ) -> Option<Signature<C>> {
+        let p = C::generator().mul_scalar(k);
+        Self::signature_from_point(private_key, z, k, &p)
+    }
+
+    /// 서명 전에 kG를 `mul_scalar_checked`로 계산하고, 내보내기 전에 저장된
+    /// 공개키로 다시 검증한다. 개인키나 중간 계산에 fault가 들어가 잘못된
+    /// 서명이 나가면 그 서명으로부터 개인키가 드러날 수 있기 때문
+    #[cfg(feature = "fault-countermeasures")]
+    pub fn signing_message_checked(
+        private_key: &C::ScalarField,
+        public_key: &CurvePoint<C>,
+        z: C::ScalarField,
+    ) -> Result<Signature<C>, FaultError> {
+        let mut rng = thread_rng();
+
+        loop {
+            let k = C::ScalarField::rand(&mut rng);
+            let signature = Self::sign_checked(
+                &k,
+                |p| Self::signature_from_point(private_key, z, &k, p),
+                |signature| Self::verifying_message(public_key, z, signature),
+            )?;
+            if let Some(signature) = signature {
+                return Ok(signature);
             }
         }
     }
 
+    /// (r, s) from p = kG
+    fn signature_from_point(
+        private_key: &C::ScalarField,
+        z: C::ScalarField,
+        k: &C::ScalarField,
+        p: &CurvePoint<C>,
+    ) -> Option<Signature<C>> {
+        let bytes = p.inner.x()?.into_bigint().to_bytes_be();
+        let r = C::ScalarField::from_be_bytes_mod_order(&bytes);
+        if r.is_zero() {
+            return None;
+        }
+
+        let k_inv = k.inverse()?;
+        let s = k_inv * (z + r * private_key);
+        Signature::from_scalars(r, s)
+    }
+
     pub fn verifying_message(
         other_public_key: &CurvePoint<C>,
         z: C::ScalarField,
-        signature: &(C::ScalarField, C::ScalarField), // (r, s)
+        signature: &Signature<C>,
     ) -> bool {
-        let (r, s) = *signature;
+        let (r, s) = signature.to_scalars();
         let g = C::generator();
 
         if let Some(s_inv) = s.inverse() {
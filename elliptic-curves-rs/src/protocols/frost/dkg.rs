@@ -0,0 +1,276 @@
+//! 딜러 없는 FROST 키 생성 (Komlo-Goldberg의 Pedersen DKG)
+//!
+//! 참가자 1..=n이 각자 딜러가 되어 자기 다항식 f_i를 나눠 주고, 받은 조각을
+//! 모두 더한다. 공유된 비밀은 s = sum f_i(0)이고 아무도 이 값을 모른다.
+//!
+//! ```text
+//! 1라운드: 계수 commitment (phi_i0, ..., phi_i(t-1))와 a_i0에 대한 지식 증명
+//!          R_i = k G,  c_i = HDKG(i || phi_i0 || R_i),  mu_i = k + a_i0 c_i
+//!          를 모두에게 보낸다. 받는 쪽은 R_i = mu_i G - c_i phi_i0을 확인한다
+//! 2라운드: 참가자 j에게만 f_i(j)를 보낸다. 받는 쪽은 f_i(j) G = sum_k phi_ik j^k를
+//!          확인한다
+//! 결과:    s_j = sum_i f_i(j),   PK = sum_i phi_i0
+//! ```
+//!
+//! 지식 증명이 없으면 마지막 참가자가 다른 사람들의 phi_i0을 보고 자기 것을
+//! 골라 PK를 마음대로 정할 수 있다 (rogue key). 틀린 패키지를 받으면 보낸
+//! 참가자의 번호와 함께 실패한다.
+
+use crate::core::point::CurvePoint;
+use crate::protocols::frost::keys::{VssCommitment, check_threshold, random_coefficients};
+use crate::protocols::frost::{
+    Ciphersuite, FrostError, Identifier, KeyPackage, PublicKeyPackage, Reader, evaluate_polynomial, hdkg,
+    serialize_element, serialize_scalar,
+};
+
+use ark_ff::Zero;
+use ark_std::{UniformRand, rand::thread_rng};
+use zeroize::Zeroize;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 1라운드에 모두에게 보내는 commitment와 지식 증명 (R_i, mu_i)
+pub struct Round1Package<C: Ciphersuite> {
+    commitment: VssCommitment<C>,
+    proof_r: CurvePoint<C>,
+    proof_mu: C::ScalarField,
+}
+
+impl<C: Ciphersuite> Round1Package<C> {
+    pub fn commitment(&self) -> &VssCommitment<C> {
+        &self.commitment
+    }
+
+    /// commitment || R_i || mu_i
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [
+            self.commitment.to_bytes(),
+            serialize_element(&self.proof_r).expect("k is nonzero"),
+            serialize_scalar::<C>(&self.proof_mu),
+        ]
+        .concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let mut reader = Reader::new(bytes);
+        let commitment = VssCommitment::read(&mut reader)?;
+        let proof_r = reader.element()?;
+        let proof_mu = reader.scalar::<C>()?;
+        reader.finish()?;
+        Ok(Round1Package { commitment, proof_r, proof_mu })
+    }
+}
+
+impl<C: Ciphersuite> Clone for Round1Package<C> {
+    fn clone(&self) -> Self {
+        Round1Package { commitment: self.commitment.clone(), proof_r: self.proof_r.clone(), proof_mu: self.proof_mu }
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for Round1Package<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Round1Package")
+            .field("commitment", &self.commitment)
+            .field("proof_r", &self.proof_r)
+            .field("proof_mu", &self.proof_mu)
+            .finish()
+    }
+}
+
+/// 2라운드에 한 참가자에게만 보내는 f_i(j)
+pub struct Round2Package<C: Ciphersuite> {
+    share: C::ScalarField,
+}
+
+impl<C: Ciphersuite> Round2Package<C> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_scalar::<C>(&self.share)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let mut reader = Reader::new(bytes);
+        let share = reader.scalar::<C>()?;
+        reader.finish()?;
+        Ok(Round2Package { share })
+    }
+}
+
+impl<C: Ciphersuite> Drop for Round2Package<C> {
+    fn drop(&mut self) {
+        self.share.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for Round2Package<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Round2Package(<redacted>)")
+    }
+}
+
+/// c_i = HDKG(i || phi_i0 || R_i)
+fn proof_challenge<C: Ciphersuite>(
+    id: Identifier,
+    constant: &CurvePoint<C>,
+    r: &CurvePoint<C>,
+) -> Result<C::ScalarField, FrostError> {
+    Ok(hdkg::<C>(&[&id.to_bytes::<C>(), &serialize_element(constant)?, &serialize_element(r)?]))
+}
+
+fn verify_proof<C: Ciphersuite>(id: Identifier, package: &Round1Package<C>) -> Result<(), FrostError> {
+    let constant = package.commitment.verifying_key();
+    let c = proof_challenge(id, constant, &package.proof_r).map_err(|_| FrostError::InvalidProofOfKnowledge(id))?;
+    if C::generator().mul_scalar(&package.proof_mu).subtract(&constant.mul_scalar(&c)) != package.proof_r {
+        return Err(FrostError::InvalidProofOfKnowledge(id));
+    }
+    Ok(())
+}
+
+/// 보낸 사람이 정확히 자신을 뺀 1..=n이어야 한다
+fn check_senders<T>(own: Identifier, max_signers: u16, packages: &BTreeMap<Identifier, T>) -> Result<(), FrostError> {
+    if let Some(&id) = packages.keys().find(|&&id| id == own || id.value() > max_signers) {
+        return Err(FrostError::UnknownParticipant(id));
+    }
+    let missing = (1..=max_signers).filter_map(Identifier::new).find(|id| *id != own && !packages.contains_key(id));
+    match missing {
+        Some(id) => Err(FrostError::MissingParticipant(id)),
+        None => Ok(()),
+    }
+}
+
+/// 1라운드 패키지를 보내고 다른 참가자들의 것을 기다리는 참가자
+pub struct FirstRound<C: Ciphersuite> {
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+    coefficients: Vec<C::ScalarField>,
+    package: Round1Package<C>,
+}
+
+/// 다음 상태와 참가자마다 보낼 2라운드 패키지
+pub type FirstRoundOutput<C> = (SecondRound<C>, BTreeMap<Identifier, Round2Package<C>>);
+
+impl<C: Ciphersuite> FirstRound<C> {
+    pub fn new(identifier: Identifier, max_signers: u16, min_signers: u16) -> Result<Self, FrostError> {
+        check_threshold(max_signers, min_signers)?;
+        if identifier.value() > max_signers {
+            return Err(FrostError::UnknownParticipant(identifier));
+        }
+        let mut rng = thread_rng();
+        let coefficients = random_coefficients::<C>(C::ScalarField::rand(&mut rng), min_signers);
+        let commitment = VssCommitment::commit(&coefficients);
+
+        let mut k = C::ScalarField::rand(&mut rng);
+        while k.is_zero() {
+            k = C::ScalarField::rand(&mut rng);
+        }
+        let proof_r = C::generator().mul_scalar(&k);
+        let c = proof_challenge(identifier, commitment.verifying_key(), &proof_r)?;
+        let proof_mu = k + coefficients[0] * c;
+        k.zeroize();
+
+        let package = Round1Package { commitment, proof_r, proof_mu };
+        Ok(FirstRound { identifier, max_signers, min_signers, coefficients, package })
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub fn package(&self) -> &Round1Package<C> {
+        &self.package
+    }
+
+    /// 다른 참가자 모두의 1라운드 패키지를 검증하고 f_i(j)를 나눠 준다
+    pub fn receive(self, packages: &BTreeMap<Identifier, Round1Package<C>>) -> Result<FirstRoundOutput<C>, FrostError> {
+        check_senders(self.identifier, self.max_signers, packages)?;
+        for (&id, package) in packages {
+            if package.commitment.min_signers() != self.min_signers as usize {
+                return Err(FrostError::IncorrectCommitmentLength(id));
+            }
+            verify_proof(id, package)?;
+        }
+
+        let shares = packages
+            .keys()
+            .map(|&id| (id, Round2Package { share: evaluate_polynomial::<C>(&self.coefficients, id) }))
+            .collect();
+        let mut commitments: BTreeMap<Identifier, VssCommitment<C>> =
+            packages.iter().map(|(&id, package)| (id, package.commitment.clone())).collect();
+        commitments.insert(self.identifier, self.package.commitment.clone());
+
+        let next = SecondRound {
+            identifier: self.identifier,
+            max_signers: self.max_signers,
+            min_signers: self.min_signers,
+            own_share: evaluate_polynomial::<C>(&self.coefficients, self.identifier),
+            commitments,
+        };
+        Ok((next, shares))
+    }
+}
+
+impl<C: Ciphersuite> Drop for FirstRound<C> {
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for FirstRound<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FirstRound").field("identifier", &self.identifier).field("package", &self.package).finish()
+    }
+}
+
+/// 1라운드 commitment를 모두 받고 2라운드 조각을 기다리는 참가자
+pub struct SecondRound<C: Ciphersuite> {
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+    own_share: C::ScalarField,
+    commitments: BTreeMap<Identifier, VssCommitment<C>>,
+}
+
+impl<C: Ciphersuite> SecondRound<C> {
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// 받은 f_j(i)를 commitment로 확인하고 더해 키 조각을 만든다
+    pub fn finish(
+        self,
+        packages: &BTreeMap<Identifier, Round2Package<C>>,
+    ) -> Result<(KeyPackage<C>, PublicKeyPackage<C>), FrostError> {
+        check_senders(self.identifier, self.max_signers, packages)?;
+        let g = C::generator();
+        let mut signing_share = self.own_share;
+        for (&id, package) in packages {
+            if g.mul_scalar(&package.share) != self.commitments[&id].evaluate(self.identifier) {
+                return Err(FrostError::InvalidSecretShare(id));
+            }
+            signing_share += package.share;
+        }
+
+        let group_commitment = VssCommitment::sum(self.commitments.values());
+        let public_key_package = PublicKeyPackage::from_commitment(&group_commitment, self.commitments.keys().copied());
+        let key_package =
+            KeyPackage::new(self.identifier, signing_share, group_commitment.verifying_key().clone(), self.min_signers);
+        signing_share.zeroize();
+        Ok((key_package, public_key_package))
+    }
+}
+
+impl<C: Ciphersuite> Drop for SecondRound<C> {
+    fn drop(&mut self) {
+        self.own_share.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for SecondRound<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecondRound")
+            .field("identifier", &self.identifier)
+            .field("commitments", &self.commitments)
+            .finish()
+    }
+}
@@ -0,0 +1,342 @@
+//! FROST 키 조각과 신뢰하는 딜러 (RFC 9591 부록 C)
+//!
+//! 딜러는 f(0) = s인 t - 1차 다항식 f를 뽑아 참가자 i에게 s_i = f(i)를 주고,
+//! 계수의 commitment phi_k = a_k G를 모두에게 공개한다 (Feldman VSS). 참가자는
+//!
+//! ```text
+//! s_i G = sum_k phi_k i^k
+//! ```
+//!
+//! 로 자기 조각이 다항식 위에 있는지 확인한다. 같은 commitment에서 누구나
+//! 공개키 PK = phi_0과 참가자마다의 공개 조각 PK_i = s_i G를 계산할 수 있다.
+
+use crate::core::point::CurvePoint;
+use crate::protocols::frost::{
+    Ciphersuite, FrostError, Identifier, Reader, evaluate_polynomial, serialize_element, serialize_scalar,
+};
+use crate::protocols::keys::SecretKey;
+use crate::protocols::secret_sharing::evaluate_commitment;
+
+use ark_std::{UniformRand, rand::thread_rng};
+use zeroize::Zeroize;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// 2 <= t <= n
+pub(crate) fn check_threshold(max_signers: u16, min_signers: u16) -> Result<(), FrostError> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(FrostError::InvalidThreshold { min: min_signers, max: max_signers });
+    }
+    Ok(())
+}
+
+/// 상수항이 `secret`이고 나머지 t - 1개가 난수인 계수
+pub(crate) fn random_coefficients<C: Ciphersuite>(secret: C::ScalarField, min_signers: u16) -> Vec<C::ScalarField> {
+    let mut rng = thread_rng();
+    std::iter::once(secret).chain((1..min_signers).map(|_| C::ScalarField::rand(&mut rng))).collect()
+}
+
+/// 다항식 계수의 commitment (phi_0, ..., phi_{t-1})
+pub struct VssCommitment<C: Ciphersuite> {
+    coefficients: Vec<CurvePoint<C>>,
+}
+
+impl<C: Ciphersuite> VssCommitment<C> {
+    pub(crate) fn commit(coefficients: &[C::ScalarField]) -> Self {
+        let g = C::generator();
+        VssCommitment { coefficients: coefficients.iter().map(|a| g.mul_scalar(a)).collect() }
+    }
+
+    pub fn coefficients(&self) -> &[CurvePoint<C>] {
+        &self.coefficients
+    }
+
+    /// 임계값 t
+    pub fn min_signers(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// phi_0, 상수항의 commitment
+    pub fn verifying_key(&self) -> &CurvePoint<C> {
+        &self.coefficients[0]
+    }
+
+    /// sum_k phi_k i^k = f(i) G
+    pub fn evaluate(&self, id: Identifier) -> CurvePoint<C> {
+        evaluate_commitment(&self.coefficients, id.to_scalar())
+    }
+
+    /// 여러 다항식의 합에 대한 commitment. 길이가 같아야 한다
+    pub(crate) fn sum<'a>(commitments: impl IntoIterator<Item = &'a Self>) -> Self
+    where
+        C: 'a,
+    {
+        let mut iter = commitments.into_iter();
+        let first = iter.next().expect("at least one commitment").clone();
+        iter.fold(first, |acc, other| VssCommitment {
+            coefficients: acc.coefficients.iter().zip(&other.coefficients).map(|(a, b)| a.add(b)).collect(),
+        })
+    }
+
+    /// 계수 개수 (2바이트) || phi_0 || ... || phi_{t-1}
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let count = u16::try_from(self.coefficients.len()).expect("t < 2^16");
+        let mut bytes = count.to_be_bytes().to_vec();
+        for phi in &self.coefficients {
+            bytes.extend(serialize_element(phi).expect("coefficients are nonzero"));
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let mut reader = Reader::new(bytes);
+        let commitment = Self::read(&mut reader)?;
+        reader.finish()?;
+        Ok(commitment)
+    }
+
+    pub(crate) fn read(reader: &mut Reader) -> Result<Self, FrostError> {
+        let count = reader.u16()?;
+        if count == 0 {
+            return Err(FrostError::Malformed);
+        }
+        let coefficients = (0..count).map(|_| reader.element()).collect::<Result<_, _>>()?;
+        Ok(VssCommitment { coefficients })
+    }
+}
+
+impl<C: Ciphersuite> Clone for VssCommitment<C> {
+    fn clone(&self) -> Self {
+        VssCommitment { coefficients: self.coefficients.clone() }
+    }
+}
+
+impl<C: Ciphersuite> PartialEq for VssCommitment<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients == other.coefficients
+    }
+}
+
+impl<C: Ciphersuite> Eq for VssCommitment<C> {}
+
+impl<C: Ciphersuite> fmt::Debug for VssCommitment<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VssCommitment").field(&self.coefficients).finish()
+    }
+}
+
+/// 딜러가 참가자 한 명에게 보내는 (i, s_i)와 commitment
+pub struct SecretShare<C: Ciphersuite> {
+    identifier: Identifier,
+    value: C::ScalarField,
+    commitment: VssCommitment<C>,
+}
+
+impl<C: Ciphersuite> SecretShare<C> {
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub fn commitment(&self) -> &VssCommitment<C> {
+        &self.commitment
+    }
+
+    /// s_i G가 commitment의 i에서의 값과 같은지 확인하고 키 조각을 만든다
+    pub fn verify(&self) -> Result<KeyPackage<C>, FrostError> {
+        let verifying_share = C::generator().mul_scalar(&self.value);
+        if verifying_share != self.commitment.evaluate(self.identifier) {
+            return Err(FrostError::InvalidSecretShare(self.identifier));
+        }
+        Ok(KeyPackage {
+            identifier: self.identifier,
+            signing_share: self.value,
+            verifying_share,
+            verifying_key: self.commitment.verifying_key().clone(),
+            min_signers: self.commitment.min_signers() as u16,
+        })
+    }
+
+    /// i || s_i || commitment
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.identifier.to_bytes::<C>(), serialize_scalar::<C>(&self.value), self.commitment.to_bytes()].concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let mut reader = Reader::new(bytes);
+        let identifier = reader.identifier::<C>()?;
+        let value = reader.scalar::<C>()?;
+        let commitment = VssCommitment::read(&mut reader)?;
+        reader.finish()?;
+        Ok(SecretShare { identifier, value, commitment })
+    }
+}
+
+impl<C: Ciphersuite> Drop for SecretShare<C> {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for SecretShare<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretShare")
+            .field("identifier", &self.identifier)
+            .field("value", &"<redacted>")
+            .field("commitment", &self.commitment)
+            .finish()
+    }
+}
+
+/// 참가자 한 명이 서명에 쓰는 것: 비밀 조각 s_i, 공개 조각 PK_i, 공개키 PK, t
+pub struct KeyPackage<C: Ciphersuite> {
+    identifier: Identifier,
+    signing_share: C::ScalarField,
+    verifying_share: CurvePoint<C>,
+    verifying_key: CurvePoint<C>,
+    min_signers: u16,
+}
+
+impl<C: Ciphersuite> KeyPackage<C> {
+    pub(crate) fn new(
+        identifier: Identifier,
+        signing_share: C::ScalarField,
+        verifying_key: CurvePoint<C>,
+        min_signers: u16,
+    ) -> Self {
+        let verifying_share = C::generator().mul_scalar(&signing_share);
+        KeyPackage { identifier, signing_share, verifying_share, verifying_key, min_signers }
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    /// s_i를 그대로 꺼낸다. 복사한 값은 지워지지 않는다
+    pub fn signing_share(&self) -> &C::ScalarField {
+        &self.signing_share
+    }
+
+    pub fn verifying_share(&self) -> &CurvePoint<C> {
+        &self.verifying_share
+    }
+
+    pub fn verifying_key(&self) -> &CurvePoint<C> {
+        &self.verifying_key
+    }
+
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+impl<C: Ciphersuite> Clone for KeyPackage<C> {
+    fn clone(&self) -> Self {
+        KeyPackage {
+            identifier: self.identifier,
+            signing_share: self.signing_share,
+            verifying_share: self.verifying_share.clone(),
+            verifying_key: self.verifying_key.clone(),
+            min_signers: self.min_signers,
+        }
+    }
+}
+
+impl<C: Ciphersuite> Drop for KeyPackage<C> {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for KeyPackage<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPackage")
+            .field("identifier", &self.identifier)
+            .field("signing_share", &"<redacted>")
+            .field("verifying_share", &self.verifying_share)
+            .field("verifying_key", &self.verifying_key)
+            .field("min_signers", &self.min_signers)
+            .finish()
+    }
+}
+
+/// 코디네이터와 검증자가 쓰는 공개 정보: 참가자마다의 PK_i와 PK
+pub struct PublicKeyPackage<C: Ciphersuite> {
+    verifying_shares: BTreeMap<Identifier, CurvePoint<C>>,
+    verifying_key: CurvePoint<C>,
+}
+
+impl<C: Ciphersuite> PublicKeyPackage<C> {
+    pub(crate) fn new(verifying_shares: BTreeMap<Identifier, CurvePoint<C>>, verifying_key: CurvePoint<C>) -> Self {
+        PublicKeyPackage { verifying_shares, verifying_key }
+    }
+
+    /// 참가자 `identifiers`의 PK_i를 commitment에서 계산한다
+    pub fn from_commitment(commitment: &VssCommitment<C>, identifiers: impl IntoIterator<Item = Identifier>) -> Self {
+        PublicKeyPackage {
+            verifying_shares: identifiers.into_iter().map(|id| (id, commitment.evaluate(id))).collect(),
+            verifying_key: commitment.verifying_key().clone(),
+        }
+    }
+
+    pub fn verifying_shares(&self) -> &BTreeMap<Identifier, CurvePoint<C>> {
+        &self.verifying_shares
+    }
+
+    pub fn verifying_key(&self) -> &CurvePoint<C> {
+        &self.verifying_key
+    }
+}
+
+impl<C: Ciphersuite> Clone for PublicKeyPackage<C> {
+    fn clone(&self) -> Self {
+        PublicKeyPackage { verifying_shares: self.verifying_shares.clone(), verifying_key: self.verifying_key.clone() }
+    }
+}
+
+impl<C: Ciphersuite> PartialEq for PublicKeyPackage<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.verifying_shares == other.verifying_shares && self.verifying_key == other.verifying_key
+    }
+}
+
+impl<C: Ciphersuite> Eq for PublicKeyPackage<C> {}
+
+impl<C: Ciphersuite> fmt::Debug for PublicKeyPackage<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PublicKeyPackage")
+            .field("verifying_shares", &self.verifying_shares)
+            .field("verifying_key", &self.verifying_key)
+            .finish()
+    }
+}
+
+/// 참가자마다 보낼 비밀 조각과 공개 정보
+pub type DealerOutput<C> = (BTreeMap<Identifier, SecretShare<C>>, PublicKeyPackage<C>);
+
+/// 비밀키 s를 참가자 1..=n에게 t-of-n으로 나눈다
+pub fn trusted_dealer_keygen<C: Ciphersuite>(
+    secret_key: &SecretKey<C>,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DealerOutput<C>, FrostError> {
+    check_threshold(max_signers, min_signers)?;
+    let mut coefficients = random_coefficients::<C>(*secret_key.expose_scalar(), min_signers);
+    let commitment = VssCommitment::commit(&coefficients);
+
+    let identifiers: Vec<Identifier> = (1..=max_signers).filter_map(Identifier::new).collect();
+    let shares = identifiers
+        .iter()
+        .map(|&identifier| {
+            let value = evaluate_polynomial::<C>(&coefficients, identifier);
+            (identifier, SecretShare { identifier, value, commitment: commitment.clone() })
+        })
+        .collect();
+    coefficients.zeroize();
+    Ok((shares, PublicKeyPackage::from_commitment(&commitment, identifiers)))
+}
+
+/// 새 비밀키를 뽑아 나눈다. 딜러도 s를 바로 잊는다
+pub fn generate_with_dealer<C: Ciphersuite>(max_signers: u16, min_signers: u16) -> Result<DealerOutput<C>, FrostError> {
+    trusted_dealer_keygen(&SecretKey::random(), max_signers, min_signers)
+}
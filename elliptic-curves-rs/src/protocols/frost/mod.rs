@@ -0,0 +1,665 @@
+//! FROST 임계값 Schnorr 서명 (RFC 9591)
+//!
+//! n명이 비밀키 s의 Shamir 조각 s_i = f(i)를 나눠 갖고 (f(0) = s, 차수 t - 1),
+//! 그중 t명 이상이 두 라운드로 공개키 PK = sG에 대한 Schnorr 서명 하나를
+//! 만든다. 누구도 s를 알지 못한다.
+//!
+//! ```text
+//! 1라운드: 서명자마다 nonce (d_i, e_i)를 뽑고 D_i = d_i G, E_i = e_i G를 보낸다
+//! rho_i = H1(PK || H4(m) || H5(commitment list) || i)
+//! R = sum (D_i + rho_i E_i),   c = H2(R || PK || m)
+//!
+//! 2라운드: z_i = d_i + e_i rho_i + lambda_i s_i c,   z = sum z_i
+//! 검증:     zG = R + cPK
+//! ```
+//!
+//! lambda_i는 서명자 집합에서 0에서의 Lagrange 계수다. 서명 조각은
+//! z_i G = D_i + rho_i E_i + lambda_i c PK_i로 하나씩 검증할 수 있어서, 틀린
+//! 조각을 낸 서명자를 찾아낼 수 있다.
+//!
+//! 키는 신뢰하는 딜러가 나눠 주거나 (`keys::trusted_dealer_keygen`) 참가자들이
+//! DKG로 함께 만든다 (`dkg`). 참가자는 라운드마다 상태를 넘기는 값이고,
+//! 주고받는 메시지는 모두 `to_bytes` / `from_bytes`로 직렬화된다. 점은 SEC1
+//! 압축, 스칼라는 big-endian 고정 길이, 해시는 SHA-256이다.
+
+pub mod dkg;
+pub mod keys;
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::protocols::hash_to_field::hash_to_field;
+use crate::protocols::secret_sharing;
+
+pub use keys::{KeyPackage, PublicKeyPackage};
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::rand::{RngCore, thread_rng};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use zeroize::Zeroize;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// FROST ciphersuite를 정하는 곡선. 해시는 SHA-256으로 고정되어 있고, 곡선마다
+/// 도메인 분리에 쓸 contextString만 정한다
+pub trait Ciphersuite: Curve + Sized {
+    const CONTEXT_STRING: &'static str;
+}
+
+/// FROST(secp256k1, SHA-256)
+impl Ciphersuite for Secp256k1Curve {
+    const CONTEXT_STRING: &'static str = "FROST-secp256k1-SHA256-v1";
+}
+
+/// FROST(P-256, SHA-256)
+impl Ciphersuite for P256Curve {
+    const CONTEXT_STRING: &'static str = "FROST-P256-SHA256-v1";
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FrostError {
+    #[error("expected {expected} bytes, got {got}")]
+    Length { expected: usize, got: usize },
+    #[error("the message is truncated or has trailing bytes")]
+    Malformed,
+    #[error("not a valid compressed point other than the identity")]
+    InvalidElement,
+    #[error("scalar is not below the group order")]
+    InvalidScalar,
+    #[error("identifiers are nonzero 16-bit integers")]
+    InvalidIdentifier,
+    #[error("need 2 <= min_signers <= max_signers, got {min} of {max}")]
+    InvalidThreshold { min: u16, max: u16 },
+    #[error("{got} signers, need at least {min}")]
+    TooFewSigners { min: u16, got: usize },
+    #[error("participant {0} is unknown")]
+    UnknownParticipant(Identifier),
+    #[error("participant {0} sent nothing")]
+    MissingParticipant(Identifier),
+    #[error("the signing package does not hold this signer's commitments")]
+    IncorrectCommitments,
+    #[error("the commitment of participant {0} has the wrong number of coefficients")]
+    IncorrectCommitmentLength(Identifier),
+    #[error("the secret share from participant {0} does not match its commitment")]
+    InvalidSecretShare(Identifier),
+    #[error("the proof of knowledge of participant {0} is invalid")]
+    InvalidProofOfKnowledge(Identifier),
+    #[error("the signature share of participant {0} is invalid")]
+    InvalidSignatureShare(Identifier),
+    #[error("the aggregate signature is invalid")]
+    InvalidSignature,
+}
+
+/// 참가자 번호 i, 1 <= i < 2^16. 다항식을 x = i에서 계산한 값이 i의 조각이다
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identifier(u16);
+
+impl Identifier {
+    /// 0은 비밀 자체의 자리이므로 None
+    pub fn new(value: u16) -> Option<Self> {
+        (value != 0).then_some(Identifier(value))
+    }
+
+    pub fn value(self) -> u16 {
+        self.0
+    }
+
+    pub fn to_scalar<F: PrimeField>(self) -> F {
+        F::from(self.0 as u64)
+    }
+
+    /// 스칼라 인코딩
+    pub fn to_bytes<C: Ciphersuite>(self) -> Vec<u8> {
+        serialize_scalar::<C>(&self.to_scalar::<C::ScalarField>())
+    }
+
+    pub fn from_bytes<C: Ciphersuite>(bytes: &[u8]) -> Result<Self, FrostError> {
+        let value: BigUint = deserialize_scalar::<C>(bytes)?.into_bigint().into();
+        u16::try_from(value).ok().and_then(Self::new).ok_or(FrostError::InvalidIdentifier)
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub(crate) fn scalar_len<C: Ciphersuite>() -> usize {
+    (C::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(8)
+}
+
+pub(crate) fn serialize_scalar<C: Ciphersuite>(value: &C::ScalarField) -> Vec<u8> {
+    let bytes = value.into_bigint().to_bytes_be();
+    bytes[bytes.len() - scalar_len::<C>()..].to_vec()
+}
+
+/// 길이가 맞고 n 미만인 big-endian 정수
+pub(crate) fn deserialize_scalar<C: Ciphersuite>(bytes: &[u8]) -> Result<C::ScalarField, FrostError> {
+    let expected = scalar_len::<C>();
+    if bytes.len() != expected {
+        return Err(FrostError::Length { expected, got: bytes.len() });
+    }
+    let value = BigUint::from_bytes_be(bytes);
+    if value >= C::ScalarField::MODULUS.into() {
+        return Err(FrostError::InvalidScalar);
+    }
+    Ok(C::ScalarField::from(value))
+}
+
+/// 항등원은 인코딩할 수 없다
+pub(crate) fn serialize_element<C: Ciphersuite>(point: &CurvePoint<C>) -> Result<Vec<u8>, FrostError> {
+    point.to_compressed().ok_or(FrostError::InvalidElement)
+}
+
+pub(crate) fn deserialize_element<C: Ciphersuite>(bytes: &[u8]) -> Result<CurvePoint<C>, FrostError> {
+    let expected = CurvePoint::<C>::compressed_len();
+    if bytes.len() != expected {
+        return Err(FrostError::Length { expected, got: bytes.len() });
+    }
+    CurvePoint::from_compressed(bytes).ok_or(FrostError::InvalidElement)
+}
+
+/// 여러 값을 이어 붙인 메시지를 앞에서부터 읽는다
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], FrostError> {
+        if self.bytes.len() < len {
+            return Err(FrostError::Malformed);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, FrostError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().expect("2 bytes")))
+    }
+
+    pub(crate) fn scalar<C: Ciphersuite>(&mut self) -> Result<C::ScalarField, FrostError> {
+        deserialize_scalar::<C>(self.take(scalar_len::<C>())?)
+    }
+
+    pub(crate) fn element<C: Ciphersuite>(&mut self) -> Result<CurvePoint<C>, FrostError> {
+        deserialize_element::<C>(self.take(CurvePoint::<C>::compressed_len())?)
+    }
+
+    pub(crate) fn identifier<C: Ciphersuite>(&mut self) -> Result<Identifier, FrostError> {
+        Identifier::from_bytes::<C>(self.take(scalar_len::<C>())?)
+    }
+
+    pub(crate) fn rest(self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub(crate) fn finish(self) -> Result<(), FrostError> {
+        if self.bytes.is_empty() { Ok(()) } else { Err(FrostError::Malformed) }
+    }
+}
+
+/// DST = contextString || label인 hash_to_field (RFC 9591 6.5)
+fn hash_to_scalar<C: Ciphersuite>(label: &str, msg: &[&[u8]]) -> C::ScalarField {
+    let dst = [C::CONTEXT_STRING.as_bytes(), label.as_bytes()].concat();
+    hash_to_field(msg, &dst)
+}
+
+/// 바인딩 계수
+fn h1<C: Ciphersuite>(msg: &[&[u8]]) -> C::ScalarField {
+    hash_to_scalar::<C>("rho", msg)
+}
+
+/// 챌린지
+fn h2<C: Ciphersuite>(msg: &[&[u8]]) -> C::ScalarField {
+    hash_to_scalar::<C>("chal", msg)
+}
+
+/// nonce
+fn h3<C: Ciphersuite>(msg: &[&[u8]]) -> C::ScalarField {
+    hash_to_scalar::<C>("nonce", msg)
+}
+
+fn h4<C: Ciphersuite>(msg: &[u8]) -> [u8; 32] {
+    Sha256::new().chain_update(C::CONTEXT_STRING).chain_update("msg").chain_update(msg).finalize().into()
+}
+
+fn h5<C: Ciphersuite>(msg: &[u8]) -> [u8; 32] {
+    Sha256::new().chain_update(C::CONTEXT_STRING).chain_update("com").chain_update(msg).finalize().into()
+}
+
+/// DKG의 지식 증명 챌린지
+pub(crate) fn hdkg<C: Ciphersuite>(msg: &[&[u8]]) -> C::ScalarField {
+    hash_to_scalar::<C>("dkg", msg)
+}
+
+/// H3(random_bytes || secret). 난수가 나빠도 비밀이 섞여 nonce가 반복되지 않는다
+pub(crate) fn nonce_generate<C: Ciphersuite>(secret: &C::ScalarField) -> C::ScalarField {
+    let mut random_bytes = [0u8; 32];
+    thread_rng().fill_bytes(&mut random_bytes);
+    let nonce = nonce_from_random_bytes::<C>(&random_bytes, secret);
+    random_bytes.zeroize();
+    nonce
+}
+
+/// H3(random_bytes || SerializeScalar(secret)). 부록 E의 벡터처럼 random_bytes를
+/// 고정할 때만 직접 쓴다
+pub(crate) fn nonce_from_random_bytes<C: Ciphersuite>(
+    random_bytes: &[u8; 32],
+    secret: &C::ScalarField,
+) -> C::ScalarField {
+    let mut secret_bytes = serialize_scalar::<C>(secret);
+    let nonce = h3::<C>(&[random_bytes, &secret_bytes]);
+    secret_bytes.zeroize();
+    nonce
+}
+
+/// f(i)
+pub(crate) fn evaluate_polynomial<C: Ciphersuite>(coefficients: &[C::ScalarField], x: Identifier) -> C::ScalarField {
+    secret_sharing::evaluate_polynomial(coefficients, x.to_scalar())
+}
+
+/// 서명자 집합에서 0에서의 Lagrange 계수 lambda_i
+pub(crate) fn lagrange_coefficient<C: Ciphersuite>(signers: &[Identifier], x_i: Identifier) -> C::ScalarField {
+    let indices: Vec<C::ScalarField> = signers.iter().map(|id| id.to_scalar()).collect();
+    secret_sharing::lagrange_coefficient(&indices, x_i.to_scalar(), C::ScalarField::zero())
+}
+
+/// 1라운드의 비밀 nonce (d_i, e_i). 복사할 수 없고 서명에 한 번 쓰이면
+/// 사라진다
+pub struct SigningNonces<C: Ciphersuite> {
+    hiding: C::ScalarField,
+    binding: C::ScalarField,
+    commitments: SigningCommitments<C>,
+}
+
+impl<C: Ciphersuite> SigningNonces<C> {
+    /// nonce마다 서명자의 비밀 조각을 섞는다
+    pub fn new(signing_share: &C::ScalarField) -> Self {
+        Self::from_nonces(nonce_generate::<C>(signing_share), nonce_generate::<C>(signing_share))
+    }
+
+    /// hiding, binding nonce의 random_bytes를 정해 준다. 테스트 벡터용
+    #[cfg(test)]
+    pub(crate) fn from_random_bytes(
+        signing_share: &C::ScalarField,
+        hiding_randomness: &[u8; 32],
+        binding_randomness: &[u8; 32],
+    ) -> Self {
+        Self::from_nonces(
+            nonce_from_random_bytes::<C>(hiding_randomness, signing_share),
+            nonce_from_random_bytes::<C>(binding_randomness, signing_share),
+        )
+    }
+
+    fn from_nonces(hiding: C::ScalarField, binding: C::ScalarField) -> Self {
+        let g = C::generator();
+        let commitments = SigningCommitments { hiding: g.mul_scalar(&hiding), binding: g.mul_scalar(&binding) };
+        SigningNonces { hiding, binding, commitments }
+    }
+
+    pub fn commitments(&self) -> &SigningCommitments<C> {
+        &self.commitments
+    }
+}
+
+impl<C: Ciphersuite> Drop for SigningNonces<C> {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for SigningNonces<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SigningNonces(<redacted>)")
+    }
+}
+
+/// 1라운드에서 보내는 (D_i, E_i)
+pub struct SigningCommitments<C: Ciphersuite> {
+    hiding: CurvePoint<C>,
+    binding: CurvePoint<C>,
+}
+
+impl<C: Ciphersuite> SigningCommitments<C> {
+    pub fn hiding(&self) -> &CurvePoint<C> {
+        &self.hiding
+    }
+
+    pub fn binding(&self) -> &CurvePoint<C> {
+        &self.binding
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = serialize_element(&self.hiding).expect("d_i is nonzero");
+        bytes.extend(serialize_element(&self.binding).expect("e_i is nonzero"));
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let expected = 2 * CurvePoint::<C>::compressed_len();
+        if bytes.len() != expected {
+            return Err(FrostError::Length { expected, got: bytes.len() });
+        }
+        let mut reader = Reader::new(bytes);
+        Ok(SigningCommitments { hiding: reader.element()?, binding: reader.element()? })
+    }
+}
+
+impl<C: Ciphersuite> Clone for SigningCommitments<C> {
+    fn clone(&self) -> Self {
+        SigningCommitments { hiding: self.hiding.clone(), binding: self.binding.clone() }
+    }
+}
+
+impl<C: Ciphersuite> PartialEq for SigningCommitments<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hiding == other.hiding && self.binding == other.binding
+    }
+}
+
+impl<C: Ciphersuite> Eq for SigningCommitments<C> {}
+
+impl<C: Ciphersuite> fmt::Debug for SigningCommitments<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningCommitments").field("hiding", &self.hiding).field("binding", &self.binding).finish()
+    }
+}
+
+/// 코디네이터가 서명자들에게 보내는 commitment 목록과 메시지
+pub struct SigningPackage<C: Ciphersuite> {
+    commitments: BTreeMap<Identifier, SigningCommitments<C>>,
+    message: Vec<u8>,
+}
+
+impl<C: Ciphersuite> SigningPackage<C> {
+    pub fn new(commitments: BTreeMap<Identifier, SigningCommitments<C>>, message: &[u8]) -> Self {
+        SigningPackage { commitments, message: message.to_vec() }
+    }
+
+    pub fn commitments(&self) -> &BTreeMap<Identifier, SigningCommitments<C>> {
+        &self.commitments
+    }
+
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    pub fn signers(&self) -> Vec<Identifier> {
+        self.commitments.keys().copied().collect()
+    }
+
+    /// i || D_i || E_i를 번호 순으로 (encode_group_commitment_list)
+    fn encode_commitment_list(&self) -> Vec<u8> {
+        self.commitments
+            .iter()
+            .flat_map(|(id, commitments)| [id.to_bytes::<C>(), commitments.to_bytes()].concat())
+            .collect()
+    }
+
+    /// 서명자마다 rho_i
+    pub(crate) fn binding_factors(
+        &self,
+        verifying_key: &CurvePoint<C>,
+    ) -> Result<BTreeMap<Identifier, C::ScalarField>, FrostError> {
+        let prefix = [
+            serialize_element(verifying_key)?,
+            h4::<C>(&self.message).to_vec(),
+            h5::<C>(&self.encode_commitment_list()).to_vec(),
+        ]
+        .concat();
+        Ok(self.commitments.keys().map(|&id| (id, h1::<C>(&[&prefix, &id.to_bytes::<C>()]))).collect())
+    }
+
+    /// R = sum (D_i + rho_i E_i)
+    fn group_commitment(&self, binding_factors: &BTreeMap<Identifier, C::ScalarField>) -> CurvePoint<C> {
+        self.commitments.iter().fold(CurvePoint::infinity(), |acc, (id, commitments)| {
+            acc.add(&commitments.hiding).add(&commitments.binding.mul_scalar(&binding_factors[id]))
+        })
+    }
+
+    /// 서명자 수 (2바이트) || commitment 목록 || 메시지
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let count = u16::try_from(self.commitments.len()).expect("at most 2^16 - 1 signers");
+        [count.to_be_bytes().to_vec(), self.encode_commitment_list(), self.message.clone()].concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let mut reader = Reader::new(bytes);
+        let count = reader.u16()?;
+        let mut commitments = BTreeMap::new();
+        for _ in 0..count {
+            let id = reader.identifier::<C>()?;
+            let pair = SigningCommitments { hiding: reader.element()?, binding: reader.element()? };
+            // 번호 순이어야 인코딩이 하나로 정해진다
+            if commitments.last_key_value().is_some_and(|(last, _)| *last >= id) {
+                return Err(FrostError::Malformed);
+            }
+            commitments.insert(id, pair);
+        }
+        Ok(SigningPackage { commitments, message: reader.rest().to_vec() })
+    }
+}
+
+impl<C: Ciphersuite> fmt::Debug for SigningPackage<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SigningPackage")
+            .field("commitments", &self.commitments)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+/// c = H2(R || PK || m)
+fn challenge<C: Ciphersuite>(
+    group_commitment: &CurvePoint<C>,
+    verifying_key: &CurvePoint<C>,
+    message: &[u8],
+) -> Result<C::ScalarField, FrostError> {
+    Ok(h2::<C>(&[&serialize_element(group_commitment)?, &serialize_element(verifying_key)?, message]))
+}
+
+/// 2라운드에서 보내는 z_i
+pub struct SignatureShare<C: Ciphersuite> {
+    share: C::ScalarField,
+}
+
+impl<C: Ciphersuite> SignatureShare<C> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_scalar::<C>(&self.share)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        Ok(SignatureShare { share: deserialize_scalar::<C>(bytes)? })
+    }
+}
+
+impl<C: Ciphersuite> Clone for SignatureShare<C> {
+    fn clone(&self) -> Self {
+        SignatureShare { share: self.share }
+    }
+}
+
+impl<C: Ciphersuite> PartialEq for SignatureShare<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.share == other.share
+    }
+}
+
+impl<C: Ciphersuite> Eq for SignatureShare<C> {}
+
+impl<C: Ciphersuite> fmt::Debug for SignatureShare<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SignatureShare").field(&self.share).finish()
+    }
+}
+
+/// (R, z), 인코딩은 R || z
+pub struct Signature<C: Ciphersuite> {
+    r: CurvePoint<C>,
+    z: C::ScalarField,
+}
+
+impl<C: Ciphersuite> Signature<C> {
+    pub fn r(&self) -> &CurvePoint<C> {
+        &self.r
+    }
+
+    pub fn z(&self) -> &C::ScalarField {
+        &self.z
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [serialize_element(&self.r).expect("R is not the identity"), serialize_scalar::<C>(&self.z)].concat()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrostError> {
+        let expected = CurvePoint::<C>::compressed_len() + scalar_len::<C>();
+        if bytes.len() != expected {
+            return Err(FrostError::Length { expected, got: bytes.len() });
+        }
+        let mut reader = Reader::new(bytes);
+        Ok(Signature { r: reader.element()?, z: reader.scalar::<C>()? })
+    }
+
+    /// zG = R + cPK
+    pub fn verify(&self, verifying_key: &CurvePoint<C>, message: &[u8]) -> bool {
+        let Ok(c) = challenge(&self.r, verifying_key, message) else {
+            return false;
+        };
+        C::generator().mul_scalar(&self.z) == self.r.add(&verifying_key.mul_scalar(&c))
+    }
+}
+
+impl<C: Ciphersuite> Clone for Signature<C> {
+    fn clone(&self) -> Self {
+        Signature { r: self.r.clone(), z: self.z }
+    }
+}
+
+impl<C: Ciphersuite> PartialEq for Signature<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.z == other.z
+    }
+}
+
+impl<C: Ciphersuite> Eq for Signature<C> {}
+
+impl<C: Ciphersuite> fmt::Debug for Signature<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signature").field("r", &self.r).field("z", &self.z).finish()
+    }
+}
+
+/// 1라운드를 마친 서명자. commitment를 보내고 `SigningPackage`를 기다린다
+pub struct FirstRound<C: Ciphersuite> {
+    nonces: SigningNonces<C>,
+}
+
+impl<C: Ciphersuite> FirstRound<C> {
+    pub fn new(key_package: &KeyPackage<C>) -> Self {
+        FirstRound { nonces: SigningNonces::new(key_package.signing_share()) }
+    }
+
+    pub fn commitments(&self) -> &SigningCommitments<C> {
+        self.nonces.commitments()
+    }
+
+    /// nonce를 소비해 서명 조각 z_i를 만든다
+    pub fn sign(
+        self,
+        package: &SigningPackage<C>,
+        key_package: &KeyPackage<C>,
+    ) -> Result<SignatureShare<C>, FrostError> {
+        sign(package, self.nonces, key_package)
+    }
+}
+
+/// z_i = d_i + e_i rho_i + lambda_i s_i c. 패키지에 자신의 commitment가 그대로
+/// 있어야 하고, 서명자가 t명 이상이어야 한다
+pub fn sign<C: Ciphersuite>(
+    package: &SigningPackage<C>,
+    nonces: SigningNonces<C>,
+    key_package: &KeyPackage<C>,
+) -> Result<SignatureShare<C>, FrostError> {
+    let id = key_package.identifier();
+    if package.commitments.get(&id) != Some(nonces.commitments()) {
+        return Err(FrostError::IncorrectCommitments);
+    }
+    let min = key_package.min_signers();
+    if package.commitments.len() < min as usize {
+        return Err(FrostError::TooFewSigners { min, got: package.commitments.len() });
+    }
+
+    let verifying_key = key_package.verifying_key();
+    let binding_factors = package.binding_factors(verifying_key)?;
+    let group_commitment = package.group_commitment(&binding_factors);
+    let lambda = lagrange_coefficient::<C>(&package.signers(), id);
+    let c = challenge(&group_commitment, verifying_key, &package.message)?;
+
+    let share = nonces.hiding + nonces.binding * binding_factors[&id] + lambda * key_package.signing_share() * c;
+    Ok(SignatureShare { share })
+}
+
+/// z_i G = D_i + rho_i E_i + lambda_i c PK_i
+pub fn verify_signature_share<C: Ciphersuite>(
+    id: Identifier,
+    share: &SignatureShare<C>,
+    package: &SigningPackage<C>,
+    public_key_package: &PublicKeyPackage<C>,
+) -> Result<(), FrostError> {
+    let commitments = package.commitments.get(&id).ok_or(FrostError::UnknownParticipant(id))?;
+    let verifying_share = public_key_package.verifying_shares().get(&id).ok_or(FrostError::UnknownParticipant(id))?;
+    let verifying_key = public_key_package.verifying_key();
+
+    let binding_factors = package.binding_factors(verifying_key)?;
+    let group_commitment = package.group_commitment(&binding_factors);
+    let lambda = lagrange_coefficient::<C>(&package.signers(), id);
+    let c = challenge(&group_commitment, verifying_key, &package.message)?;
+
+    let commitment_share = commitments.hiding.add(&commitments.binding.mul_scalar(&binding_factors[&id]));
+    let expected = commitment_share.add(&verifying_share.mul_scalar(&(lambda * c)));
+    if C::generator().mul_scalar(&share.share) == expected {
+        Ok(())
+    } else {
+        Err(FrostError::InvalidSignatureShare(id))
+    }
+}
+
+/// z = sum z_i. 결과 서명이 틀리면 조각을 하나씩 검증해 틀린 서명자를 알려 준다
+pub fn aggregate<C: Ciphersuite>(
+    package: &SigningPackage<C>,
+    shares: &BTreeMap<Identifier, SignatureShare<C>>,
+    public_key_package: &PublicKeyPackage<C>,
+) -> Result<Signature<C>, FrostError> {
+    if let Some(&id) = shares.keys().find(|id| !package.commitments.contains_key(id)) {
+        return Err(FrostError::UnknownParticipant(id));
+    }
+    if let Some(&id) = package.commitments.keys().find(|id| !shares.contains_key(id)) {
+        return Err(FrostError::MissingParticipant(id));
+    }
+
+    let verifying_key = public_key_package.verifying_key();
+    let binding_factors = package.binding_factors(verifying_key)?;
+    let r = package.group_commitment(&binding_factors);
+    let z = shares.values().map(|share| share.share).sum();
+    let signature = Signature { r, z };
+    if signature.verify(verifying_key, &package.message) {
+        return Ok(signature);
+    }
+
+    for (&id, share) in shares {
+        verify_signature_share(id, share, package, public_key_package)?;
+    }
+    Err(FrostError::InvalidSignature)
+}
@@ -0,0 +1,77 @@
+//! 바이트열을 소수체의 원소나 곡선 위의 점으로 보내는 해시 (RFC 9380)
+//!
+//! ```text
+//! expand_message_xmd(msg, DST, len):
+//!     b_0 = H(Z_pad || msg || I2OSP(len, 2) || 0x00 || DST')
+//!     b_1 = H(b_0 || 0x01 || DST'),   b_i = H((b_0 xor b_{i-1}) || i || DST')
+//!
+//! hash_to_field(msg, DST) = OS2IP(expand_message_xmd(msg, DST, L)) mod p,
+//!     L = ceil((ceil(log2 p) + 128) / 8)
+//! ```
+//!
+//! DST' = DST || I2OSP(len(DST), 1). 해시는 SHA-256이고, L바이트를 p로
+//! 줄이므로 편향은 2^-128 이하다.
+//!
+//! 곡선 위의 점은 try-and-increment로 만든다. 이산 로그를 아무도 모르는
+//! 생성원 (Pedersen commitment의 H 등)을 정하는 데 쓴다.
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+/// SHA-256의 출력과 블록 크기
+const B_IN_BYTES: usize = 32;
+const S_IN_BYTES: usize = 64;
+
+/// `msg`는 이어 붙여 하나의 메시지로 본다. `len`은 255 * 32 이하, `dst`는
+/// 255바이트 이하여야 한다
+pub fn expand_message_xmd(msg: &[&[u8]], dst: &[u8], len: usize) -> Vec<u8> {
+    let ell = len.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255 && len <= u16::MAX as usize, "requested output is too long");
+    assert!(dst.len() <= 255, "DST is longer than 255 bytes");
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let mut hasher = Sha256::new().chain_update([0; S_IN_BYTES]);
+    for part in msg {
+        hasher.update(part);
+    }
+    let b_0 = hasher.chain_update((len as u16).to_be_bytes()).chain_update([0]).chain_update(&dst_prime).finalize();
+
+    let mut output = Vec::with_capacity(ell * B_IN_BYTES);
+    let mut b_i = Sha256::new().chain_update(b_0).chain_update([1]).chain_update(&dst_prime).finalize();
+    output.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let mixed: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new().chain_update(mixed).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        output.extend_from_slice(&b_i);
+    }
+    output.truncate(len);
+    output
+}
+
+/// 원소 하나 (count = 1)
+pub fn hash_to_field<F: PrimeField>(msg: &[&[u8]], dst: &[u8]) -> F {
+    let len = (F::MODULUS_BIT_SIZE as usize + 128).div_ceil(8);
+    F::from_be_bytes_mod_order(&expand_message_xmd(msg, dst, len))
+}
+
+/// x = hash_to_field(msg || ctr)가 곡선 위의 x좌표가 되는 첫 ctr에서 y가 짝수인 점.
+/// nP = O가 아니면 (cofactor가 있는 곡선) 다음 ctr로 넘어간다. 걸리는 시간이
+/// 입력에 따라 다르므로 공개된 값에만 쓴다
+pub fn hash_to_curve<C: Curve>(msg: &[&[u8]], dst: &[u8]) -> CurvePoint<C> {
+    let order: BigUint = C::order().into();
+    for counter in 0u32.. {
+        let counter = counter.to_be_bytes();
+        let input: Vec<&[u8]> = msg.iter().copied().chain([counter.as_slice()]).collect();
+        let x = hash_to_field::<C::BaseField>(&input, dst);
+        if let Some(point) = CurvePoint::<C>::lift_x(x, false)
+            && point.mul_biguint(&order).inner.is_infinity()
+        {
+            return point;
+        }
+    }
+    unreachable!("about half of the x coordinates lie on the curve")
+}
@@ -0,0 +1,99 @@
+//! HMAC (RFC 2104)와 RFC 6979가 쓰는 HMAC_DRBG, sha2의 해시 위에 구현
+//!
+//! ```text
+//! HMAC(K, m) = H((K' ^ opad) || H((K' ^ ipad) || m))
+//! ```
+//!
+//! K'은 블록 크기로 0을 채운 키이고, 블록보다 긴 키는 먼저 H(K)로 줄인다.
+
+use sha2::Digest;
+use sha2::digest::core_api::BlockSizeUser;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// HMAC을 나눠서 계산하는 상태. 해시 D는 sha2의 Sha256, Sha512 등
+pub struct Hmac<D: Digest + BlockSizeUser> {
+    inner: D,
+    outer_key: Vec<u8>,
+}
+
+impl<D: Digest + BlockSizeUser> Hmac<D> {
+    pub fn new(key: &[u8]) -> Self {
+        let block_size = D::block_size();
+        let mut padded = if key.len() > block_size { D::digest(key).to_vec() } else { key.to_vec() };
+        padded.resize(block_size, 0);
+
+        let mut inner = D::new();
+        inner.update(padded.iter().map(|byte| byte ^ IPAD).collect::<Vec<u8>>());
+        let outer_key = padded.iter().map(|byte| byte ^ OPAD).collect();
+        Hmac { inner, outer_key }
+    }
+
+    pub fn update(&mut self, data: &[u8]) -> &mut Self {
+        self.inner.update(data);
+        self
+    }
+
+    pub fn finalize(self) -> Vec<u8> {
+        let inner = self.inner.finalize();
+        let mut outer = D::new();
+        outer.update(&self.outer_key);
+        outer.update(inner);
+        outer.finalize().to_vec()
+    }
+}
+
+/// HMAC(key, data_1 || data_2 || ...)
+pub fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut mac = Hmac::<D>::new(key);
+    for part in data {
+        mac.update(part);
+    }
+    mac.finalize()
+}
+
+/// HMAC_DRBG (SP 800-90A 10.1.2)에서 RFC 6979가 쓰는 부분
+pub struct HmacDrbg<D: Digest + BlockSizeUser> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    _hash: std::marker::PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> HmacDrbg<D> {
+    /// K = 0x00..00, V = 0x01..01에서 seed = seed_1 || seed_2 || ...로 갱신
+    pub fn new(seed: &[&[u8]]) -> Self {
+        let len = <D as Digest>::output_size();
+        let mut drbg = HmacDrbg { k: vec![0x00; len], v: vec![0x01; len], _hash: std::marker::PhantomData };
+        drbg.update(seed);
+        drbg
+    }
+
+    /// K = HMAC_K(V || 0x00 || seed), V = HMAC_K(V), seed가 있으면 0x01로
+    /// 한 번 더
+    pub fn update(&mut self, seed: &[&[u8]]) {
+        for separator in [0x00u8, 0x01] {
+            let mut mac = Hmac::<D>::new(&self.k);
+            mac.update(&self.v).update(&[separator]);
+            for part in seed {
+                mac.update(part);
+            }
+            self.k = mac.finalize();
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+            if seed.iter().all(|part| part.is_empty()) {
+                break;
+            }
+        }
+    }
+
+    /// V = HMAC_K(V)를 이어 붙인 `len` 바이트
+    pub fn generate(&mut self, len: usize) -> Vec<u8> {
+        let mut output = Vec::with_capacity(len + self.v.len());
+        while output.len() < len {
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+            output.extend_from_slice(&self.v);
+        }
+        output.truncate(len);
+        output
+    }
+}
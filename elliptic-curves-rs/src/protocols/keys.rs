@@ -0,0 +1,211 @@
+//! 개인키, 공개키, ECDH 공유 비밀의 타입
+//!
+//! 비밀 값(`SecretKey`, `SharedSecret`)은 drop될 때 0으로 지워지고, `Debug`로
+//! 출력되지 않으며, 비교는 상수 시간에 한다. 스칼라나 점과의 변환은
+//! 이름 있는 메서드로만 한다.
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+
+use ark_ff::{BigInteger, PrimeField as ArkPrimeField, Zero};
+use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::BigUint;
+use subtle::{Choice, ConstantTimeEq};
+use thiserror::Error;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use std::fmt;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KeyError {
+    #[error("expected {expected} bytes, got {got}")]
+    Length { expected: usize, got: usize },
+    #[error("secret key is zero")]
+    Zero,
+    #[error("secret key is not below the group order")]
+    OutOfRange,
+    #[error("invalid public key: {0}")]
+    PublicKey(#[from] EcdhError),
+}
+
+/// big-endian, 앞을 0으로 채운 `len` 바이트
+fn to_fixed_bytes<F: ArkPrimeField>(value: &F) -> Zeroizing<Vec<u8>> {
+    let len = (F::MODULUS_BIT_SIZE as usize).div_ceil(8);
+    let bytes = Zeroizing::new(value.into_bigint().to_bytes_be());
+    Zeroizing::new(bytes[bytes.len() - len..].to_vec())
+}
+
+/// 개인키 d, 1 <= d < n
+pub struct SecretKey<C: Curve> {
+    scalar: C::ScalarField,
+}
+
+impl<C: Curve> SecretKey<C> {
+    pub fn random() -> Self {
+        let mut rng = thread_rng();
+        loop {
+            let d = C::ScalarField::rand(&mut rng);
+            if let Some(secret_key) = Self::from_scalar(d) {
+                return secret_key;
+            }
+        }
+    }
+
+    /// d = 0이면 None
+    pub fn from_scalar(scalar: C::ScalarField) -> Option<Self> {
+        (!scalar.is_zero()).then_some(SecretKey { scalar })
+    }
+
+    /// n의 바이트 길이만큼의 big-endian 정수. n 이상이거나 0이면 거부
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, KeyError> {
+        let expected = Self::byte_len();
+        if bytes.len() != expected {
+            return Err(KeyError::Length { expected, got: bytes.len() });
+        }
+        let value = BigUint::from_bytes_be(bytes);
+        if value >= C::ScalarField::MODULUS.into() {
+            return Err(KeyError::OutOfRange);
+        }
+        Self::from_scalar(C::ScalarField::from(value)).ok_or(KeyError::Zero)
+    }
+
+    pub fn byte_len() -> usize {
+        (C::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(8)
+    }
+
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        to_fixed_bytes(&self.scalar)
+    }
+
+    /// 스칼라를 그대로 꺼낸다. 복사한 값은 지워지지 않는다
+    pub fn expose_scalar(&self) -> &C::ScalarField {
+        &self.scalar
+    }
+
+    pub fn public_key(&self) -> PublicKey<C> {
+        PublicKey { point: C::generator().mul_scalar(&self.scalar) }
+    }
+}
+
+impl<C: Curve> Drop for SecretKey<C> {
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C: Curve> ZeroizeOnDrop for SecretKey<C> {}
+
+impl<C: Curve> Clone for SecretKey<C> {
+    fn clone(&self) -> Self {
+        SecretKey { scalar: self.scalar }
+    }
+}
+
+impl<C: Curve> ConstantTimeEq for SecretKey<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_bytes().ct_eq(&other.to_bytes())
+    }
+}
+
+impl<C: Curve> PartialEq for SecretKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: Curve> Eq for SecretKey<C> {}
+
+impl<C: Curve> fmt::Debug for SecretKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+/// 공개키 dG. `Ecdh::validate_public_key`를 통과한 점만 담는다
+pub struct PublicKey<C: Curve> {
+    point: CurvePoint<C>,
+}
+
+impl<C: Curve> PublicKey<C> {
+    pub fn from_point(point: CurvePoint<C>) -> Result<Self, KeyError> {
+        Ecdh::<C>::validate_public_key(&point)?;
+        Ok(PublicKey { point })
+    }
+
+    pub fn as_point(&self) -> &CurvePoint<C> {
+        &self.point
+    }
+
+    pub fn to_point(&self) -> CurvePoint<C> {
+        self.point.clone()
+    }
+}
+
+impl<C: Curve> Clone for PublicKey<C> {
+    fn clone(&self) -> Self {
+        PublicKey { point: self.point.clone() }
+    }
+}
+
+impl<C: Curve> PartialEq for PublicKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<C: Curve> Eq for PublicKey<C> {}
+
+impl<C: Curve> fmt::Debug for PublicKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKey").field(&self.point).finish()
+    }
+}
+
+/// ECDH 결과 dQ의 x좌표
+pub struct SharedSecret<C: Curve> {
+    x: C::BaseField,
+}
+
+impl<C: Curve> SharedSecret<C> {
+    pub(crate) fn new(x: C::BaseField) -> Self {
+        SharedSecret { x }
+    }
+
+    /// p의 바이트 길이만큼의 big-endian x좌표. 키 유도 함수의 입력으로 쓴다
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        to_fixed_bytes(&self.x)
+    }
+
+    pub fn expose_x(&self) -> &C::BaseField {
+        &self.x
+    }
+}
+
+impl<C: Curve> Drop for SharedSecret<C> {
+    fn drop(&mut self) {
+        self.x.zeroize();
+    }
+}
+
+impl<C: Curve> ZeroizeOnDrop for SharedSecret<C> {}
+
+impl<C: Curve> ConstantTimeEq for SharedSecret<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_bytes().ct_eq(&other.to_bytes())
+    }
+}
+
+impl<C: Curve> PartialEq for SharedSecret<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: Curve> Eq for SharedSecret<C> {}
+
+impl<C: Curve> fmt::Debug for SharedSecret<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedSecret(<redacted>)")
+    }
+}
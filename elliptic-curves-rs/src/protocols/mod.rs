@@ -1,5 +1,17 @@
+pub mod batch;
+pub mod der;
+pub mod dkg;
+pub mod dual_ec_drbg;
 pub mod ecdh;
 pub mod ecdsa;
+pub mod frost;
+pub mod hash_to_field;
+pub mod hmac;
+pub mod keys;
+pub mod musig2;
+pub mod rfc6979;
+pub mod schnorr;
+pub mod secret_sharing;
 
 #[cfg(test)]
 pub mod tests;
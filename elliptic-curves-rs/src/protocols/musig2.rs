@@ -0,0 +1,522 @@
+//! MuSig2 다중 서명 (BIP-327)
+//!
+//! u명의 서명자가 공개키를 하나로 모으고, 두 라운드로 그 키에 대한 BIP-340
+//! 서명 하나를 만든다. 결과는 보통의 Schnorr 서명과 구별되지 않는다.
+//!
+//! ```text
+//! L = hash_keys(pk_1 || ... || pk_u),   a_i = hash_coef(L || pk_i)   (두 번째 키는 1)
+//! Q = a_1 P_1 + ... + a_u P_u
+//!
+//! 1라운드: 서명자마다 R_i1 = k_i1 G, R_i2 = k_i2 G를 보낸다
+//! R_1 = sum R_i1,   R_2 = sum R_i2,   b = hash_noncecoef(R_1 || R_2 || x(Q) || m)
+//! R = R_1 + b R_2,  e = hash_challenge(x(R) || x(Q) || m)
+//!
+//! 2라운드: s_i = k_i1 + b k_i2 + e a_i d_i,   s = sum s_i
+//! ```
+//!
+//! Q나 R의 y가 홀수면 BIP-340에 맞게 d_i나 k_i의 부호를 바꾼다. 같은 nonce로
+//! 다른 세션에 두 번 서명하면 개인키가 드러나므로, `SecNonce`는 복사할 수 없고
+//! 서명할 때 소비되며 drop될 때 지워진다.
+
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, PointSecp256k1, Secp256k1Curve};
+use crate::protocols::keys::{PublicKey, SecretKey};
+use crate::protocols::schnorr::{CHALLENGE_TAG, Signature, XOnlyPublicKey, has_even_y, tagged_hash, to_bytes32};
+
+use ark_ff::{One, PrimeField, Zero};
+use ark_std::rand::{RngCore, thread_rng};
+use num_bigint::BigUint;
+use thiserror::Error;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use std::fmt;
+
+pub const KEY_AGG_LIST_TAG: &str = "KeyAgg list";
+pub const KEY_AGG_COEFF_TAG: &str = "KeyAgg coefficient";
+pub const AUX_TAG: &str = "MuSig/aux";
+pub const NONCE_TAG: &str = "MuSig/nonce";
+pub const NONCE_COEF_TAG: &str = "MuSig/noncecoef";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MuSigError {
+    #[error("expected {expected} bytes, got {got}")]
+    Length { expected: usize, got: usize },
+    #[error("public key {0} is not a valid compressed point")]
+    InvalidPublicKey(usize),
+    #[error("the public nonce is not a pair of valid compressed points")]
+    InvalidPubNonce,
+    #[error("the aggregate nonce is not a pair of compressed points or infinity")]
+    InvalidAggNonce,
+    #[error("the secret nonce is out of range")]
+    InvalidSecNonce,
+    #[error("the partial signature is not below the group order")]
+    InvalidPartialSignature,
+    #[error("the tweak is not below the group order")]
+    TweakOutOfRange,
+    #[error("the aggregate key is the point at infinity")]
+    InfiniteAggregateKey,
+    #[error("the public key is not one of the signers")]
+    NotASigner,
+    #[error("the secret nonce was generated for another public key")]
+    WrongSecNonce,
+}
+
+/// 33바이트 SEC1 압축 점
+fn cbytes(point: &PointSecp256k1) -> [u8; 33] {
+    let bytes = point.to_compressed().expect("not the point at infinity");
+    bytes.try_into().expect("33 bytes")
+}
+
+/// 무한원점은 33바이트의 0
+fn cbytes_ext(point: &PointSecp256k1) -> [u8; 33] {
+    if point.inner.is_infinity() { [0; 33] } else { cbytes(point) }
+}
+
+fn cpoint_ext(bytes: &[u8]) -> Option<PointSecp256k1> {
+    if bytes.iter().all(|&byte| byte == 0) {
+        return Some(PointSecp256k1::infinity());
+    }
+    PointSecp256k1::from_compressed(bytes)
+}
+
+fn xbytes(point: &PointSecp256k1) -> [u8; 32] {
+    to_bytes32(&point.inner.x().expect("not the point at infinity"))
+}
+
+/// n 미만인 32바이트 정수
+fn scalar_from_bytes(bytes: &[u8]) -> Option<FrSecp256k1> {
+    let value = BigUint::from_bytes_be(bytes);
+    (value < FrSecp256k1::MODULUS.into()).then(|| FrSecp256k1::from(value))
+}
+
+/// Q의 y가 짝수면 1, 아니면 -1
+fn parity_sign(point: &PointSecp256k1) -> FrSecp256k1 {
+    if has_even_y(point) { FrSecp256k1::one() } else { -FrSecp256k1::one() }
+}
+
+/// 압축 인코딩의 사전순으로 정렬 (BIP-327 KeySort)
+pub fn key_sort(public_keys: &mut [PublicKey<Secp256k1Curve>]) {
+    public_keys.sort_by_key(|public_key| public_key.as_point().to_compressed());
+}
+
+/// 모은 공개키 Q와 tweak 누적값. Q = gacc * (a_1 P_1 + ... ) + tacc * G
+#[derive(Clone)]
+pub struct KeyAggContext {
+    public_keys: Vec<[u8; 33]>,
+    list_hash: [u8; 32],
+    second_key: [u8; 33],
+    q: PointSecp256k1,
+    gacc: FrSecp256k1,
+    tacc: FrSecp256k1,
+}
+
+impl KeyAggContext {
+    pub fn new(public_keys: &[PublicKey<Secp256k1Curve>]) -> Result<Self, MuSigError> {
+        let encoded: Vec<[u8; 33]> = public_keys.iter().map(|public_key| cbytes(public_key.as_point())).collect();
+        let encoded: Vec<&[u8]> = encoded.iter().map(|bytes| bytes.as_slice()).collect();
+        Self::from_bytes(&encoded)
+    }
+
+    /// 압축 공개키 목록에서. 틀린 키는 위치와 함께 거부한다
+    pub fn from_bytes(public_keys: &[&[u8]]) -> Result<Self, MuSigError> {
+        let mut encoded = Vec::with_capacity(public_keys.len());
+        let mut points = Vec::with_capacity(public_keys.len());
+        for (i, bytes) in public_keys.iter().enumerate() {
+            let point = PointSecp256k1::from_compressed(bytes).ok_or(MuSigError::InvalidPublicKey(i))?;
+            encoded.push(cbytes(&point));
+            points.push(point);
+        }
+
+        let list: Vec<&[u8]> = encoded.iter().map(|bytes| bytes.as_slice()).collect();
+        let mut context = KeyAggContext {
+            list_hash: tagged_hash(KEY_AGG_LIST_TAG, &list),
+            second_key: encoded.iter().find(|bytes| Some(*bytes) != encoded.first()).copied().unwrap_or([0; 33]),
+            public_keys: encoded.clone(),
+            q: PointSecp256k1::infinity(),
+            gacc: FrSecp256k1::one(),
+            tacc: FrSecp256k1::zero(),
+        };
+        for (point, bytes) in points.iter().zip(&encoded) {
+            context.q = context.q.add(&point.mul_scalar(&context.coefficient(bytes)));
+        }
+        if context.q.inner.is_infinity() {
+            return Err(MuSigError::InfiniteAggregateKey);
+        }
+        Ok(context)
+    }
+
+    /// a_i = hash_coef(L || pk_i), 목록에서 첫 키와 다른 첫 번째 키는 1
+    fn coefficient(&self, public_key: &[u8; 33]) -> FrSecp256k1 {
+        if *public_key == self.second_key {
+            return FrSecp256k1::one();
+        }
+        FrSecp256k1::from_be_bytes_mod_order(&tagged_hash(KEY_AGG_COEFF_TAG, &[&self.list_hash, public_key]))
+    }
+
+    fn session_coefficient(&self, public_key: &[u8; 33]) -> Result<FrSecp256k1, MuSigError> {
+        if !self.public_keys.contains(public_key) {
+            return Err(MuSigError::NotASigner);
+        }
+        Ok(self.coefficient(public_key))
+    }
+
+    /// Q' = gQ + tG. x-only tweak (Taproot)이면 g는 Q를 짝수 y로 맞추는 부호,
+    /// 아니면 1 (BIP 32 스타일)
+    pub fn with_tweak(mut self, tweak: &[u8; 32], is_xonly: bool) -> Result<Self, MuSigError> {
+        let g = if is_xonly { parity_sign(&self.q) } else { FrSecp256k1::one() };
+        let t = scalar_from_bytes(tweak).ok_or(MuSigError::TweakOutOfRange)?;
+        self.q = self.q.mul_scalar(&g).add(&Secp256k1Curve::generator().mul_scalar(&t));
+        if self.q.inner.is_infinity() {
+            return Err(MuSigError::InfiniteAggregateKey);
+        }
+        self.gacc *= g;
+        self.tacc = t + g * self.tacc;
+        Ok(self)
+    }
+
+    pub fn aggregate_key(&self) -> &PointSecp256k1 {
+        &self.q
+    }
+
+    /// BIP-340 검증에 쓰는 x-only Q
+    pub fn x_only_public_key(&self) -> XOnlyPublicKey {
+        XOnlyPublicKey::from_point(&self.q).expect("Q is not the point at infinity").0
+    }
+
+    pub fn public_keys(&self) -> &[[u8; 33]] {
+        &self.public_keys
+    }
+}
+
+impl fmt::Debug for KeyAggContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyAggContext").field("q", &self.q).field("gacc", &self.gacc).field("tacc", &self.tacc).finish()
+    }
+}
+
+/// 비밀 nonce (k_1, k_2)와 그것을 만든 공개키. 복사할 수 없고 서명에 한 번
+/// 쓰이면 사라진다
+pub struct SecNonce {
+    k1: FrSecp256k1,
+    k2: FrSecp256k1,
+    public_key: [u8; 33],
+}
+
+impl SecNonce {
+    /// k_1 || k_2 || pk, 97바이트. BIP의 테스트 벡터를 위한 것으로, 바이트에서
+    /// 되살린 nonce는 재사용을 막을 수 없다
+    #[cfg(test)]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, MuSigError> {
+        if bytes.len() != 97 {
+            return Err(MuSigError::Length { expected: 97, got: bytes.len() });
+        }
+        let k1 = scalar_from_bytes(&bytes[..32]).filter(|k| !k.is_zero());
+        let k2 = scalar_from_bytes(&bytes[32..64]).filter(|k| !k.is_zero());
+        let (Some(k1), Some(k2)) = (k1, k2) else {
+            return Err(MuSigError::InvalidSecNonce);
+        };
+        Ok(SecNonce { k1, k2, public_key: bytes[64..].try_into().expect("33 bytes") })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(97));
+        bytes.extend_from_slice(&to_bytes32(&self.k1));
+        bytes.extend_from_slice(&to_bytes32(&self.k2));
+        bytes.extend_from_slice(&self.public_key);
+        bytes
+    }
+}
+
+impl Drop for SecNonce {
+    fn drop(&mut self) {
+        self.k1.zeroize();
+        self.k2.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecNonce {}
+
+impl fmt::Debug for SecNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecNonce(<redacted>)")
+    }
+}
+
+/// 1라운드에 보내는 (R_1, R_2) = (k_1 G, k_2 G)
+#[derive(Clone, PartialEq, Eq)]
+pub struct PubNonce {
+    r1: PointSecp256k1,
+    r2: PointSecp256k1,
+}
+
+impl PubNonce {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MuSigError> {
+        if bytes.len() != 66 {
+            return Err(MuSigError::Length { expected: 66, got: bytes.len() });
+        }
+        let r1 = PointSecp256k1::from_compressed(&bytes[..33]).ok_or(MuSigError::InvalidPubNonce)?;
+        let r2 = PointSecp256k1::from_compressed(&bytes[33..]).ok_or(MuSigError::InvalidPubNonce)?;
+        Ok(PubNonce { r1, r2 })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 66] {
+        let mut bytes = [0u8; 66];
+        bytes[..33].copy_from_slice(&cbytes(&self.r1));
+        bytes[33..].copy_from_slice(&cbytes(&self.r2));
+        bytes
+    }
+}
+
+impl fmt::Debug for PubNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PubNonce").field("r1", &self.r1).field("r2", &self.r2).finish()
+    }
+}
+
+/// 공개 nonce들의 합. 어느 쪽이든 무한원점일 수 있다
+#[derive(Clone, PartialEq, Eq)]
+pub struct AggNonce {
+    r1: PointSecp256k1,
+    r2: PointSecp256k1,
+}
+
+impl AggNonce {
+    /// BIP-327 NonceAgg
+    pub fn aggregate(pubnonces: &[PubNonce]) -> Self {
+        let mut r1 = PointSecp256k1::infinity();
+        let mut r2 = PointSecp256k1::infinity();
+        for pubnonce in pubnonces {
+            r1 = r1.add(&pubnonce.r1);
+            r2 = r2.add(&pubnonce.r2);
+        }
+        AggNonce { r1, r2 }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MuSigError> {
+        if bytes.len() != 66 {
+            return Err(MuSigError::Length { expected: 66, got: bytes.len() });
+        }
+        let r1 = cpoint_ext(&bytes[..33]).ok_or(MuSigError::InvalidAggNonce)?;
+        let r2 = cpoint_ext(&bytes[33..]).ok_or(MuSigError::InvalidAggNonce)?;
+        Ok(AggNonce { r1, r2 })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 66] {
+        let mut bytes = [0u8; 66];
+        bytes[..33].copy_from_slice(&cbytes_ext(&self.r1));
+        bytes[33..].copy_from_slice(&cbytes_ext(&self.r2));
+        bytes
+    }
+}
+
+impl fmt::Debug for AggNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AggNonce").field("r1", &self.r1).field("r2", &self.r2).finish()
+    }
+}
+
+/// NonceGen의 선택 입력. 넣을수록 RNG가 나쁠 때의 피해가 줄어든다
+#[derive(Clone, Copy, Default)]
+pub struct NonceInputs<'a> {
+    pub secret_key: Option<&'a SecretKey<Secp256k1Curve>>,
+    pub aggregate_key: Option<&'a XOnlyPublicKey>,
+    pub message: Option<&'a [u8]>,
+    pub extra_in: Option<&'a [u8]>,
+}
+
+/// BIP-327 NonceGen, rand'은 `thread_rng()`에서
+pub fn nonce_gen(public_key: &PublicKey<Secp256k1Curve>, inputs: NonceInputs) -> (SecNonce, PubNonce) {
+    let mut rand = Zeroizing::new([0u8; 32]);
+    thread_rng().fill_bytes(&mut *rand);
+    let aggregate_key = inputs.aggregate_key.map(|key| key.to_bytes());
+    nonce_gen_with_rand(
+        &rand,
+        inputs.secret_key.map(|key| key.to_bytes()).as_deref().map(|bytes| bytes.as_slice()),
+        &cbytes(public_key.as_point()),
+        aggregate_key.as_ref().map(|bytes| bytes.as_slice()),
+        inputs.message,
+        inputs.extra_in,
+    )
+}
+
+/// rand'을 직접 받는 NonceGen. 같은 rand'을 두 번 쓰면 nonce가 재사용되므로
+/// BIP의 테스트 벡터에만 쓴다
+pub(crate) fn nonce_gen_with_rand(
+    rand: &[u8; 32],
+    secret_key: Option<&[u8]>,
+    public_key: &[u8],
+    aggregate_key: Option<&[u8]>,
+    message: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> (SecNonce, PubNonce) {
+    let rand = Zeroizing::new(match secret_key {
+        Some(secret_key) => {
+            let mask = tagged_hash(AUX_TAG, &[rand]);
+            let mut masked: [u8; 32] = secret_key.try_into().expect("32-byte secret key");
+            masked.iter_mut().zip(mask).for_each(|(byte, mask)| *byte ^= mask);
+            masked
+        }
+        None => *rand,
+    });
+    let aggregate_key = aggregate_key.unwrap_or(&[]);
+    let message_prefixed = match message {
+        Some(message) => [&[1u8][..], &(message.len() as u64).to_be_bytes(), message].concat(),
+        None => vec![0],
+    };
+    let extra_in = extra_in.unwrap_or(&[]);
+
+    let k = |i: u8| {
+        let hash = Zeroizing::new(tagged_hash(
+            NONCE_TAG,
+            &[
+                &*rand,
+                &[public_key.len() as u8],
+                public_key,
+                &[aggregate_key.len() as u8],
+                aggregate_key,
+                &message_prefixed,
+                &(extra_in.len() as u32).to_be_bytes(),
+                extra_in,
+                &[i],
+            ],
+        ));
+        let k = FrSecp256k1::from_be_bytes_mod_order(&*hash);
+        assert!(!k.is_zero(), "k = 0 happens with negligible probability");
+        k
+    };
+    let (k1, k2) = (k(0), k(1));
+
+    let g = Secp256k1Curve::generator();
+    let pubnonce = PubNonce { r1: g.mul_scalar(&k1), r2: g.mul_scalar(&k2) };
+    let secnonce = SecNonce { k1, k2, public_key: public_key.try_into().expect("33-byte public key") };
+    (secnonce, pubnonce)
+}
+
+/// 2라운드의 부분 서명 s_i
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialSignature(FrSecp256k1);
+
+impl PartialSignature {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MuSigError> {
+        if bytes.len() != 32 {
+            return Err(MuSigError::Length { expected: 32, got: bytes.len() });
+        }
+        scalar_from_bytes(bytes).map(PartialSignature).ok_or(MuSigError::InvalidPartialSignature)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        to_bytes32(&self.0)
+    }
+}
+
+/// 한 서명자의 1라운드 상태. `sign`이 self를 소비하므로 같은 nonce로 두
+/// 번 서명할 수 없다
+pub struct FirstRound {
+    secnonce: SecNonce,
+    pubnonce: PubNonce,
+}
+
+impl FirstRound {
+    /// 개인키, 모은 키, 알고 있다면 메시지까지 NonceGen에 넣는다
+    pub fn new(secret_key: &SecretKey<Secp256k1Curve>, key_agg: &KeyAggContext, message: Option<&[u8]>) -> Self {
+        let aggregate_key = key_agg.x_only_public_key();
+        let inputs =
+            NonceInputs { secret_key: Some(secret_key), aggregate_key: Some(&aggregate_key), message, extra_in: None };
+        let (secnonce, pubnonce) = nonce_gen(&secret_key.public_key(), inputs);
+        FirstRound { secnonce, pubnonce }
+    }
+
+    /// 다른 서명자에게 보낼 공개 nonce
+    pub fn pubnonce(&self) -> &PubNonce {
+        &self.pubnonce
+    }
+
+    pub fn sign(
+        self,
+        session: &Session,
+        secret_key: &SecretKey<Secp256k1Curve>,
+    ) -> Result<PartialSignature, MuSigError> {
+        session.sign(self.secnonce, secret_key)
+    }
+}
+
+/// 모은 nonce와 메시지가 정해진 2라운드. 모든 서명자가 같은 값을 계산한다
+#[derive(Debug, Clone)]
+pub struct Session {
+    key_agg: KeyAggContext,
+    b: FrSecp256k1,
+    r: PointSecp256k1,
+    e: FrSecp256k1,
+}
+
+impl Session {
+    /// R = R_1 + b R_2가 무한원점이면 G를 대신 쓴다. 그런 R은 정직한
+    /// 서명자만으로는 나오지 않지만, 악의적인 서명자 때문에 세션이 멈추지
+    /// 않게 한다
+    pub fn new(key_agg: &KeyAggContext, aggnonce: &AggNonce, message: &[u8]) -> Self {
+        let q = xbytes(&key_agg.q);
+        let b =
+            FrSecp256k1::from_be_bytes_mod_order(&tagged_hash(NONCE_COEF_TAG, &[&aggnonce.to_bytes(), &q, message]));
+        let r = aggnonce.r1.add(&aggnonce.r2.mul_scalar(&b));
+        let r = if r.inner.is_infinity() { Secp256k1Curve::generator() } else { r };
+        let e = FrSecp256k1::from_be_bytes_mod_order(&tagged_hash(CHALLENGE_TAG, &[&xbytes(&r), &q, message]));
+        Session { key_agg: key_agg.clone(), b, r, e }
+    }
+
+    /// s = k_1 + b k_2 + e a d, 여기서 d = g gacc d'이고 k_i는 R의 y가
+    /// 짝수가 되게 부호를 맞춘 값
+    pub fn sign(
+        &self,
+        secnonce: SecNonce,
+        secret_key: &SecretKey<Secp256k1Curve>,
+    ) -> Result<PartialSignature, MuSigError> {
+        let public_key = cbytes(secret_key.public_key().as_point());
+        if public_key != secnonce.public_key {
+            return Err(MuSigError::WrongSecNonce);
+        }
+        let a = self.key_agg.session_coefficient(&public_key)?;
+
+        let g = parity_sign(&self.key_agg.q);
+        let d = Zeroizing::new(g * self.key_agg.gacc * secret_key.expose_scalar());
+        let (k1, k2) = if has_even_y(&self.r) { (secnonce.k1, secnonce.k2) } else { (-secnonce.k1, -secnonce.k2) };
+        let (k1, k2) = (Zeroizing::new(k1), Zeroizing::new(k2));
+        let s = PartialSignature(*k1 + self.b * *k2 + self.e * a * *d);
+
+        debug_assert!({
+            let g = Secp256k1Curve::generator();
+            let pubnonce = PubNonce { r1: g.mul_scalar(&secnonce.k1), r2: g.mul_scalar(&secnonce.k2) };
+            self.verify_partial(&s, &pubnonce, &secret_key.public_key())
+        });
+        Ok(s)
+    }
+
+    /// s_i G = R_i1 + b R_i2 (R의 홀짝에 맞춰 부호) + e a_i g gacc P_i
+    pub fn verify_partial(
+        &self,
+        partial_signature: &PartialSignature,
+        pubnonce: &PubNonce,
+        public_key: &PublicKey<Secp256k1Curve>,
+    ) -> bool {
+        let public_key_bytes = cbytes(public_key.as_point());
+        let Ok(a) = self.key_agg.session_coefficient(&public_key_bytes) else {
+            return false;
+        };
+        let r_i = pubnonce.r1.add(&pubnonce.r2.mul_scalar(&self.b));
+        let r_i = if has_even_y(&self.r) { r_i } else { r_i.mul_scalar(&-FrSecp256k1::one()) };
+        let g = parity_sign(&self.key_agg.q) * self.key_agg.gacc;
+
+        let expected = r_i.add(&public_key.as_point().mul_scalar(&(self.e * a * g)));
+        Secp256k1Curve::generator().mul_scalar(&partial_signature.0) == expected
+    }
+
+    /// s = sum s_i + e g tacc. 결과는 x-only Q에 대한 BIP-340 서명
+    pub fn aggregate(&self, partial_signatures: &[PartialSignature]) -> Signature {
+        let s = partial_signatures.iter().fold(FrSecp256k1::zero(), |sum, partial| sum + partial.0);
+        let s = s + self.e * parity_sign(&self.key_agg.q) * self.key_agg.tacc;
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&xbytes(&self.r));
+        bytes[32..].copy_from_slice(&to_bytes32(&s));
+        Signature::from_bytes(&bytes).expect("x(R) < p and s < n")
+    }
+}
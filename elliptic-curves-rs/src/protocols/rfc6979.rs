@@ -0,0 +1,94 @@
+//! RFC 6979: 결정적 ECDSA nonce
+//!
+//! k를 난수 대신 개인키 x와 메시지 해시 h1로부터 HMAC_DRBG로 만든다.
+//! 같은 (x, h1)은 항상 같은 서명을 내고, 서명 품질이 RNG에 의존하지 않는다.
+//!
+//! ```text
+//! K, V <- HMAC_DRBG(int2octets(x) || bits2octets(h1) || k')
+//! T    <- V || V || ...   (qlen 비트가 될 때까지 V = HMAC_K(V))
+//! k    =  bits2int(T),    1 <= k < q 가 아니면 K, V를 갱신하고 다시
+//! ```
+//!
+//! k'은 RFC 6979 3.6의 추가 엔트로피로, 비어 있으면 원래의 결정적 방식이다.
+
+use crate::core::curve::Curve;
+use crate::protocols::hmac::HmacDrbg;
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use num_bigint::BigUint;
+use sha2::Digest;
+use sha2::digest::core_api::BlockSizeUser;
+
+/// qlen: 군의 위수 q의 비트 수
+fn qlen<C: Curve>() -> u64 {
+    C::ScalarField::MODULUS_BIT_SIZE as u64
+}
+
+/// rlen / 8: q를 담는 바이트 수
+fn rlen<C: Curve>() -> usize {
+    qlen::<C>().div_ceil(8) as usize
+}
+
+/// 바이트열의 앞쪽 qlen 비트를 정수로 (RFC 6979 2.3.2)
+pub fn bits2int<C: Curve>(bytes: &[u8]) -> BigUint {
+    let value = BigUint::from_bytes_be(bytes);
+    let blen = 8 * bytes.len() as u64;
+    if blen > qlen::<C>() { value >> (blen - qlen::<C>()) } else { value }
+}
+
+/// rlen 바이트 big-endian 인코딩 (RFC 6979 2.3.3)
+pub fn int2octets<C: Curve>(value: &C::ScalarField) -> Vec<u8> {
+    let bytes = value.into_bigint().to_bytes_be();
+    bytes[bytes.len() - rlen::<C>()..].to_vec()
+}
+
+/// int2octets(bits2int(h) mod q) (RFC 6979 2.3.4)
+pub fn bits2octets<C: Curve>(bytes: &[u8]) -> Vec<u8> {
+    int2octets::<C>(&hash_to_scalar::<C>(bytes))
+}
+
+/// ECDSA의 z = bits2int(h1) mod q
+pub fn hash_to_scalar<C: Curve>(bytes: &[u8]) -> C::ScalarField {
+    C::ScalarField::from(bits2int::<C>(bytes))
+}
+
+/// 후보 nonce를 차례로 내는 반복자. 첫 값으로 서명이 안 되면 (r = 0 또는
+/// s = 0) 다음 값을 쓴다
+pub struct Nonces<C: Curve, D: Digest + BlockSizeUser> {
+    drbg: HmacDrbg<D>,
+    started: bool,
+    _curve: std::marker::PhantomData<C>,
+}
+
+impl<C: Curve, D: Digest + BlockSizeUser> Nonces<C, D> {
+    /// z = bits2int(h1) mod q이므로 bits2octets(h1) = int2octets(z)
+    pub fn new(private_key: &C::ScalarField, z: &C::ScalarField, extra_entropy: &[u8]) -> Self {
+        let x = int2octets::<C>(private_key);
+        let h = int2octets::<C>(z);
+        Nonces {
+            drbg: HmacDrbg::new(&[&x, &h, extra_entropy]),
+            started: false,
+            _curve: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<C: Curve, D: Digest + BlockSizeUser> Iterator for Nonces<C, D> {
+    type Item = C::ScalarField;
+
+    fn next(&mut self) -> Option<C::ScalarField> {
+        let modulus: BigUint = C::ScalarField::MODULUS.into();
+        loop {
+            if self.started {
+                self.drbg.update(&[]);
+            }
+            self.started = true;
+
+            let t = self.drbg.generate(rlen::<C>());
+            let k = bits2int::<C>(&t);
+            if !k.is_zero() && k < modulus {
+                return Some(C::ScalarField::from(k));
+            }
+        }
+    }
+}
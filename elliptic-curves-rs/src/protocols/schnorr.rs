@@ -0,0 +1,285 @@
+//! BIP-340 Schnorr 서명 (secp256k1)
+//!
+//! 공개키는 y가 짝수인 점의 x좌표 32바이트이고, 서명은 R의 x좌표와 s를
+//! 이은 64바이트다. 해시는 모두 태그를 붙인 SHA-256이다.
+//!
+//! ```text
+//! t = bytes(d) ^ hash_aux(a)
+//! k = int(hash_nonce(t || P || m)) mod n,   R = kG (y가 짝수가 되게 k 또는 n - k)
+//! e = int(hash_challenge(R || P || m)) mod n
+//! s = k + ed mod n
+//! ```
+//!
+//! 검증은 R = sG - eP가 무한원점이 아니고 y가 짝수이며 x(R) = r인지 본다.
+
+use crate::core::curve::Curve;
+use crate::core::msm::multi_scalar_mul;
+use crate::curves::secp256k1::secp256k1::{FqSecp256k1, FrSecp256k1, PointSecp256k1, Secp256k1Curve};
+use crate::protocols::batch::{self, BatchError};
+use crate::protocols::keys::SecretKey;
+
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_std::rand::{RngCore, thread_rng};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+use std::fmt;
+
+pub const CHALLENGE_TAG: &str = "BIP0340/challenge";
+pub const AUX_TAG: &str = "BIP0340/aux";
+pub const NONCE_TAG: &str = "BIP0340/nonce";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SchnorrError {
+    #[error("expected {expected} bytes, got {got}")]
+    Length { expected: usize, got: usize },
+    #[error("x is not below the field size")]
+    XOutOfRange,
+    #[error("no curve point has this x")]
+    NotOnCurve,
+    #[error("s is not below the group order")]
+    SOutOfRange,
+}
+
+/// SHA256(SHA256(tag) || SHA256(tag) || data_1 || data_2 || ...)
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in data {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// 32바이트 big-endian
+pub(crate) fn to_bytes32<F: PrimeField>(value: &F) -> [u8; 32] {
+    let bytes = value.into_bigint().to_bytes_be();
+    bytes[bytes.len() - 32..].try_into().expect("32 bytes")
+}
+
+/// p 미만이어야 하는 32바이트 x좌표
+fn field_from_bytes(bytes: &[u8]) -> Result<FqSecp256k1, SchnorrError> {
+    let value = BigUint::from_bytes_be(bytes);
+    if value >= FqSecp256k1::MODULUS.into() {
+        return Err(SchnorrError::XOutOfRange);
+    }
+    Ok(FqSecp256k1::from(value))
+}
+
+pub(crate) fn has_even_y(point: &PointSecp256k1) -> bool {
+    point.inner.y().is_some_and(|y| y.into_bigint().is_even())
+}
+
+/// int(hash_challenge(R || P || m)) mod n
+fn challenge(r: &[u8; 32], public_key: &XOnlyPublicKey, message: &[u8]) -> FrSecp256k1 {
+    let hash = tagged_hash(CHALLENGE_TAG, &[r, &public_key.to_bytes(), message]);
+    FrSecp256k1::from_be_bytes_mod_order(&hash)
+}
+
+/// x-only 공개키. y가 짝수인 점 lift_x(x)를 나타낸다
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct XOnlyPublicKey {
+    x: FqSecp256k1,
+}
+
+impl XOnlyPublicKey {
+    /// x가 p 미만이고 곡선 위의 점의 x좌표일 때만 받는다
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SchnorrError> {
+        if bytes.len() != 32 {
+            return Err(SchnorrError::Length { expected: 32, got: bytes.len() });
+        }
+        let x = field_from_bytes(bytes)?;
+        PointSecp256k1::lift_x(x, false).ok_or(SchnorrError::NotOnCurve)?;
+        Ok(XOnlyPublicKey { x })
+    }
+
+    /// 점의 x좌표와, y가 홀수여서 버려진 부호가 있었는지. 무한원점이면 None
+    pub fn from_point(point: &PointSecp256k1) -> Option<(Self, bool)> {
+        let x = point.inner.x()?;
+        Some((XOnlyPublicKey { x }, !has_even_y(point)))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        to_bytes32(&self.x)
+    }
+
+    /// y가 짝수인 점
+    pub fn to_point(&self) -> PointSecp256k1 {
+        PointSecp256k1::lift_x(self.x, false).expect("x was checked on construction")
+    }
+}
+
+impl fmt::Debug for XOnlyPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("XOnlyPublicKey").field(&self.x).finish()
+    }
+}
+
+/// 서명 (r, s): r은 y가 짝수인 R의 x좌표 (p 미만), s는 n 미만
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Signature {
+    r: [u8; 32],
+    s: FrSecp256k1,
+}
+
+impl Signature {
+    /// 64바이트 r || s. r이 곡선 위의 x좌표인지는 검증할 때 본다
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SchnorrError> {
+        if bytes.len() != 64 {
+            return Err(SchnorrError::Length { expected: 64, got: bytes.len() });
+        }
+        let (r, s) = bytes.split_at(32);
+        field_from_bytes(r)?;
+        let s = BigUint::from_bytes_be(s);
+        if s >= FrSecp256k1::MODULUS.into() {
+            return Err(SchnorrError::SOutOfRange);
+        }
+        Ok(Signature { r: r.try_into().expect("32 bytes"), s: FrSecp256k1::from(s) })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&to_bytes32(&self.s));
+        bytes
+    }
+
+    pub fn r(&self) -> &[u8; 32] {
+        &self.r
+    }
+
+    pub fn s(&self) -> &FrSecp256k1 {
+        &self.s
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signature").field("r", &self.r).field("s", &self.s).finish()
+    }
+}
+
+/// 개인키와 그 x-only 공개키
+pub struct Keypair {
+    secret_key: SecretKey<Secp256k1Curve>,
+    public_key: XOnlyPublicKey,
+}
+
+impl Keypair {
+    pub fn new(secret_key: SecretKey<Secp256k1Curve>) -> Self {
+        let point = secret_key.public_key().to_point();
+        let (public_key, _) = XOnlyPublicKey::from_point(&point).expect("d is nonzero");
+        Keypair { secret_key, public_key }
+    }
+
+    pub fn random() -> Self {
+        Self::new(SecretKey::random())
+    }
+
+    pub fn secret_key(&self) -> &SecretKey<Secp256k1Curve> {
+        &self.secret_key
+    }
+
+    pub fn public_key(&self) -> &XOnlyPublicKey {
+        &self.public_key
+    }
+
+    /// dG의 y가 짝수가 되도록 d 또는 n - d
+    fn even_y_scalar(&self) -> Zeroizing<FrSecp256k1> {
+        let d = *self.secret_key.expose_scalar();
+        let point = Secp256k1Curve::generator().mul_scalar(&d);
+        Zeroizing::new(if has_even_y(&point) { d } else { -d })
+    }
+}
+
+/// `Schnorr::verify_batch`의 (공개키, 메시지, 서명)
+pub type BatchItem<'a> = (&'a XOnlyPublicKey, &'a [u8], &'a Signature);
+
+pub struct Schnorr;
+
+impl Schnorr {
+    /// BIP-340의 서명. `aux_rand`는 nonce에 섞는 보조 난수로, 0이어도 서명은
+    /// 안전하지만 새로 뽑으면 부채널과 fault 공격을 어렵게 한다
+    pub fn sign(key: &Keypair, message: &[u8], aux_rand: &[u8; 32]) -> Signature {
+        let d = key.even_y_scalar();
+        let p = key.public_key.to_bytes();
+
+        let aux = tagged_hash(AUX_TAG, &[aux_rand]);
+        let mut t = Zeroizing::new(to_bytes32(&*d));
+        t.iter_mut().zip(aux).for_each(|(byte, mask)| *byte ^= mask);
+        let rand = Zeroizing::new(tagged_hash(NONCE_TAG, &[&*t, &p, message]));
+        let k = Zeroizing::new(FrSecp256k1::from_be_bytes_mod_order(&*rand));
+        assert!(!k.is_zero(), "k' = 0 happens with negligible probability");
+
+        let big_r = Secp256k1Curve::generator().mul_scalar(&k);
+        let k = if has_even_y(&big_r) { *k } else { -*k };
+        let r = to_bytes32(&big_r.inner.x().expect("k is nonzero"));
+        let e = challenge(&r, &key.public_key, message);
+        let signature = Signature { r, s: k + e * *d };
+
+        debug_assert!(Self::verify(&key.public_key, message, &signature));
+        signature
+    }
+
+    /// `thread_rng()`에서 뽑은 aux_rand로 서명
+    pub fn sign_random(key: &Keypair, message: &[u8]) -> Signature {
+        let mut aux_rand = [0u8; 32];
+        thread_rng().fill_bytes(&mut aux_rand);
+        Self::sign(key, message, &aux_rand)
+    }
+
+    /// R = sG - eP가 무한원점이 아니고 y가 짝수이며 x(R) = r
+    pub fn verify(public_key: &XOnlyPublicKey, message: &[u8], signature: &Signature) -> bool {
+        let e = challenge(&signature.r, public_key, message);
+        let big_r = Secp256k1Curve::generator()
+            .mul_scalar(&signature.s)
+            .subtract(&public_key.to_point().mul_scalar(&e));
+        match big_r.inner.x() {
+            Some(x) => has_even_y(&big_r) && to_bytes32(&x) == signature.r,
+            None => false,
+        }
+    }
+
+    /// BIP-340의 배치 검증. a_1 = 1과 임의의 a_2, ..., a_u로
+    ///
+    /// ```text
+    /// (a_1 s_1 + ... + a_u s_u) G = a_1 R_1 + ... + a_u R_u + (a_1 e_1) P_1 + ... + (a_u e_u) P_u
+    /// ```
+    ///
+    /// 를 한 번의 multi-scalar multiplication으로 확인한다. R_i는
+    /// lift_x(r_i)로 복원하고, 실패하면 하나씩 검증해 틀린 서명을 찾는다
+    pub fn verify_batch(items: &[BatchItem<'_>]) -> Result<(), BatchError> {
+        if !Self::batch_equation_holds(items) {
+            return batch::fallback(items.len(), |i| {
+                let (public_key, message, signature) = items[i];
+                Self::verify(public_key, message, signature)
+            });
+        }
+        Ok(())
+    }
+
+    fn batch_equation_holds(items: &[BatchItem<'_>]) -> bool {
+        let coefficients = batch::coefficients::<FrSecp256k1>(items.len());
+        let mut points = vec![Secp256k1Curve::generator()];
+        let mut scalars = vec![FrSecp256k1::zero()];
+
+        for ((public_key, message, signature), a) in items.iter().zip(coefficients) {
+            let Ok(r_x) = field_from_bytes(&signature.r) else {
+                return false;
+            };
+            let Some(big_r) = PointSecp256k1::lift_x(r_x, false) else {
+                return false;
+            };
+            let e = challenge(&signature.r, public_key, message);
+
+            scalars[0] -= a * signature.s;
+            points.extend([big_r, public_key.to_point()]);
+            scalars.extend([a, a * e]);
+        }
+        multi_scalar_mul(&points, &scalars).inner.is_infinity()
+    }
+}
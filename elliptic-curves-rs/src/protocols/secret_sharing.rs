@@ -0,0 +1,460 @@
+//! Shamir 비밀 분산과 검증 가능한 비밀 분산 (Feldman, Pedersen)
+//!
+//! 비밀 s를 상수항으로 하는 t - 1차 다항식 f를 뽑아 참가자 i에게 (x_i, f(x_i))를
+//! 준다. t개의 조각이면 Lagrange 보간으로 s = f(0)을 되찾고, t - 1개로는 s에
+//! 대해 아무것도 알 수 없다.
+//!
+//! ```text
+//! f(x) = sum_j y_j lambda_j(x),   lambda_j(x) = prod_{m != j} (x - x_m) / (x_j - x_m)
+//!
+//! Feldman:  C_k = a_k G,           f(x_i) G = sum_k C_k x_i^k
+//! Pedersen: C_k = a_k G + b_k H,   f(x_i) G + g(x_i) H = sum_k C_k x_i^k
+//! ```
+//!
+//! 검증 가능한 분산에서는 딜러가 계수의 commitment를 공개하므로, 참가자는 받은
+//! 조각이 한 다항식 위에 있는지 혼자 확인할 수 있다. Feldman의 C_0 = sG는 s의
+//! 공개키를 드러내지만, Pedersen은 두 번째 다항식 g로 가려서 commitment가 s에
+//! 대해 아무것도 알려 주지 않는다. H는 해시로 만든 점이라 log_G H를 아무도
+//! 모른다 (`hash_to_field::hash_to_curve`).
+
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::protocols::hash_to_field::hash_to_curve;
+
+use ark_ff::{Field, Zero};
+use ark_std::{UniformRand, rand::thread_rng};
+use thiserror::Error;
+use zeroize::Zeroize;
+
+use std::fmt;
+
+/// Pedersen VSS의 두 번째 생성원 H를 만드는 DST
+pub const PEDERSEN_GENERATOR_DST: &[u8] = b"elliptic-curves-rs-Pedersen-VSS-H";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SecretSharingError {
+    #[error("need 1 <= threshold <= shares, got {threshold} of {shares}")]
+    InvalidThreshold { threshold: usize, shares: usize },
+    #[error("share index must be nonzero")]
+    ZeroIndex,
+    #[error("two shares have the same index")]
+    DuplicateIndex,
+    #[error("no shares to interpolate")]
+    NoShares,
+    #[error("commitment has no points")]
+    EmptyCommitment,
+}
+
+/// sum_k a_k x^k (Horner)
+pub fn evaluate_polynomial<F: Field>(coefficients: &[F], x: F) -> F {
+    coefficients.iter().rev().fold(F::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// sum_k C_k x^k, 지수에서 다항식을 계산한다
+pub fn evaluate_commitment<C: Curve>(commitment: &[CurvePoint<C>], x: C::ScalarField) -> CurvePoint<C> {
+    commitment.iter().rev().fold(CurvePoint::infinity(), |acc, point| acc.mul_scalar(&x).add(point))
+}
+
+/// `indices`로 보간한 다항식에서 x_i의 값이 x에서 갖는 가중치 lambda_i(x).
+/// 인덱스는 서로 달라야 한다
+pub fn lagrange_coefficient<F: Field>(indices: &[F], x_i: F, x: F) -> F {
+    let (mut numerator, mut denominator) = (F::one(), F::one());
+    for &x_m in indices.iter().filter(|&&x_m| x_m != x_i) {
+        numerator *= x - x_m;
+        denominator *= x_i - x_m;
+    }
+    numerator * denominator.inverse().expect("indices are distinct")
+}
+
+fn check_distinct<F: Field>(indices: &[F]) -> Result<(), SecretSharingError> {
+    for (i, x) in indices.iter().enumerate() {
+        if indices[..i].contains(x) {
+            return Err(SecretSharingError::DuplicateIndex);
+        }
+    }
+    Ok(())
+}
+
+/// 점들 (x_j, y_j)를 지나는 가장 낮은 차수의 다항식을 x에서 계산한다
+pub fn interpolate<F: Field>(points: &[(F, F)], x: F) -> Result<F, SecretSharingError> {
+    if points.is_empty() {
+        return Err(SecretSharingError::NoShares);
+    }
+    let indices: Vec<F> = points.iter().map(|(x_j, _)| *x_j).collect();
+    check_distinct(&indices)?;
+    Ok(points.iter().map(|&(x_j, y_j)| y_j * lagrange_coefficient(&indices, x_j, x)).sum())
+}
+
+fn check_indices<F: Field>(threshold: usize, indices: &[F]) -> Result<(), SecretSharingError> {
+    if threshold == 0 || threshold > indices.len() {
+        return Err(SecretSharingError::InvalidThreshold { threshold, shares: indices.len() });
+    }
+    if indices.iter().any(|x| x.is_zero()) {
+        return Err(SecretSharingError::ZeroIndex);
+    }
+    check_distinct(indices)
+}
+
+/// 1, 2, ..., n
+fn default_indices<F: Field>(shares: usize) -> Vec<F> {
+    (1..=shares as u64).map(F::from).collect()
+}
+
+/// 상수항이 주어지고 나머지 계수가 난수인 다항식. drop될 때 지워진다
+pub struct Polynomial<C: Curve> {
+    coefficients: Vec<C::ScalarField>,
+}
+
+impl<C: Curve> Polynomial<C> {
+    pub fn random(constant: C::ScalarField, degree: usize) -> Self {
+        let mut rng = thread_rng();
+        let coefficients =
+            std::iter::once(constant).chain((0..degree).map(|_| C::ScalarField::rand(&mut rng))).collect();
+        Polynomial { coefficients }
+    }
+
+    pub fn from_coefficients(coefficients: Vec<C::ScalarField>) -> Self {
+        Polynomial { coefficients }
+    }
+
+    pub fn coefficients(&self) -> &[C::ScalarField] {
+        &self.coefficients
+    }
+
+    pub fn evaluate(&self, x: C::ScalarField) -> C::ScalarField {
+        evaluate_polynomial(&self.coefficients, x)
+    }
+}
+
+impl<C: Curve> Drop for Polynomial<C> {
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<C: Curve> fmt::Debug for Polynomial<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Polynomial(degree {}, <redacted>)", self.coefficients.len().saturating_sub(1))
+    }
+}
+
+/// 조각 (x_i, f(x_i)), x_i != 0
+pub struct Share<C: Curve> {
+    index: C::ScalarField,
+    value: C::ScalarField,
+}
+
+impl<C: Curve> Share<C> {
+    pub fn new(index: C::ScalarField, value: C::ScalarField) -> Result<Self, SecretSharingError> {
+        if index.is_zero() {
+            return Err(SecretSharingError::ZeroIndex);
+        }
+        Ok(Share { index, value })
+    }
+
+    pub fn index(&self) -> &C::ScalarField {
+        &self.index
+    }
+
+    /// f(x_i)를 그대로 꺼낸다. 복사한 값은 지워지지 않는다
+    pub fn value(&self) -> &C::ScalarField {
+        &self.value
+    }
+}
+
+impl<C: Curve> Clone for Share<C> {
+    fn clone(&self) -> Self {
+        Share { index: self.index, value: self.value }
+    }
+}
+
+impl<C: Curve> Drop for Share<C> {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<C: Curve> fmt::Debug for Share<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share").field("index", &self.index).field("value", &"<redacted>").finish()
+    }
+}
+
+/// Shamir 비밀 분산
+pub struct Shamir<C: Curve> {
+    _curve: std::marker::PhantomData<C>,
+}
+
+impl<C: Curve> Shamir<C> {
+    /// 인덱스 1..=n에 t-of-n으로 나눈다
+    pub fn split(
+        secret: &C::ScalarField,
+        threshold: usize,
+        shares: usize,
+    ) -> Result<Vec<Share<C>>, SecretSharingError> {
+        Self::split_at(secret, threshold, &default_indices(shares))
+    }
+
+    /// 0이 아니고 서로 다른 임의의 인덱스에 나눈다
+    pub fn split_at(
+        secret: &C::ScalarField,
+        threshold: usize,
+        indices: &[C::ScalarField],
+    ) -> Result<Vec<Share<C>>, SecretSharingError> {
+        check_indices(threshold, indices)?;
+        let polynomial = Polynomial::<C>::random(*secret, threshold - 1);
+        Ok(Self::shares_of(&polynomial, indices))
+    }
+
+    fn shares_of(polynomial: &Polynomial<C>, indices: &[C::ScalarField]) -> Vec<Share<C>> {
+        indices.iter().map(|&index| Share { index, value: polynomial.evaluate(index) }).collect()
+    }
+
+    /// f(0). 조각이 t개 미만이면 틀린 값이 나오고, 그것을 알아챌 방법은 없다
+    pub fn reconstruct(shares: &[Share<C>]) -> Result<C::ScalarField, SecretSharingError> {
+        Self::interpolate_at(shares, C::ScalarField::zero())
+    }
+
+    /// 조각들이 정하는 다항식의 x에서의 값. 새 참가자에게 줄 조각을 만들 때 쓴다
+    pub fn interpolate_at(shares: &[Share<C>], x: C::ScalarField) -> Result<C::ScalarField, SecretSharingError> {
+        let points: Vec<(C::ScalarField, C::ScalarField)> =
+            shares.iter().map(|share| (share.index, share.value)).collect();
+        interpolate(&points, x)
+    }
+}
+
+/// Feldman VSS의 commitment (a_0 G, ..., a_{t-1} G)
+pub struct FeldmanCommitment<C: Curve> {
+    points: Vec<CurvePoint<C>>,
+}
+
+impl<C: Curve> FeldmanCommitment<C> {
+    pub fn commit(polynomial: &Polynomial<C>) -> Self {
+        let g = C::generator();
+        FeldmanCommitment { points: polynomial.coefficients().iter().map(|a| g.mul_scalar(a)).collect() }
+    }
+
+    /// 점이 하나도 없으면 상수항의 commitment가 없으므로 받지 않는다
+    pub fn from_points(points: Vec<CurvePoint<C>>) -> Result<Self, SecretSharingError> {
+        if points.is_empty() {
+            return Err(SecretSharingError::EmptyCommitment);
+        }
+        Ok(FeldmanCommitment { points })
+    }
+
+    pub fn points(&self) -> &[CurvePoint<C>] {
+        &self.points
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.points.len()
+    }
+
+    /// sG, 비밀의 공개키
+    pub fn public_key(&self) -> &CurvePoint<C> {
+        &self.points[0]
+    }
+
+    /// f(x) G
+    pub fn evaluate(&self, x: C::ScalarField) -> CurvePoint<C> {
+        evaluate_commitment(&self.points, x)
+    }
+
+    pub fn verify(&self, share: &Share<C>) -> bool {
+        C::generator().mul_scalar(&share.value) == self.evaluate(share.index)
+    }
+}
+
+impl<C: Curve> Clone for FeldmanCommitment<C> {
+    fn clone(&self) -> Self {
+        FeldmanCommitment { points: self.points.clone() }
+    }
+}
+
+impl<C: Curve> PartialEq for FeldmanCommitment<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.points == other.points
+    }
+}
+
+impl<C: Curve> Eq for FeldmanCommitment<C> {}
+
+impl<C: Curve> fmt::Debug for FeldmanCommitment<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FeldmanCommitment").field(&self.points).finish()
+    }
+}
+
+/// 모두가 받은 조각을 확인할 수 있는 Shamir 분산. commitment가 sG를 드러낸다
+pub struct Feldman<C: Curve> {
+    _curve: std::marker::PhantomData<C>,
+}
+
+/// 참가자마다의 조각과 딜러가 공개하는 commitment
+pub type FeldmanOutput<C> = (Vec<Share<C>>, FeldmanCommitment<C>);
+
+impl<C: Curve> Feldman<C> {
+    pub fn split(
+        secret: &C::ScalarField,
+        threshold: usize,
+        shares: usize,
+    ) -> Result<FeldmanOutput<C>, SecretSharingError> {
+        Self::split_at(secret, threshold, &default_indices(shares))
+    }
+
+    pub fn split_at(
+        secret: &C::ScalarField,
+        threshold: usize,
+        indices: &[C::ScalarField],
+    ) -> Result<FeldmanOutput<C>, SecretSharingError> {
+        check_indices(threshold, indices)?;
+        let polynomial = Polynomial::<C>::random(*secret, threshold - 1);
+        Ok((Shamir::shares_of(&polynomial, indices), FeldmanCommitment::commit(&polynomial)))
+    }
+}
+
+/// Pedersen VSS의 조각 (x_i, f(x_i), g(x_i))
+pub struct PedersenShare<C: Curve> {
+    share: Share<C>,
+    blinding: C::ScalarField,
+}
+
+impl<C: Curve> PedersenShare<C> {
+    pub fn new(share: Share<C>, blinding: C::ScalarField) -> Self {
+        PedersenShare { share, blinding }
+    }
+
+    /// 비밀을 복원할 때 쓰는 (x_i, f(x_i))
+    pub fn share(&self) -> &Share<C> {
+        &self.share
+    }
+
+    pub fn blinding(&self) -> &C::ScalarField {
+        &self.blinding
+    }
+}
+
+impl<C: Curve> Clone for PedersenShare<C> {
+    fn clone(&self) -> Self {
+        PedersenShare { share: self.share.clone(), blinding: self.blinding }
+    }
+}
+
+impl<C: Curve> Drop for PedersenShare<C> {
+    fn drop(&mut self) {
+        self.blinding.zeroize();
+    }
+}
+
+impl<C: Curve> fmt::Debug for PedersenShare<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PedersenShare").field("share", &self.share).field("blinding", &"<redacted>").finish()
+    }
+}
+
+/// Pedersen VSS의 commitment (a_k G + b_k H)
+pub struct PedersenCommitment<C: Curve> {
+    points: Vec<CurvePoint<C>>,
+}
+
+impl<C: Curve> PedersenCommitment<C> {
+    pub fn commit(polynomial: &Polynomial<C>, blinding: &Polynomial<C>) -> Self {
+        let (g, h) = (C::generator(), Pedersen::<C>::generator());
+        let points = polynomial
+            .coefficients()
+            .iter()
+            .zip(blinding.coefficients())
+            .map(|(a, b)| g.mul_scalar(a).add(&h.mul_scalar(b)))
+            .collect();
+        PedersenCommitment { points }
+    }
+
+    /// 점이 하나도 없으면 상수항의 commitment가 없으므로 받지 않는다
+    pub fn from_points(points: Vec<CurvePoint<C>>) -> Result<Self, SecretSharingError> {
+        if points.is_empty() {
+            return Err(SecretSharingError::EmptyCommitment);
+        }
+        Ok(PedersenCommitment { points })
+    }
+
+    pub fn points(&self) -> &[CurvePoint<C>] {
+        &self.points
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.points.len()
+    }
+
+    /// f(x) G + g(x) H
+    pub fn evaluate(&self, x: C::ScalarField) -> CurvePoint<C> {
+        evaluate_commitment(&self.points, x)
+    }
+
+    pub fn verify(&self, share: &PedersenShare<C>) -> bool {
+        let h = Pedersen::<C>::generator();
+        let expected = C::generator().mul_scalar(&share.share.value).add(&h.mul_scalar(&share.blinding));
+        expected == self.evaluate(share.share.index)
+    }
+}
+
+impl<C: Curve> Clone for PedersenCommitment<C> {
+    fn clone(&self) -> Self {
+        PedersenCommitment { points: self.points.clone() }
+    }
+}
+
+impl<C: Curve> PartialEq for PedersenCommitment<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.points == other.points
+    }
+}
+
+impl<C: Curve> Eq for PedersenCommitment<C> {}
+
+impl<C: Curve> fmt::Debug for PedersenCommitment<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PedersenCommitment").field(&self.points).finish()
+    }
+}
+
+/// commitment가 비밀을 완전히 가리는 검증 가능한 분산
+pub struct Pedersen<C: Curve> {
+    _curve: std::marker::PhantomData<C>,
+}
+
+/// 참가자마다의 조각과 딜러가 공개하는 commitment
+pub type PedersenOutput<C> = (Vec<PedersenShare<C>>, PedersenCommitment<C>);
+
+impl<C: Curve> Pedersen<C> {
+    /// H = hash_to_curve("generator H"), log_G H는 아무도 모른다
+    pub fn generator() -> CurvePoint<C> {
+        hash_to_curve(&[b"generator H"], PEDERSEN_GENERATOR_DST)
+    }
+
+    pub fn split(
+        secret: &C::ScalarField,
+        threshold: usize,
+        shares: usize,
+    ) -> Result<PedersenOutput<C>, SecretSharingError> {
+        Self::split_at(secret, threshold, &default_indices(shares))
+    }
+
+    pub fn split_at(
+        secret: &C::ScalarField,
+        threshold: usize,
+        indices: &[C::ScalarField],
+    ) -> Result<PedersenOutput<C>, SecretSharingError> {
+        check_indices(threshold, indices)?;
+        let mut rng = thread_rng();
+        let polynomial = Polynomial::<C>::random(*secret, threshold - 1);
+        let blinding = Polynomial::<C>::random(C::ScalarField::rand(&mut rng), threshold - 1);
+        let shares = indices
+            .iter()
+            .map(|&index| PedersenShare {
+                share: Share { index, value: polynomial.evaluate(index) },
+                blinding: blinding.evaluate(index),
+            })
+            .collect();
+        Ok((shares, PedersenCommitment::commit(&polynomial, &blinding)))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::protocols::batch::BatchError;
+use crate::protocols::ecdsa::{Ecdsa, RecoveryId, Signature, SigningKey};
+use crate::protocols::keys::PublicKey;
+
+use sha2::Sha256;
+
+struct Signed<C: Curve> {
+    public_key: PublicKey<C>,
+    message: Vec<u8>,
+    signature: Signature<C>,
+    recovery_id: RecoveryId,
+}
+
+fn sign_many<C: Curve>(count: usize) -> Vec<Signed<C>> {
+    (0..count)
+        .map(|i| {
+            let key = SigningKey::<C>::random();
+            let message = format!("transaction {i}").into_bytes();
+            let (signature, recovery_id) = Ecdsa::sign_recoverable::<Sha256>(&key, &message).unwrap();
+            Signed { public_key: key.public_key().clone(), message, signature, recovery_id }
+        })
+        .collect()
+}
+
+fn verify_all<C: Curve>(signed: &[Signed<C>]) -> Result<(), BatchError> {
+    let items: Vec<_> = signed
+        .iter()
+        .map(|item| (&item.public_key, item.message.as_slice(), &item.signature, item.recovery_id))
+        .collect();
+    Ecdsa::verify_batch::<Sha256>(&items)
+}
+
+fn batch_round_trip<C: Curve>() {
+    let mut signed = sign_many::<C>(12);
+    assert_eq!(verify_all(&signed), Ok(()));
+    assert_eq!(verify_all::<C>(&[]), Ok(()));
+
+    // a valid signature for another message, and a wrong parity
+    signed[3].message = b"tampered".to_vec();
+    let flipped = signed[8].recovery_id;
+    signed[8].recovery_id = RecoveryId::new(!flipped.is_y_odd(), flipped.is_x_reduced());
+    assert_eq!(verify_all(&signed), Err(BatchError { failed: vec![3, 8] }));
+}
+
+#[test]
+fn test_ecdsa_batch_verification() {
+    batch_round_trip::<Secp256k1Curve>();
+    batch_round_trip::<P256Curve>();
+}
+
+#[test]
+fn test_ecdsa_batch_agrees_with_single_verification() {
+    let signed = sign_many::<Secp256k1Curve>(4);
+    for item in &signed {
+        let z = Ecdsa::<Secp256k1Curve>::hash_message::<Sha256>(&item.message);
+        let point = item.public_key.as_point();
+        assert!(Ecdsa::verifying_message(point, z, &item.signature));
+        assert!(Ecdsa::verifying_message_recoverable(point, z, &item.signature, item.recovery_id));
+        assert_eq!(Ecdsa::verifying_batch(&[(point, z, &item.signature, item.recovery_id)]), Ok(()));
+
+        // (r, -s) verifies as plain ECDSA, but belongs to -R
+        let (r, s) = item.signature.to_scalars();
+        let negated = Signature::from_scalars(r, -s).unwrap();
+        assert!(Ecdsa::verifying_message(point, z, &negated));
+        assert!(!Ecdsa::verifying_message_recoverable(point, z, &negated, item.recovery_id));
+    }
+}
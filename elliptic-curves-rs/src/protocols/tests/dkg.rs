@@ -0,0 +1,184 @@
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::curves::toy::toy::Toy28Curve;
+use crate::protocols::dkg::{self, DkgError, Envelope, Network, Participant, Payload, ROUNDS, Recipient};
+use crate::protocols::frost::{FirstRound, Identifier, SigningPackage, aggregate};
+use crate::protocols::secret_sharing::{FeldmanCommitment, PedersenShare, Shamir, Share};
+use crate::protocols::tests::id;
+
+use ark_ff::One;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+fn participants<C: Curve>(n: u16, t: u16) -> Vec<Participant<C>> {
+    (1..=n).map(|i| Participant::new(id(i), n, t).unwrap()).collect()
+}
+
+/// `run`과 같지만 보내는 메시지마다 `tamper`를 거친다. false면 버린다
+fn run_with<C: Curve>(
+    n: u16,
+    t: u16,
+    mut tamper: impl FnMut(&mut Envelope<C>) -> bool,
+) -> Result<Vec<Participant<C>>, DkgError> {
+    let mut participants = participants::<C>(n, t);
+    let mut network = Network::new((1..=n).map(id));
+    for _ in 0..ROUNDS {
+        let mut outgoing = Vec::new();
+        for participant in participants.iter_mut() {
+            let inbox = network.receive(participant.identifier());
+            outgoing.extend(participant.step(inbox)?);
+        }
+        for mut envelope in outgoing {
+            if tamper(&mut envelope) {
+                network.send(envelope);
+            }
+        }
+    }
+    Ok(participants)
+}
+
+/// 한 칸 어긋난 조각
+fn corrupt<C: Curve>(share: &PedersenShare<C>) -> PedersenShare<C> {
+    let value = *share.share().value() + C::ScalarField::one();
+    PedersenShare::new(Share::new(*share.share().index(), value).unwrap(), *share.blinding())
+}
+
+/// `dealer`가 `victims`에게 틀린 조각을 보낸다
+fn bad_shares<C: Curve>(dealer: u16, victims: &[u16]) -> impl FnMut(&mut Envelope<C>) -> bool {
+    let victims: Vec<Recipient> = victims.iter().map(|&i| Recipient::One(id(i))).collect();
+    move |envelope| {
+        if envelope.from == id(dealer)
+            && victims.contains(&envelope.to)
+            && let Payload::Share(share) = &envelope.payload
+        {
+            envelope.payload = Payload::Share(corrupt(share));
+        }
+        true
+    }
+}
+
+/// 정직한 참가자들이 같은 결과를 얻고, 조각이 공개키와 맞는지 확인한다
+fn check_consistent<C: Curve>(participants: &[Participant<C>], honest: &[u16], qualified: &[u16]) {
+    let outputs: Vec<_> = honest.iter().map(|&i| participants[i as usize - 1].output().unwrap()).collect();
+    let first = outputs[0];
+    let qualified: BTreeSet<Identifier> = qualified.iter().copied().map(id).collect();
+    for output in &outputs {
+        assert_eq!(output.public_key(), first.public_key());
+        assert_eq!(output.verifying_shares(), first.verifying_shares());
+        assert_eq!(output.qualified(), &qualified);
+        let expected = C::generator().mul_scalar(output.secret_share().value());
+        assert_eq!(output.verifying_shares()[&output.identifier()], expected);
+    }
+
+    let shares: Vec<Share<C>> = outputs.iter().map(|output| output.secret_share().clone()).collect();
+    let secret = Shamir::reconstruct(&shares[..first.threshold() as usize]).unwrap();
+    assert_eq!(&C::generator().mul_scalar(&secret), first.public_key());
+}
+
+#[test]
+fn test_honest_run_agrees_and_signs_with_frost() {
+    let mut participants = participants::<Secp256k1Curve>(5, 3);
+    dkg::run(&mut participants).unwrap();
+    check_consistent(&participants, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+
+    let packages: BTreeMap<Identifier, _> = participants
+        .iter()
+        .map(|participant| (participant.identifier(), participant.output().unwrap().to_frost()))
+        .collect();
+    let public_key_package = &packages[&id(1)].1;
+    assert_eq!(public_key_package.verifying_key(), participants[0].output().unwrap().public_key());
+
+    for signers in [[1, 2, 3], [2, 4, 5]] {
+        let rounds: Vec<_> = signers.iter().map(|&i| (id(i), FirstRound::new(&packages[&id(i)].0))).collect();
+        let commitments = rounds.iter().map(|(i, round)| (*i, round.commitments().clone())).collect();
+        let package = SigningPackage::new(commitments, b"distributed");
+        let shares = rounds.into_iter().map(|(i, round)| (i, round.sign(&package, &packages[&i].0).unwrap())).collect();
+        let signature = aggregate(&package, &shares, public_key_package).unwrap();
+        assert!(signature.verify(public_key_package.verifying_key(), b"distributed"));
+    }
+}
+
+#[test]
+fn test_answered_complaints_keep_the_dealer() {
+    // 고발이 threshold보다 적고 공개한 조각이 맞으면 QUAL에 남는다
+    let participants = run_with::<Toy28Curve>(5, 3, bad_shares(2, &[3, 4])).unwrap();
+    check_consistent(&participants, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_too_many_complaints_disqualify() {
+    let participants = run_with::<Toy28Curve>(5, 3, bad_shares(2, &[1, 3, 4])).unwrap();
+    check_consistent(&participants, &[1, 3, 4, 5], &[1, 3, 4, 5]);
+}
+
+#[test]
+fn test_unanswered_complaint_disqualifies() {
+    let mut cheat = bad_shares::<Toy28Curve>(2, &[3]);
+    let participants = run_with::<Toy28Curve>(5, 3, |envelope| {
+        cheat(envelope) && !(envelope.from == id(2) && matches!(envelope.payload, Payload::Reveal { .. }))
+    })
+    .unwrap();
+    check_consistent(&participants, &[1, 3, 4, 5], &[1, 3, 4, 5]);
+
+    // 공개한 조각이 commitment와 맞지 않아도 마찬가지다
+    let mut cheat = bad_shares::<Toy28Curve>(2, &[3]);
+    let participants = run_with::<Toy28Curve>(5, 3, |envelope| {
+        if envelope.from == id(2)
+            && let Payload::Reveal { complainer, share } = &envelope.payload
+        {
+            envelope.payload = Payload::Reveal { complainer: *complainer, share: corrupt(share) };
+        }
+        cheat(envelope)
+    })
+    .unwrap();
+    check_consistent(&participants, &[1, 3, 4, 5], &[1, 3, 4, 5]);
+}
+
+#[test]
+fn test_bad_public_commitment_is_reconstructed() {
+    // A_2가 f_2와 맞지 않으면 나머지가 f_2를 복원하고 z_2는 그대로 키에 들어간다
+    let participants = run_with::<Toy28Curve>(5, 3, |envelope| {
+        if envelope.from == id(2)
+            && let Payload::PublicCommitment(commitment) = &envelope.payload
+        {
+            let mut points = commitment.points().to_vec();
+            points[0] = points[0].add(&Toy28Curve::generator());
+            envelope.payload = Payload::PublicCommitment(FeldmanCommitment::from_points(points).unwrap());
+        }
+        true
+    })
+    .unwrap();
+    check_consistent(&participants, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+
+    // A_2를 아예 보내지 않아도 마찬가지다
+    let participants = run_with::<Toy28Curve>(5, 3, |envelope| {
+        !(envelope.from == id(2) && matches!(envelope.payload, Payload::PublicCommitment(_)))
+    })
+    .unwrap();
+    check_consistent(&participants, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_dkg_errors() {
+    assert_eq!(
+        Participant::<Toy28Curve>::new(id(1), 3, 4).unwrap_err(),
+        DkgError::InvalidThreshold { threshold: 4, participants: 3 }
+    );
+    assert_eq!(
+        Participant::<Toy28Curve>::new(id(1), 3, 0).unwrap_err(),
+        DkgError::InvalidThreshold { threshold: 0, participants: 3 }
+    );
+    // FROST은 혼자 서명할 수 있는 키를 받지 않는다
+    assert_eq!(
+        Participant::<Toy28Curve>::new(id(1), 3, 1).unwrap_err(),
+        DkgError::InvalidThreshold { threshold: 1, participants: 3 }
+    );
+    assert_eq!(Participant::<Toy28Curve>::new(id(6), 5, 3).unwrap_err(), DkgError::UnknownParticipant(id(6)));
+
+    let error = run_with::<Toy28Curve>(3, 2, |envelope| !matches!(envelope.payload, Payload::Commitment(_)));
+    assert_eq!(error.unwrap_err(), DkgError::NoQualifiedDealers);
+
+    let mut participants = participants::<Toy28Curve>(3, 2);
+    dkg::run(&mut participants).unwrap();
+    assert_eq!(participants[0].step(Vec::new()).unwrap_err(), DkgError::Finished);
+}
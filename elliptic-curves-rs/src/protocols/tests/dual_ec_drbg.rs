@@ -0,0 +1,41 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::{FrP256, P256Curve};
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::protocols::dual_ec_drbg::DualEcDrbg;
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_dual_ec_drbg_output() {
+    let p = P256Curve::generator();
+    let q = p.mul_scalar(&FrP256::from(0xc0ffeeu64));
+    let seed = BigUint::from(123456789u64);
+
+    let mut drbg = DualEcDrbg::new(p.clone(), q.clone(), &seed);
+    assert_eq!(drbg.block_len(), 30);
+    let output = drbg.generate(75);
+    assert_eq!(output.len(), 75);
+
+    // deterministic in the seed
+    let mut again = DualEcDrbg::new(p.clone(), q.clone(), &seed);
+    assert_eq!(again.generate(75), output);
+    let mut other = DualEcDrbg::new(p.clone(), q.clone(), &(seed + 1u32));
+    assert_ne!(other.generate(75), output);
+
+    // the extra update after each call: two calls differ from one
+    let mut split = DualEcDrbg::new(p.clone(), q.clone(), &BigUint::from(123456789u64));
+    let mut first = split.generate(30);
+    first.extend(split.generate(30));
+    assert_eq!(output[..30], first[..30]);
+    assert_ne!(output[30..60], first[30..60]);
+}
+
+#[test]
+fn test_dual_ec_drbg_on_secp256k1() {
+    let p = Secp256k1Curve::generator();
+    let q = p.mul_scalar(&FrSecp256k1::from(7u64));
+    let mut drbg = DualEcDrbg::with_truncation(p, q, &BigUint::from(42u64), 8);
+    assert_eq!(drbg.block_len(), 31);
+    let output = drbg.generate(62);
+    assert_ne!(output[..31], output[31..]);
+}
@@ -1,5 +1,7 @@
-use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::core::point::{CurvePoint, Point};
+use crate::curves::secp256k1::secp256k1::{FqSecp256k1, Secp256k1Curve};
 use crate::protocols::ecdh::*;
+use crate::protocols::keys::{KeyError, PublicKey};
 
 #[test]
 fn test_ecdh_secp256k1() {
@@ -13,10 +15,71 @@ fn test_ecdh_secp256k1() {
     assert_ne!(alice_private_key, bob_private_key);
 
     // Ecdh 구조체에서 어떤 구체적인 타입을 사용할지 :: 를 이용해 명시적으로 알려줌
-    let shared_alice = Ecdh::<Secp256k1Curve>::compute_shared_secret(
-                                &alice_private_key, &bob_public_key);
-    let shared_bob = Ecdh::<Secp256k1Curve>::compute_shared_secret(
-                                &bob_private_key, &alice_public_key);
+    let shared_alice = Ecdh::<Secp256k1Curve>::diffie_hellman(
+                                &alice_private_key, &bob_public_key).unwrap();
+    let shared_bob = Ecdh::<Secp256k1Curve>::diffie_hellman(
+                                &bob_private_key, &alice_public_key).unwrap();
 
     assert_eq!(shared_alice, shared_bob);
+    assert_eq!(shared_alice.to_bytes().len(), 32);
+}
+
+#[test]
+fn test_ecdh_secp256k1_checked() {
+    let (alice_private_key, alice_public_key) =
+        Ecdh::<Secp256k1Curve>::generate_keypair();
+    let (bob_private_key, bob_public_key) =
+        Ecdh::<Secp256k1Curve>::generate_keypair();
+
+    let shared_alice = Ecdh::<Secp256k1Curve>::compute_shared_secret_checked(
+                                alice_private_key.expose_scalar(), bob_public_key.as_point());
+    let shared_bob = Ecdh::<Secp256k1Curve>::compute_shared_secret(
+                                bob_private_key.expose_scalar(), alice_public_key.as_point());
+    assert_eq!(shared_alice, Ok(shared_bob));
+
+    // 무한원점과 곡선 밖의 점은 거부
+    assert_eq!(
+        Ecdh::<Secp256k1Curve>::validate_public_key(&CurvePoint::infinity()),
+        Err(EcdhError::Infinity)
+    );
+    let mut tampered = alice_public_key.to_point();
+    if let Point::Affine { y, .. } = &mut tampered.inner {
+        *y += FqSecp256k1::from(1u64);
+    }
+    assert_eq!(
+        Ecdh::<Secp256k1Curve>::compute_shared_secret_checked(
+            bob_private_key.expose_scalar(), &tampered),
+        Err(EcdhError::NotOnCurve)
+    );
+    assert_eq!(
+        PublicKey::from_point(tampered),
+        Err(KeyError::PublicKey(EcdhError::NotOnCurve))
+    );
+}
+
+#[test]
+fn test_ecdh_secp256k1_x_only() {
+    let (alice_private_key, alice_public_key) =
+        Ecdh::<Secp256k1Curve>::generate_keypair();
+    let (bob_private_key, _) = Ecdh::<Secp256k1Curve>::generate_keypair();
+
+    // 래더로 계산한 x좌표는 affine 스칼라 곱의 x좌표와 같다
+    let alice_x = Ecdh::<Secp256k1Curve>::public_key_x(alice_private_key.expose_scalar()).unwrap();
+    assert_eq!(Some(alice_x), alice_public_key.as_point().inner.x());
+
+    let bob_x = Ecdh::<Secp256k1Curve>::public_key_x(bob_private_key.expose_scalar()).unwrap();
+    let shared_alice = Ecdh::<Secp256k1Curve>::compute_shared_secret_x_checked(
+                                alice_private_key.expose_scalar(), &bob_x);
+    let shared_bob = Ecdh::<Secp256k1Curve>::compute_shared_secret_x(
+                                bob_private_key.expose_scalar(), &alice_x);
+    assert_eq!(shared_alice.ok(), shared_bob);
+
+    // p = 3 mod 4 이므로 x^3 + 7 = -1 은 제곱수가 아니고, x = -2 는 twist 위의 점
+    let twist_x = -FqSecp256k1::from(2u64);
+    assert_eq!(
+        Ecdh::<Secp256k1Curve>::validate_public_x(&twist_x),
+        Err(EcdhError::OnTwist)
+    );
+    // d = 0 이면 공개키가 무한원점이라 x좌표가 없다
+    assert_eq!(Ecdh::<Secp256k1Curve>::public_key_x(&Default::default()), None);
 }
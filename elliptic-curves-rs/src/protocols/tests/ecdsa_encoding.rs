@@ -0,0 +1,120 @@
+use crate::core::group::hex;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::protocols::der::DerError;
+use crate::protocols::ecdsa::{Ecdsa, Signature, SignatureError, SigningKey};
+use crate::protocols::keys::SecretKey;
+use crate::protocols::tests::unhex;
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use sha2::Sha256;
+
+type Sig = Signature<Secp256k1Curve>;
+
+// private key 1 signing "Satoshi Nakamoto" with RFC 6979, as used by
+// Bitcoin libraries; the raw s is high
+const R: &str = "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8";
+const LOW_S: &str = "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5";
+const DER: &str = "3045022100934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+                   02202442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5";
+
+fn satoshi() -> (SigningKey<Secp256k1Curve>, Sig) {
+    let key = SigningKey::new(SecretKey::from_scalar(FrSecp256k1::from(1u64)).unwrap());
+    let signature = Ecdsa::sign::<Sha256>(&key, b"Satoshi Nakamoto").unwrap();
+    (key, signature)
+}
+
+#[test]
+fn test_low_s_normalization() {
+    let (key, signature) = satoshi();
+    assert!(!signature.is_low_s());
+
+    let normalized = signature.normalize_s();
+    assert!(normalized.is_low_s());
+    assert_eq!(normalized.normalize_s(), normalized);
+    assert_eq!(normalized.r(), signature.r());
+    assert_eq!(*normalized.s(), -*signature.s());
+    assert_eq!(hex(&normalized.to_bytes()[32..]), LOW_S);
+
+    let public_key = key.public_key();
+    let message = b"Satoshi Nakamoto";
+    assert!(Ecdsa::verify::<Sha256>(public_key, message, &signature));
+    assert!(Ecdsa::verify::<Sha256>(public_key, message, &normalized));
+    assert!(!Ecdsa::verify_strict::<Sha256>(public_key, message, &signature));
+    assert!(Ecdsa::verify_strict::<Sha256>(public_key, message, &normalized));
+}
+
+#[test]
+fn test_encodings_round_trip() {
+    let (_, signature) = satoshi();
+    let normalized = signature.normalize_s();
+
+    assert_eq!(hex(&normalized.to_der()), DER);
+    assert_eq!(Sig::from_der(&unhex(DER)), Ok(normalized));
+    let bytes = normalized.to_bytes();
+    assert_eq!(hex(&bytes), format!("{R}{LOW_S}"));
+    assert_eq!(Sig::from_bytes(&bytes), Ok(normalized));
+
+    // short integers keep their length in DER and are padded in r || s
+    let small = Sig::from_scalars(FrSecp256k1::from(1u64), FrSecp256k1::from(0x80u64)).unwrap();
+    assert_eq!(hex(&small.to_der()), "300702010102020080");
+    assert_eq!(Sig::from_der(&small.to_der()), Ok(small));
+    assert_eq!(Sig::from_bytes(&small.to_bytes()), Ok(small));
+
+    // P-256 signatures are 64 bytes too
+    let key = SigningKey::<P256Curve>::random();
+    let signature = Ecdsa::sign::<Sha256>(&key, b"sample").unwrap();
+    assert_eq!(signature.to_bytes().len(), 64);
+    assert_eq!(Signature::from_der(&signature.to_der()), Ok(signature));
+}
+
+#[test]
+fn test_der_rejects_non_canonical() {
+    let der = unhex(DER);
+    let reject = |bytes: &[u8]| Sig::from_der(bytes).unwrap_err();
+
+    // BER long-form length for a short sequence
+    let mut long_form = vec![0x30, 0x81];
+    long_form.extend_from_slice(&der[1..]);
+    assert_eq!(reject(&long_form), SignatureError::Der(DerError::NonMinimalLength));
+
+    // a superfluous zero byte in s
+    let mut padded = der[..37].to_vec();
+    padded.extend_from_slice(&[0x02, 0x21, 0x00]);
+    padded.extend_from_slice(&der[39..]);
+    padded[1] += 1;
+    assert_eq!(reject(&padded), SignatureError::Der(DerError::NonMinimalInteger));
+
+    // r without its sign byte reads as negative
+    let mut negative = vec![0x30, 0x44, 0x02, 0x20];
+    negative.extend_from_slice(&der[5..]);
+    assert_eq!(reject(&negative), SignatureError::Der(DerError::Negative));
+
+    let mut trailing = der.clone();
+    trailing.push(0x00);
+    assert_eq!(reject(&trailing), SignatureError::Der(DerError::Trailing));
+    assert_eq!(reject(&der[..der.len() - 1]), SignatureError::Der(DerError::Truncated));
+    assert_eq!(reject(&[0x31, 0x00]), SignatureError::Der(DerError::Tag(0x31)));
+    assert_eq!(reject(&unhex("3006020100020101")), SignatureError::Zero);
+
+    let order: BigUint = FrSecp256k1::MODULUS.into();
+    let mut too_large = vec![0x30, 0x26, 0x02, 0x21, 0x00];
+    too_large.extend(order.to_bytes_be());
+    too_large.extend_from_slice(&[0x02, 0x01, 0x01]);
+    assert_eq!(reject(&too_large), SignatureError::OutOfRange);
+}
+
+#[test]
+fn test_fixed_width_rejects_invalid() {
+    let (_, signature) = satoshi();
+    let bytes = signature.to_bytes();
+    assert_eq!(
+        Sig::from_bytes(&bytes[1..]),
+        Err(SignatureError::Length { expected: 64, got: 63 })
+    );
+    let mut overflow = bytes.clone();
+    overflow[32..].copy_from_slice(&BigUint::from(FrSecp256k1::MODULUS).to_bytes_be());
+    assert_eq!(Sig::from_bytes(&overflow), Err(SignatureError::OutOfRange));
+    assert_eq!(Sig::from_bytes(&[0u8; 64]), Err(SignatureError::Zero));
+}
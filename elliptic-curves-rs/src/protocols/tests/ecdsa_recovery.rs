@@ -0,0 +1,82 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::curves::toy::toy::{FrToy28, Toy28Curve};
+use crate::protocols::ecdsa::{Ecdsa, RecoveryError, RecoveryId, SigningKey};
+use crate::protocols::keys::SecretKey;
+
+use ark_ff::PrimeField;
+use ark_std::{UniformRand, rand::thread_rng};
+use num_bigint::BigUint;
+use sha2::{Sha256, Sha512};
+
+fn round_trip<C: Curve>() {
+    for round in 0..16u32 {
+        let key = SigningKey::<C>::random();
+        let message = format!("message {round}");
+        let (signature, recovery_id) = Ecdsa::sign_recoverable::<Sha256>(&key, message.as_bytes()).unwrap();
+        assert!(signature.is_low_s());
+        assert!(Ecdsa::verify_strict::<Sha256>(key.public_key(), message.as_bytes(), &signature));
+
+        let z = Ecdsa::<C>::hash_message::<Sha256>(message.as_bytes());
+        assert_eq!(Ecdsa::recover_public_key(z, &signature, recovery_id).as_ref(), Ok(key.public_key()));
+
+        // the other parity gives -R and a different key
+        let flipped = RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+        let other = Ecdsa::recover_public_key(z, &signature, flipped).unwrap();
+        assert_ne!(&other, key.public_key());
+        assert!(Ecdsa::verifying_message(other.as_point(), z, &signature));
+    }
+}
+
+#[test]
+fn test_recovery_round_trip() {
+    round_trip::<Secp256k1Curve>();
+    round_trip::<P256Curve>();
+
+    // the prehash API agrees with the message API
+    let key = SigningKey::<Secp256k1Curve>::random();
+    let (signature, recovery_id) = Ecdsa::sign_recoverable::<Sha512>(&key, b"hello").unwrap();
+    let prehash = <Sha512 as sha2::Digest>::digest(b"hello");
+    assert_eq!(Ecdsa::sign_prehash_recoverable::<Sha512>(&key, &prehash).unwrap(), (signature, recovery_id));
+    assert_eq!(signature, Ecdsa::sign::<Sha512>(&key, b"hello").unwrap().normalize_s());
+}
+
+#[test]
+fn test_recovery_with_reduced_x() {
+    // p - n = 10944 on this curve: walk kG until x >= n
+    let order: BigUint = FrToy28::MODULUS.into();
+    let g = Toy28Curve::generator();
+    let mut point = g.clone();
+    let mut k = FrToy28::from(1u64);
+    while BigUint::from(point.inner.x().unwrap().into_bigint()) < order {
+        point = point.add(&g);
+        k += FrToy28::from(1u64);
+    }
+
+    let secret_key = SecretKey::<Toy28Curve>::random();
+    let public_key = secret_key.public_key();
+    let z = FrToy28::rand(&mut thread_rng());
+    let (signature, recovery_id) =
+        Ecdsa::signing_message_recoverable_with_nonce(secret_key.expose_scalar(), z, &k).unwrap();
+    assert!(recovery_id.is_x_reduced());
+    assert_eq!(Ecdsa::recover_public_key(z, &signature, recovery_id), Ok(public_key));
+
+    // ignoring the reduction lifts a different x
+    let unreduced = RecoveryId::new(recovery_id.is_y_odd(), false);
+    assert_ne!(Ecdsa::recover_public_key(z, &signature, unreduced).ok(), Some(secret_key.public_key()));
+}
+
+#[test]
+fn test_recovery_rejects_invalid_ids() {
+    assert_eq!(RecoveryId::from_byte(4), None);
+    assert_eq!(RecoveryId::from_byte(3), Some(RecoveryId::new(true, true)));
+
+    // on secp256k1 r + n exceeds p for almost every r
+    let key = SigningKey::<Secp256k1Curve>::random();
+    let (signature, recovery_id) = Ecdsa::sign_recoverable::<Sha256>(&key, b"hello").unwrap();
+    assert!(!recovery_id.is_x_reduced());
+    let z = Ecdsa::<Secp256k1Curve>::hash_message::<Sha256>(b"hello");
+    let reduced = RecoveryId::new(recovery_id.is_y_odd(), true);
+    assert_eq!(Ecdsa::recover_public_key(z, &signature, reduced), Err(RecoveryError::XOutOfRange));
+}
@@ -1,10 +1,14 @@
+use crate::curves::p256::p256::{FrP256, P256Curve};
 use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
 use crate::curves::secp256k1::secp256k1::FrSecp256k1;
+use crate::curves::toy::toy::{FrToy32, Toy32Curve};
 use crate::protocols::ecdh::*;
 use crate::protocols::ecdsa::*;
+use crate::protocols::keys::SecretKey;
 
 use ark_ff::PrimeField;
-use sha2::{Sha256, Digest};
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256, Sha512};
 
 #[test]
 fn test_ecdsa_secp256k1() {
@@ -23,15 +27,78 @@ fn test_ecdsa_secp256k1() {
     let wrong_z = FrSecp256k1::from_be_bytes_mod_order(&wrong_digest);
 
     let signature =
-        Ecdsa::<Secp256k1Curve>::signing_message(&alice_private_key, z);
+        Ecdsa::<Secp256k1Curve>::signing_message(alice_private_key.expose_scalar(), z);
     
     assert!(Ecdsa::<Secp256k1Curve>::verifying_message(
-        &alice_public_key, z, &signature
+        alice_public_key.as_point(), z, &signature
     ));
     assert!(!Ecdsa::<Secp256k1Curve>::verifying_message(
-        &alice_public_key, wrong_z, &signature
+        alice_public_key.as_point(), wrong_z, &signature
     ));
     assert!(!Ecdsa::<Secp256k1Curve>::verifying_message(
-        &bob_public_key, z, &signature
+        bob_public_key.as_point(), z, &signature
     ));
 }
+
+#[test]
+fn test_ecdsa_sign_verify_message() {
+    let alice = SigningKey::<Secp256k1Curve>::random();
+    let bob = SigningKey::<Secp256k1Curve>::random();
+
+    // Alice's and Bob's keys should be different
+    assert_ne!(alice.public_key(), bob.public_key());
+    assert_ne!(alice.secret_key(), bob.secret_key());
+
+    let signature = Ecdsa::sign::<Sha256>(&alice, b"hello world").unwrap();
+
+    assert!(Ecdsa::verify::<Sha256>(alice.public_key(), b"hello world", &signature));
+    assert!(!Ecdsa::verify::<Sha256>(alice.public_key(), b"hello there", &signature));
+    assert!(!Ecdsa::verify::<Sha256>(bob.public_key(), b"hello world", &signature));
+    // the verifier has to use the signer's hash
+    assert!(!Ecdsa::verify::<Sha512>(alice.public_key(), b"hello world", &signature));
+
+    // a 256-bit hash fits the order: z is the digest reduced mod n
+    let z = Ecdsa::<Secp256k1Curve>::hash_message::<Sha256>(b"hello world");
+    assert!(Ecdsa::verifying_message(alice.public_key().as_point(), z, &signature));
+}
+
+#[test]
+fn test_ecdsa_prehash() {
+    let key = SigningKey::<Secp256k1Curve>::random();
+    let prehash = Sha256::digest(b"hello world");
+
+    let signature = Ecdsa::sign_prehash::<Sha256>(&key, &prehash).unwrap();
+    assert_eq!(signature, Ecdsa::sign::<Sha256>(&key, b"hello world").unwrap());
+    assert!(Ecdsa::verify_prehash(key.public_key(), &prehash, &signature));
+    assert!(Ecdsa::verify::<Sha256>(key.public_key(), b"hello world", &signature));
+    assert!(!Ecdsa::verify_prehash(key.public_key(), &prehash[1..], &signature));
+}
+
+#[test]
+fn test_ecdsa_hash_truncation() {
+    // SHA-512 over P-256: the leftmost 256 bits, as in RFC 6979 A.2.5
+    let x = FrP256::from(BigUint::parse_bytes(
+        b"C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        16,
+    ).unwrap());
+    let key = SigningKey::<P256Curve>::new(SecretKey::from_scalar(x).unwrap());
+    let signature = Ecdsa::sign::<Sha512>(&key, b"sample").unwrap();
+    let r = BigUint::parse_bytes(
+        b"8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00",
+        16,
+    ).unwrap();
+    assert_eq!(*signature.r(), FrP256::from(r));
+    assert!(Ecdsa::verify::<Sha512>(key.public_key(), b"sample", &signature));
+
+    // a 32-bit order keeps only the top 32 bits of the digest, not the
+    // digest mod n
+    let digest = Sha256::digest(b"sample");
+    let z = Ecdsa::<Toy32Curve>::hash_message::<Sha256>(b"sample");
+    assert_eq!(z, FrToy32::from(BigUint::from_bytes_be(&digest[..4])));
+    assert_ne!(z, FrToy32::from_be_bytes_mod_order(&digest));
+
+    let key = SigningKey::<Toy32Curve>::random();
+    let signature = Ecdsa::sign::<Sha256>(&key, b"sample").unwrap();
+    assert!(Ecdsa::verify::<Sha256>(key.public_key(), b"sample", &signature));
+    assert!(!Ecdsa::verify::<Sha256>(key.public_key(), b"example", &signature));
+}
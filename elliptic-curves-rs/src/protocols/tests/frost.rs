@@ -0,0 +1,389 @@
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::protocols::frost::dkg::{self, Round1Package, Round2Package};
+use crate::protocols::frost::keys::{SecretShare, generate_with_dealer, trusted_dealer_keygen};
+use crate::protocols::frost::{
+    Ciphersuite, FirstRound, FrostError, Identifier, KeyPackage, PublicKeyPackage, Signature, SignatureShare,
+    SigningCommitments, SigningNonces, SigningPackage, aggregate, deserialize_scalar, evaluate_polynomial,
+    lagrange_coefficient, serialize_element, serialize_scalar, sign, verify_signature_share,
+};
+use crate::protocols::keys::SecretKey;
+use crate::protocols::tests::{id, unhex};
+
+use std::collections::BTreeMap;
+
+/// 딜러의 조각을 바이트로 받아 참가자마다 검증한다
+fn dealer_key_packages<C: Ciphersuite>(
+    max: u16,
+    min: u16,
+) -> (BTreeMap<Identifier, KeyPackage<C>>, PublicKeyPackage<C>) {
+    let (shares, public_key_package) = generate_with_dealer::<C>(max, min).unwrap();
+    let key_packages = shares
+        .iter()
+        .map(|(&id, share)| (id, SecretShare::<C>::from_bytes(&share.to_bytes()).unwrap().verify().unwrap()))
+        .collect();
+    (key_packages, public_key_package)
+}
+
+/// 참가자마다 상태를 들고, 메시지는 모두 바이트로 주고받는다
+fn run_dkg<C: Ciphersuite>(max: u16, min: u16) -> Vec<(KeyPackage<C>, PublicKeyPackage<C>)> {
+    let first: Vec<dkg::FirstRound<C>> = (1..=max).map(|i| dkg::FirstRound::new(id(i), max, min).unwrap()).collect();
+    let broadcast: Vec<Vec<u8>> = first.iter().map(|participant| participant.package().to_bytes()).collect();
+
+    let mut second = Vec::new();
+    let mut outbox: BTreeMap<(Identifier, Identifier), Vec<u8>> = BTreeMap::new();
+    for participant in first {
+        let me = participant.identifier();
+        let received: BTreeMap<Identifier, Round1Package<C>> = (1..=max)
+            .filter(|&j| id(j) != me)
+            .map(|j| (id(j), Round1Package::from_bytes(&broadcast[j as usize - 1]).unwrap()))
+            .collect();
+        let (next, packages) = participant.receive(&received).unwrap();
+        for (to, package) in packages {
+            outbox.insert((me, to), package.to_bytes());
+        }
+        second.push(next);
+    }
+
+    second
+        .into_iter()
+        .map(|participant| {
+            let me = participant.identifier();
+            let received: BTreeMap<Identifier, Round2Package<C>> = outbox
+                .iter()
+                .filter(|((_, to), _)| *to == me)
+                .map(|(&(from, _), bytes)| (from, Round2Package::from_bytes(bytes).unwrap()))
+                .collect();
+            participant.finish(&received).unwrap()
+        })
+        .collect()
+}
+
+/// `signers`가 두 라운드로 서명하고 코디네이터가 모은다
+fn sign_with<C: Ciphersuite>(
+    key_packages: &BTreeMap<Identifier, KeyPackage<C>>,
+    public_key_package: &PublicKeyPackage<C>,
+    signers: &[u16],
+    message: &[u8],
+) -> Result<Signature<C>, FrostError> {
+    let rounds: Vec<(Identifier, FirstRound<C>)> =
+        signers.iter().map(|&i| (id(i), FirstRound::new(&key_packages[&id(i)]))).collect();
+    let commitments = rounds
+        .iter()
+        .map(|(id, round)| (*id, SigningCommitments::<C>::from_bytes(&round.commitments().to_bytes()).unwrap()))
+        .collect();
+    let package_bytes = SigningPackage::new(commitments, message).to_bytes();
+
+    let mut shares = BTreeMap::new();
+    for (id, round) in rounds {
+        let package = SigningPackage::from_bytes(&package_bytes).unwrap();
+        let share = round.sign(&package, &key_packages[&id])?;
+        shares.insert(id, SignatureShare::from_bytes(&share.to_bytes()).unwrap());
+    }
+    aggregate(&SigningPackage::from_bytes(&package_bytes).unwrap(), &shares, public_key_package)
+}
+
+#[test]
+fn test_dealer_shares_interpolate_to_the_secret() {
+    let secret_key = SecretKey::<Secp256k1Curve>::random();
+    let (shares, public_key_package) = trusted_dealer_keygen(&secret_key, 5, 3).unwrap();
+    assert_eq!(public_key_package.verifying_key(), secret_key.public_key().as_point());
+
+    let key_packages: BTreeMap<Identifier, KeyPackage<Secp256k1Curve>> =
+        shares.values().map(|share| (share.identifier(), share.verify().unwrap())).collect();
+    for (id, key_package) in &key_packages {
+        assert_eq!(key_package.verifying_share(), &public_key_package.verifying_shares()[id]);
+        assert_eq!(key_package.min_signers(), 3);
+    }
+
+    for signers in [[1, 2, 3], [2, 4, 5], [1, 3, 5]] {
+        let signers: Vec<Identifier> = signers.into_iter().map(id).collect();
+        let secret = signers
+            .iter()
+            .map(|&i| lagrange_coefficient::<Secp256k1Curve>(&signers, i) * key_packages[&i].signing_share())
+            .sum();
+        assert_eq!(SecretKey::from_scalar(secret).unwrap(), secret_key);
+    }
+}
+
+#[test]
+fn test_dealer_threshold_signing() {
+    let (key_packages, public_key_package) = dealer_key_packages::<Secp256k1Curve>(5, 3);
+    let verifying_key = public_key_package.verifying_key();
+
+    for signers in [&[1, 2, 3][..], &[5, 2, 4][..], &[1, 2, 3, 4, 5][..]] {
+        let signature = sign_with(&key_packages, &public_key_package, signers, b"threshold").unwrap();
+        assert!(signature.verify(verifying_key, b"threshold"));
+        assert!(!signature.verify(verifying_key, b"other"));
+        assert_eq!(Signature::from_bytes(&signature.to_bytes()).unwrap(), signature);
+    }
+
+    // fewer than t signers cannot sign
+    assert_eq!(
+        sign_with(&key_packages, &public_key_package, &[1, 4], b"threshold"),
+        Err(FrostError::TooFewSigners { min: 3, got: 2 })
+    );
+}
+
+#[test]
+fn test_dkg_threshold_signing() {
+    fn check<C: Ciphersuite>() {
+        let outputs = run_dkg::<C>(3, 2);
+        let public_key_package = outputs[0].1.clone();
+        assert!(outputs.iter().all(|(_, package)| *package == public_key_package));
+
+        // the shares lie on one polynomial whose constant term is the group secret
+        let signers = [id(1), id(3)];
+        let secret: C::ScalarField = signers
+            .iter()
+            .map(|&i| lagrange_coefficient::<C>(&signers, i) * outputs[i.value() as usize - 1].0.signing_share())
+            .sum();
+        assert_eq!(&C::generator().mul_scalar(&secret), public_key_package.verifying_key());
+
+        let key_packages: BTreeMap<Identifier, KeyPackage<C>> =
+            outputs.into_iter().map(|(key_package, _)| (key_package.identifier(), key_package)).collect();
+        let signature = sign_with(&key_packages, &public_key_package, &[3, 2], b"dkg").unwrap();
+        assert!(signature.verify(public_key_package.verifying_key(), b"dkg"));
+    }
+    check::<Secp256k1Curve>();
+    check::<P256Curve>();
+}
+
+#[test]
+fn test_cheating_dealer_and_signer_are_identified() {
+    let (shares, public_key_package) = generate_with_dealer::<Secp256k1Curve>(3, 2).unwrap();
+
+    // a share that is off the committed polynomial
+    let mut bytes = shares[&id(2)].to_bytes();
+    bytes[40] ^= 1;
+    let share = SecretShare::<Secp256k1Curve>::from_bytes(&bytes).unwrap();
+    assert_eq!(share.verify().unwrap_err(), FrostError::InvalidSecretShare(id(2)));
+
+    let key_packages: BTreeMap<Identifier, KeyPackage<Secp256k1Curve>> =
+        shares.iter().map(|(&id, share)| (id, share.verify().unwrap())).collect();
+    let rounds: Vec<(Identifier, FirstRound<Secp256k1Curve>)> =
+        [1, 3].into_iter().map(|i| (id(i), FirstRound::new(&key_packages[&id(i)]))).collect();
+    let package =
+        SigningPackage::new(rounds.iter().map(|(id, round)| (*id, round.commitments().clone())).collect(), b"message");
+    let mut signature_shares: BTreeMap<Identifier, SignatureShare<Secp256k1Curve>> =
+        rounds.into_iter().map(|(id, round)| (id, round.sign(&package, &key_packages[&id]).unwrap())).collect();
+    for (&id, share) in &signature_shares {
+        assert_eq!(verify_signature_share(id, share, &package, &public_key_package), Ok(()));
+    }
+
+    // signer 3 sends signer 1's share
+    let forged = signature_shares[&id(1)].clone();
+    signature_shares.insert(id(3), forged);
+    assert_eq!(
+        aggregate(&package, &signature_shares, &public_key_package),
+        Err(FrostError::InvalidSignatureShare(id(3)))
+    );
+
+    signature_shares.remove(&id(3));
+    assert_eq!(aggregate(&package, &signature_shares, &public_key_package), Err(FrostError::MissingParticipant(id(3))));
+}
+
+#[test]
+fn test_signer_rejects_foreign_commitments() {
+    let (key_packages, _) = dealer_key_packages::<Secp256k1Curve>(3, 2);
+    let round = FirstRound::new(&key_packages[&id(1)]);
+    let other = FirstRound::new(&key_packages[&id(2)]);
+
+    // the coordinator swapped signer 1's commitments
+    let commitments = BTreeMap::from([(id(1), other.commitments().clone()), (id(2), other.commitments().clone())]);
+    let package = SigningPackage::new(commitments, b"message");
+    assert_eq!(round.sign(&package, &key_packages[&id(1)]), Err(FrostError::IncorrectCommitments));
+}
+
+#[test]
+fn test_dkg_rejects_misbehaving_participants() {
+    let first: Vec<dkg::FirstRound<Secp256k1Curve>> =
+        (1..=3).map(|i| dkg::FirstRound::new(id(i), 3, 2).unwrap()).collect();
+    let received_by = |me: u16| -> BTreeMap<Identifier, Round1Package<Secp256k1Curve>> {
+        first
+            .iter()
+            .filter(|participant| participant.identifier() != id(me))
+            .map(|participant| (participant.identifier(), participant.package().clone()))
+            .collect()
+    };
+
+    // participant 3 replays participant 2's proof for its own commitment
+    let mut forged = received_by(1);
+    let proof = forged[&id(2)].to_bytes();
+    let bytes = [forged[&id(3)].commitment().to_bytes(), proof[proof.len() - 33 - 32..].to_vec()].concat();
+    forged.insert(id(3), Round1Package::from_bytes(&bytes).unwrap());
+
+    let mut missing = received_by(3);
+    missing.remove(&id(2));
+    let received = received_by(2);
+
+    let [first_1, first_2, first_3]: [dkg::FirstRound<Secp256k1Curve>; 3] = first.try_into().unwrap();
+    assert_eq!(first_1.receive(&forged).unwrap_err(), FrostError::InvalidProofOfKnowledge(id(3)));
+    assert_eq!(first_3.receive(&missing).unwrap_err(), FrostError::MissingParticipant(id(2)));
+
+    // shares that do not match the senders' commitments
+    let (second_2, _) = first_2.receive(&received).unwrap();
+    let wrong = BTreeMap::from([
+        (id(1), Round2Package::from_bytes(&[1; 32]).unwrap()),
+        (id(3), Round2Package::from_bytes(&[1; 32]).unwrap()),
+    ]);
+    assert_eq!(second_2.finish(&wrong).unwrap_err(), FrostError::InvalidSecretShare(id(1)));
+}
+
+#[test]
+fn test_parameters_and_encodings() {
+    assert_eq!(
+        generate_with_dealer::<Secp256k1Curve>(3, 1).unwrap_err(),
+        FrostError::InvalidThreshold { min: 1, max: 3 }
+    );
+    assert_eq!(
+        generate_with_dealer::<Secp256k1Curve>(2, 3).unwrap_err(),
+        FrostError::InvalidThreshold { min: 3, max: 2 }
+    );
+    assert_eq!(dkg::FirstRound::<Secp256k1Curve>::new(id(4), 3, 2).unwrap_err(), FrostError::UnknownParticipant(id(4)));
+
+    assert_eq!(Identifier::new(0), None);
+    assert_eq!(Identifier::from_bytes::<Secp256k1Curve>(&id(513).to_bytes::<Secp256k1Curve>()), Ok(id(513)));
+    assert_eq!(Identifier::from_bytes::<Secp256k1Curve>(&[0; 32]), Err(FrostError::InvalidIdentifier));
+    let mut large = [0; 32];
+    large[29] = 1;
+    assert_eq!(Identifier::from_bytes::<Secp256k1Curve>(&large), Err(FrostError::InvalidIdentifier));
+
+    assert_eq!(SignatureShare::<P256Curve>::from_bytes(&[0xFF; 32]), Err(FrostError::InvalidScalar));
+    assert_eq!(Signature::<P256Curve>::from_bytes(&[0; 64]), Err(FrostError::Length { expected: 65, got: 64 }));
+    assert_eq!(SigningCommitments::<P256Curve>::from_bytes(&[0; 66]), Err(FrostError::InvalidElement));
+
+    // commitments must be listed in identifier order
+    let (key_packages, _) = dealer_key_packages::<Secp256k1Curve>(3, 2);
+    let commitments: BTreeMap<Identifier, SigningCommitments<Secp256k1Curve>> = key_packages
+        .iter()
+        .map(|(&id, key_package)| (id, FirstRound::new(key_package).commitments().clone()))
+        .collect();
+    let bytes = SigningPackage::new(commitments, b"m").to_bytes();
+    let entry = 32 + 66;
+    let swapped =
+        [&bytes[..2], &bytes[2 + entry..2 + 2 * entry], &bytes[2..2 + entry], &bytes[2 + 2 * entry..]].concat();
+    assert_eq!(SigningPackage::<Secp256k1Curve>::from_bytes(&swapped).unwrap_err(), FrostError::Malformed);
+    assert_eq!(
+        SigningPackage::<Secp256k1Curve>::from_bytes(&bytes[..bytes.len() - 2]).unwrap_err(),
+        FrostError::Malformed
+    );
+}
+
+/// RFC 9591 부록 E의 참가자 한 명
+struct VectorSigner {
+    identifier: u16,
+    signing_share: &'static str,
+    hiding_nonce_randomness: &'static str,
+    binding_nonce_randomness: &'static str,
+    binding_factor: &'static str,
+    sig_share: &'static str,
+}
+
+/// 2-of-3, 딜러의 다항식 f(x) = s + a_1 x, 서명자 1과 3, 메시지 "test"
+struct Vector {
+    group_secret_key: &'static str,
+    group_public_key: &'static str,
+    share_polynomial_coefficient: &'static str,
+    signers: [VectorSigner; 2],
+    signature: &'static str,
+}
+
+// RFC 9591 E.5, FROST(secp256k1, SHA-256)
+const SECP256K1_VECTOR: Vector = Vector {
+    group_secret_key: "0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114",
+    group_public_key: "02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f",
+    share_polynomial_coefficient: "fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579",
+    signers: [
+        VectorSigner {
+            identifier: 1,
+            signing_share: "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+            hiding_nonce_randomness: "7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2",
+            binding_nonce_randomness: "47acab018f116020c10cb9b9abdc7ac10aae1b48ca6e36dc15acb6ec9be5cdc5",
+            binding_factor: "3e08fe561e075c653cbfd46908a10e7637c70c74f0a77d5fd45d1a750c739ec6",
+            sig_share: "c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197",
+        },
+        VectorSigner {
+            identifier: 3,
+            signing_share: "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+            hiding_nonce_randomness: "e6cc56ccbd0502b3f6f831d91e2ebd01c4de0479e0191b66895a4ffd9b68d544",
+            binding_nonce_randomness: "7203d55eb82a5ca0d7d83674541ab55f6e76f1b85391d2c13706a89a064fd5b9",
+            binding_factor: "93f79041bb3fd266105be251adaeb5fd7f8b104fb554a4ba9a0becea48ddbfd7",
+            sig_share: "0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d",
+        },
+    ],
+    signature: "0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0\
+                c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324",
+};
+
+// RFC 9591 E.4, FROST(P-256, SHA-256)
+const P256_VECTOR: Vector = Vector {
+    group_secret_key: "8ba9bba2e0fd8c4767154d35a0b7562244a4aaf6f36c8fb8735fa48b301bd8de",
+    group_public_key: "023a309ad94e9fe8a7ba45dfc58f38bf091959d3c99cfbd02b4dc00585ec45ab70",
+    share_polynomial_coefficient: "80f25e6c0709353e46bfbe882a11bdbb1f8097e46340eb8673b7e14556e6c3a4",
+    signers: [
+        VectorSigner {
+            identifier: 1,
+            signing_share: "0c9c1a0fe806c184add50bbdcac913dda73e482daf95dcb9f35dbb0d8a9f7731",
+            hiding_nonce_randomness: "ec4c891c85fee802a9d757a67d1252e7f4e5efb8a538991ac18fbd0e06fb6fd3",
+            binding_nonce_randomness: "9334e29d09061223f69a09421715a347e4e6deba77444c8f42b0c833f80f4ef9",
+            binding_factor: "7925f0d4693f204e6e59233e92227c7124664a99739d2c06b81cf64ddf90559e",
+            sig_share: "400308eaed7a2ddee02a265abe6a1cfe04d946ee8720768899619cfabe7a3aeb",
+        },
+        VectorSigner {
+            identifier: 3,
+            signing_share: "0e80d6e8f6192c003b5488ce1eec8f5429587d48cf001541e713b2d53c09d928",
+            hiding_nonce_randomness: "c0451c5a0a5480d6c1f860e5db7d655233dca2669fd90ff048454b8ce983367b",
+            binding_nonce_randomness: "2ba5f7793ae700e40e78937a82f407dd35e847e33d1e607b5c7eb6ed2a8ed799",
+            binding_factor: "e10d24a8a403723bcb6f9bb4c537f316593683b472f7a89f166630dde11822c4",
+            sig_share: "561da3c179edbb0502d941bb3e3ace3c37d122aaa46fb54499f15f3a3331de44",
+        },
+    ],
+    signature: "026d8d434874f87bdb7bc0dfd239b2c00639044f9dcb195e9a04426f70bfa4b70d\
+                9620acac6767e8e3e3036815fca4eb3a3caa69992b902bcd3352fc34f1ac192f",
+};
+
+fn check_vector<C: Ciphersuite>(vector: &Vector) {
+    let scalar = |hex: &str| deserialize_scalar::<C>(&unhex(hex)).unwrap();
+    let randomness = |hex: &str| -> [u8; 32] { unhex(hex).try_into().unwrap() };
+
+    let group_secret_key = scalar(vector.group_secret_key);
+    let verifying_key = C::generator().mul_scalar(&group_secret_key);
+    assert_eq!(serialize_element(&verifying_key).unwrap(), unhex(vector.group_public_key));
+
+    let coefficients = [group_secret_key, scalar(vector.share_polynomial_coefficient)];
+    let mut key_packages = BTreeMap::new();
+    let mut verifying_shares = BTreeMap::new();
+    let mut nonces = BTreeMap::new();
+    for signer in &vector.signers {
+        let id = id(signer.identifier);
+        let signing_share = evaluate_polynomial::<C>(&coefficients, id);
+        assert_eq!(signing_share, scalar(signer.signing_share));
+        let randomness = (randomness(signer.hiding_nonce_randomness), randomness(signer.binding_nonce_randomness));
+        nonces.insert(id, SigningNonces::<C>::from_random_bytes(&signing_share, &randomness.0, &randomness.1));
+        verifying_shares.insert(id, C::generator().mul_scalar(&signing_share));
+        key_packages.insert(id, KeyPackage::new(id, signing_share, verifying_key.clone(), 2));
+    }
+    let public_key_package = PublicKeyPackage::new(verifying_shares, verifying_key.clone());
+
+    let commitments = nonces.iter().map(|(&id, nonces)| (id, nonces.commitments().clone())).collect();
+    let package = SigningPackage::new(commitments, b"test");
+    let binding_factors = package.binding_factors(&verifying_key).unwrap();
+
+    let mut shares = BTreeMap::new();
+    for (signer, (id, nonces)) in vector.signers.iter().zip(nonces) {
+        assert_eq!(serialize_scalar::<C>(&binding_factors[&id]), unhex(signer.binding_factor));
+        let share = sign(&package, nonces, &key_packages[&id]).unwrap();
+        assert_eq!(share.to_bytes(), unhex(signer.sig_share));
+        shares.insert(id, share);
+    }
+    let signature = aggregate(&package, &shares, &public_key_package).unwrap();
+    assert_eq!(signature.to_bytes(), unhex(vector.signature));
+    assert!(signature.verify(&verifying_key, b"test"));
+}
+
+#[test]
+fn test_rfc9591_vectors() {
+    check_vector::<Secp256k1Curve>(&SECP256K1_VECTOR);
+    check_vector::<P256Curve>(&P256_VECTOR);
+}
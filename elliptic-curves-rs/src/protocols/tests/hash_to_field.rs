@@ -0,0 +1,60 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::curves::toy::toy::Toy16Curve;
+use crate::protocols::hash_to_field::{expand_message_xmd, hash_to_curve, hash_to_field};
+use crate::protocols::tests::unhex;
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+// RFC 9380 K.1, expand_message_xmd(SHA-256)
+const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+#[test]
+fn test_expand_message_xmd_vectors() {
+    assert_eq!(
+        expand_message_xmd(&[b""], DST, 0x20),
+        unhex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+    );
+    assert_eq!(
+        expand_message_xmd(&[b"abc"], DST, 0x20),
+        unhex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+    );
+}
+
+#[test]
+fn test_expand_message_xmd_lengths() {
+    // the message parts are concatenated; the length is hashed into b_0, so
+    // a longer output does not start with a shorter one
+    let long = expand_message_xmd(&[b"a", b"bc"], DST, 0x80);
+    assert_eq!(long.len(), 0x80);
+    assert_eq!(long, expand_message_xmd(&[b"abc"], DST, 0x80));
+    assert_ne!(long[..0x20].to_vec(), expand_message_xmd(&[b"abc"], DST, 0x20));
+}
+
+#[test]
+fn test_hash_to_field_reduces_48_bytes() {
+    let uniform = expand_message_xmd(&[b"abc"], DST, 48);
+    let expected = FrSecp256k1::from_be_bytes_mod_order(&uniform);
+    assert_eq!(hash_to_field::<FrSecp256k1>(&[b"abc"], DST), expected);
+}
+
+#[test]
+fn test_hash_to_curve_lands_in_the_group() {
+    fn check<C: Curve>() {
+        let point = hash_to_curve::<C>(&[b"abc"], DST);
+        assert!(C::is_on_curve(&point.inner));
+        assert!(!point.inner.is_infinity());
+        let order: BigUint = C::order().into();
+        assert!(point.mul_biguint(&order).inner.is_infinity());
+        // even y, deterministic, and separated by message and DST
+        assert_eq!(point.to_compressed().unwrap()[0], 0x02);
+        assert_eq!(hash_to_curve::<C>(&[b"a", b"bc"], DST), point);
+        assert_ne!(hash_to_curve::<C>(&[b"abd"], DST), point);
+        assert_ne!(hash_to_curve::<C>(&[b"abc"], b"other"), point);
+    }
+    check::<Secp256k1Curve>();
+    check::<P256Curve>();
+    check::<Toy16Curve>();
+}
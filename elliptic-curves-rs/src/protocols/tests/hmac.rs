@@ -0,0 +1,30 @@
+use crate::core::group::hex;
+use crate::protocols::hmac::{Hmac, hmac};
+
+use sha2::{Sha256, Sha512};
+
+// RFC 4231 test cases 1, 2 and 6
+#[test]
+fn test_hmac_rfc4231() {
+    let mac = hmac::<Sha256>(&[0x0b; 20], &[b"Hi There"]);
+    assert_eq!(hex(&mac), "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7");
+
+    let mac = hmac::<Sha256>(b"Jefe", &[b"what do ya want for nothing?"]);
+    assert_eq!(hex(&mac), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+    let mac = hmac::<Sha512>(b"Jefe", &[b"what do ya want for nothing?"]);
+    assert_eq!(
+        hex(&mac),
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+         9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+    );
+
+    // a key longer than the block is hashed first
+    let message = b"Test Using Larger Than Block-Size Key - Hash Key First";
+    let mac = hmac::<Sha256>(&[0xaa; 131], &[message]);
+    assert_eq!(hex(&mac), "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+
+    // incremental updates give the same tag
+    let mut incremental = Hmac::<Sha256>::new(&[0xaa; 131]);
+    incremental.update(&message[..10]).update(&message[10..]);
+    assert_eq!(incremental.finalize(), mac);
+}
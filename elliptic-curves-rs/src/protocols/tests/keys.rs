@@ -0,0 +1,55 @@
+use crate::core::curve::Curve;
+use crate::core::point::CurvePoint;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::protocols::ecdh::{Ecdh, EcdhError};
+use crate::protocols::keys::{KeyError, PublicKey, SecretKey};
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+type Secret = SecretKey<Secp256k1Curve>;
+
+#[test]
+fn test_secret_key_bytes() {
+    let secret_key = Secret::random();
+    let bytes = secret_key.to_bytes();
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(Secret::from_bytes(&bytes), Ok(secret_key.clone()));
+    assert_ne!(Secret::from_bytes(&bytes), Ok(Secret::random()));
+
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let one = Secret::from_bytes(&one).unwrap();
+    assert_eq!(one.expose_scalar(), &FrSecp256k1::from(1u64));
+    assert_eq!(one.public_key().as_point(), &Secp256k1Curve::generator());
+
+    assert_eq!(Secret::from_bytes(&[0u8; 32]), Err(KeyError::Zero));
+    assert_eq!(Secret::from_bytes(&[1u8; 31]), Err(KeyError::Length { expected: 32, got: 31 }));
+    let order: BigUint = FrSecp256k1::MODULUS.into();
+    assert_eq!(Secret::from_bytes(&order.to_bytes_be()), Err(KeyError::OutOfRange));
+    assert!(Secret::from_scalar(FrSecp256k1::from(0u64)).is_none());
+}
+
+#[test]
+fn test_secrets_are_redacted() {
+    let (secret_key, public_key) = Ecdh::<Secp256k1Curve>::generate_keypair();
+    let (other_secret, other_public) = Ecdh::<Secp256k1Curve>::generate_keypair();
+    let shared = Ecdh::diffie_hellman(&secret_key, &other_public).unwrap();
+    assert_eq!(shared, Ecdh::diffie_hellman(&other_secret, &public_key).unwrap());
+
+    assert_eq!(format!("{secret_key:?}"), "SecretKey(<redacted>)");
+    assert_eq!(format!("{shared:?}"), "SharedSecret(<redacted>)");
+    let scalar = secret_key.expose_scalar().into_bigint().to_string();
+    assert!(!format!("{secret_key:?}").contains(&scalar));
+    assert!(format!("{public_key:?}").starts_with("PublicKey"));
+}
+
+#[test]
+fn test_public_key_is_validated() {
+    let public_key = Secret::random().public_key();
+    assert_eq!(PublicKey::from_point(public_key.to_point()), Ok(public_key));
+    assert_eq!(
+        PublicKey::<Secp256k1Curve>::from_point(CurvePoint::infinity()),
+        Err(KeyError::PublicKey(EcdhError::Infinity))
+    );
+}
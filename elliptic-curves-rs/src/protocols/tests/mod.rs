@@ -1,2 +1,25 @@
+pub mod batch;
+pub mod dkg;
+pub mod dual_ec_drbg;
 pub mod ecdh_secp256k1;
+pub mod ecdsa_encoding;
+pub mod ecdsa_recovery;
 pub mod ecdsa_secp256k1;
+pub mod frost;
+pub mod hash_to_field;
+pub mod hmac;
+pub mod keys;
+pub mod musig2;
+pub mod rfc6979;
+pub mod schnorr;
+pub mod secret_sharing;
+
+use crate::protocols::frost::Identifier;
+
+fn unhex(hex: &str) -> Vec<u8> {
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+}
+
+fn id(value: u16) -> Identifier {
+    Identifier::new(value).unwrap()
+}
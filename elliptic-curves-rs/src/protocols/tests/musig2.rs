@@ -0,0 +1,333 @@
+use crate::curves::secp256k1::secp256k1::{PointSecp256k1, Secp256k1Curve};
+use crate::protocols::keys::{PublicKey, SecretKey};
+use crate::protocols::musig2::{
+    AggNonce, FirstRound, KeyAggContext, MuSigError, PartialSignature, PubNonce, SecNonce, Session, key_sort,
+    nonce_gen_with_rand,
+};
+use crate::protocols::schnorr::Schnorr;
+use crate::protocols::tests::unhex;
+
+// https://github.com/bitcoin/bips/tree/master/bip-0327/vectors
+
+fn pick(list: &[&str], indices: &[usize]) -> Vec<Vec<u8>> {
+    indices.iter().map(|&i| unhex(list[i])).collect()
+}
+
+fn key_agg(public_keys: &[Vec<u8>]) -> Result<KeyAggContext, MuSigError> {
+    let slices: Vec<&[u8]> = public_keys.iter().map(|key| key.as_slice()).collect();
+    KeyAggContext::from_bytes(&slices)
+}
+
+// key_agg_vectors.json
+const KEY_AGG_PUBKEYS: [&str; 7] = [
+    "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+    "03DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+    "023590A94E768F8E1815C2F24B4D80A8E3149316C3518CE7B7AD338368D038CA66",
+    "020000000000000000000000000000000000000000000000000000000000000005",
+    "02FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+    "04F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+    "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+];
+
+#[test]
+fn test_key_agg_vectors() {
+    for (indices, expected) in [
+        (&[0, 1, 2][..], "90539EEDE565F5D054F32CC0C220126889ED1E5D193BAF15AEF344FE59D4610C"),
+        (&[2, 1, 0][..], "6204DE8B083426DC6EAF9502D27024D53FC826BF7D2012148A0575435DF54B2B"),
+        (&[0, 0, 0][..], "B436E3BAD62B8CD409969A224731C193D051162D8C5AE8B109306127DA3AA935"),
+        (&[0, 0, 1, 1][..], "69BC22BFA5D106306E48A20679DE1D7389386124D07571D0D872686028C26A3E"),
+    ] {
+        let context = key_agg(&pick(&KEY_AGG_PUBKEYS, indices)).unwrap();
+        assert_eq!(context.x_only_public_key().to_bytes().to_vec(), unhex(expected), "keys {indices:?}");
+    }
+
+    // not on the curve, x not below p, and an uncompressed prefix
+    for (indices, blamed) in [(&[0, 3][..], 1), (&[0, 4][..], 1), (&[5, 0][..], 0)] {
+        assert_eq!(key_agg(&pick(&KEY_AGG_PUBKEYS, indices)).unwrap_err(), MuSigError::InvalidPublicKey(blamed));
+    }
+
+    let context = key_agg(&pick(&KEY_AGG_PUBKEYS, &[0, 1])).unwrap();
+    let out_of_range = unhex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+    assert_eq!(context.with_tweak(&out_of_range.try_into().unwrap(), true).unwrap_err(), MuSigError::TweakOutOfRange);
+}
+
+#[test]
+fn test_key_sort() {
+    let mut public_keys: Vec<PublicKey<Secp256k1Curve>> = [0, 1, 2, 6]
+        .iter()
+        .map(|&i| PublicKey::from_point(PointSecp256k1::from_compressed(&unhex(KEY_AGG_PUBKEYS[i])).unwrap()).unwrap())
+        .collect();
+    key_sort(&mut public_keys);
+    let sorted: Vec<Vec<u8>> = public_keys.iter().map(|key| key.as_point().to_compressed().unwrap()).collect();
+    assert_eq!(sorted, pick(&KEY_AGG_PUBKEYS, &[2, 0, 6, 1]));
+}
+
+/// sk, pk, aggpk, msg, extra_in, 기대하는 secnonce(공개키 제외)와 pubnonce
+type NonceGenCase<'a> =
+    (Option<&'a [u8]>, &'a [u8], Option<&'a [u8]>, Option<&'a [u8]>, Option<&'a [u8]>, &'a str, &'a str);
+
+// nonce_gen_vectors.json
+#[test]
+fn test_nonce_gen_vectors() {
+    let sk = unhex("0202020202020202020202020202020202020202020202020202020202020202");
+    let pk = unhex("024D4B6CD1361032CA9BD2AEB9D900AA4D45D9EAD80AC9423374C451A7254D0766");
+    let aggpk = unhex("0707070707070707070707070707070707070707070707070707070707070707");
+    let extra_in = unhex("0808080808080808080808080808080808080808080808080808080808080808");
+    let long_message = unhex("2626262626262626262626262626262626262626262626262626262626262626262626262626");
+
+    let cases: [NonceGenCase; 4] = [
+        (
+            Some(&sk),
+            &pk,
+            Some(&aggpk),
+            Some(&unhex("0101010101010101010101010101010101010101010101010101010101010101")),
+            Some(&extra_in),
+            "B114E502BEAA4E301DD08A50264172C84E41650E6CB726B410C0694D59EFFB6495B5CAF28D045B973D63E3C99A44B807BDE375FD6CB39E46DC4A511708D0E9D2",
+            "02F7BE7089E8376EB355272368766B17E88E7DB72047D05E56AA881EA52B3B35DF02C29C8046FDD0DED4C7E55869137200FBDBFE2EB654267B6D7013602CAED3115A",
+        ),
+        (
+            Some(&sk),
+            &pk,
+            Some(&aggpk),
+            Some(&[]),
+            Some(&extra_in),
+            "E862B068500320088138468D47E0E6F147E01B6024244AE45EAC40ACE5929B9F0789E051170B9E705D0B9EB49049A323BBBBB206D8E05C19F46C6228742AA7A9",
+            "023034FA5E2679F01EE66E12225882A7A48CC66719B1B9D3B6C4DBD743EFEDA2C503F3FD6F01EB3A8E9CB315D73F1F3D287CAFBB44AB321153C6287F407600205109",
+        ),
+        (
+            Some(&sk),
+            &pk,
+            Some(&aggpk),
+            Some(&long_message),
+            Some(&extra_in),
+            "3221975ACBDEA6820EABF02A02B7F27D3A8EF68EE42787B88CBEFD9AA06AF3632EE85B1A61D8EF31126D4663A00DD96E9D1D4959E72D70FE5EBB6E7696EBA66F",
+            "02E5BBC21C69270F59BD634FCBFA281BE9D76601295345112C58954625BF23793A021307511C79F95D38ACACFF1B4DA98228B77E65AA216AD075E9673286EFB4EAF3",
+        ),
+        (
+            None,
+            &unhex("02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9"),
+            None,
+            None,
+            None,
+            "89BDD787D0284E5E4D5FC572E49E316BAB7E21E3B1830DE37DFE80156FA41A6D0B17AE8D024C53679699A6FD7944D9C4A366B514BAF43088E0708B1023DD2897",
+            "02C96E7CB1E8AA5DAC64D872947914198F607D90ECDE5200DE52978AD5DED63C000299EC5117C2D29EDEE8A2092587C3909BE694D5CFF0667D6C02EA4059F7CD9786",
+        ),
+    ];
+    for (i, (sk, pk, aggpk, msg, extra_in, secnonce, pubnonce)) in cases.into_iter().enumerate() {
+        let (sec, public) = nonce_gen_with_rand(&[0x0F; 32], sk, pk, aggpk, msg, extra_in);
+        assert_eq!(sec.to_bytes().to_vec(), [unhex(secnonce), pk.to_vec()].concat(), "case {i}");
+        assert_eq!(public.to_bytes().to_vec(), unhex(pubnonce), "case {i}");
+    }
+}
+
+// nonce_agg_vectors.json
+const NONCE_AGG_PNONCES: [&str; 7] = [
+    "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E66603BA47FBC1834437B3212E89A84D8425E7BF12E0245D98262268EBDCB385D50641",
+    "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833",
+    "020151C80F435648DF67A22B749CD798CE54E0321D034B92B709B567D60A42E6660279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60379BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    "04FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B833",
+    "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A60248C264CDD57D3C24D79990B0F865674EB62A0F9018277A95011B41BFC193B831",
+    "03FF406FFD8ADB9CD29877E4985014F66A59F6CD01C0E88CAA8E5F3166B1F676A602FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+];
+
+#[test]
+fn test_nonce_agg_vectors() {
+    for (indices, expected) in [
+        (
+            [0, 1],
+            "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B024725377345BDE0E9C33AF3C43C0A29A9249F2F2956FA8CFEB55C8573D0262DC8",
+        ),
+        // the second points cancel and are encoded as 33 zero bytes
+        (
+            [2, 3],
+            "035FE1873B4F2967F52FEA4A06AD5A8ECCBE9D0FD73068012C894E2E87CCB5804B000000000000000000000000000000000000000000000000000000000000000000",
+        ),
+    ] {
+        let pubnonces: Vec<PubNonce> =
+            indices.iter().map(|&i| PubNonce::from_bytes(&unhex(NONCE_AGG_PNONCES[i])).unwrap()).collect();
+        let aggnonce = AggNonce::aggregate(&pubnonces);
+        assert_eq!(aggnonce.to_bytes().to_vec(), unhex(expected));
+        assert_eq!(AggNonce::from_bytes(&unhex(expected)), Ok(aggnonce));
+    }
+
+    // a bad prefix, an x off the curve and an x not below p
+    for (i, pnonce) in NONCE_AGG_PNONCES.iter().enumerate().skip(4) {
+        assert_eq!(PubNonce::from_bytes(&unhex(pnonce)), Err(MuSigError::InvalidPubNonce), "pnonce {i}");
+    }
+}
+
+// sign_verify_vectors.json
+const SK: &str = "7FB9E0E687ADA1EEBF7ECFE2F21E73EBDB51A7D450948DFE8D76D7F2D1007671";
+const SIGN_PUBKEYS: [&str; 3] = [
+    "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+    "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+    "02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA661",
+];
+const SECNONCE: &str = "508B81A611F100A6B2B6B29656590898AF488BCF2E1F55CF22E5CFB84421FE61\
+                        FA27FD49B1D50085B481285E1CA205D55C82CC1B31FF5CD54A489829355901F7\
+                        03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9";
+const SIGN_PNONCES: [&str; 4] = [
+    "0337C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0287BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+    "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F817980279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    "032DE2662628C90B03F5E720284EB52FF7D71F4284F627B68A853D78C78E1FFE9303E4C5524E83FFE1493B9077CF1CA6BEB2090C93D930321071AD40B2F44E599046",
+    "0237C87821AFD50A8644D820A8F3E02E499C931865C2360FB43D0A0D20DAFE07EA0387BF891D2A6DEAEBADC909352AA9405D1428C15F4B75F04DAE642A95C2548480",
+];
+const SIGN_AGGNONCES: [&str; 2] = [
+    "028465FCF0BBDBCF443AABCCE533D42B4B5A10966AC09A49655E8C42DAAB8FCD61037496A3CC86926D452CAFCFD55D25972CA1675D549310DE296BFF42F72EEEA8C9",
+    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+];
+const SIGN_MSGS: [&str; 3] = [
+    "F95466D086770E689964664219266FE5ED215C92AE20BAB5C9D79ADDDDF3C0CF",
+    "",
+    "2626262626262626262626262626262626262626262626262626262626262626262626262626",
+];
+
+fn secret_key() -> SecretKey<Secp256k1Curve> {
+    SecretKey::from_bytes(&unhex(SK)).unwrap()
+}
+
+/// 서명 벡터 한 건: 공개키, 공개 nonce, 집계 nonce, 메시지의 인덱스와 서명자 위치
+struct SignCase<'a> {
+    key_indices: &'a [usize],
+    nonce_indices: &'a [usize],
+    aggnonce_index: usize,
+    msg_index: usize,
+    signer: usize,
+}
+
+/// 벡터의 한 서명자(`signer`)로 세션을 만들고 부분 서명을 확인한다
+fn check_partial_signature(public_keys: &[&str], case: SignCase, tweaks: &[(&str, bool)], expected: &str) {
+    let SignCase { key_indices, nonce_indices, aggnonce_index, msg_index, signer } = case;
+    let mut context = key_agg(&pick(public_keys, key_indices)).unwrap();
+    for (tweak, is_xonly) in tweaks {
+        context = context.with_tweak(&unhex(tweak).try_into().unwrap(), *is_xonly).unwrap();
+    }
+    let pubnonces: Vec<PubNonce> =
+        nonce_indices.iter().map(|&i| PubNonce::from_bytes(&unhex(SIGN_PNONCES[i])).unwrap()).collect();
+    let aggnonce = AggNonce::from_bytes(&unhex(SIGN_AGGNONCES[aggnonce_index])).unwrap();
+    assert_eq!(AggNonce::aggregate(&pubnonces), aggnonce);
+
+    let session = Session::new(&context, &aggnonce, &unhex(SIGN_MSGS[msg_index]));
+    let secnonce = SecNonce::from_bytes(&unhex(SECNONCE)).unwrap();
+    let partial_signature = session.sign(secnonce, &secret_key()).unwrap();
+    assert_eq!(partial_signature.to_bytes().to_vec(), unhex(expected));
+    assert!(session.verify_partial(&partial_signature, &pubnonces[signer], &secret_key().public_key()));
+}
+
+#[test]
+fn test_sign_verify_vectors() {
+    // the vectors' secret nonce belongs to the first public nonce and key
+    let secnonce = SecNonce::from_bytes(&unhex(SECNONCE)).unwrap();
+    assert_eq!(secret_key().public_key().as_point().to_compressed().unwrap(), unhex(SIGN_PUBKEYS[0]));
+    drop(secnonce);
+
+    for (keys, nonces, aggnonce, msg, signer, expected) in [
+        (&[0, 1, 2][..], &[0, 1, 2][..], 0, 0, 0, "012ABBCB52B3016AC03AD82395A1A415C48B93DEF78718E62A7A90052FE224FB"),
+        (&[1, 0, 2][..], &[1, 0, 2][..], 0, 0, 1, "9FF2F7AAA856150CC8819254218D3ADEEB0535269051897724F9DB3789513A52"),
+        (&[1, 2, 0][..], &[1, 2, 0][..], 0, 0, 2, "FA23C359F6FAC4E7796BB93BC9F0532A95468C539BA20FF86D7C76ED92227900"),
+        // both aggregate nonce points are infinite, so R = G
+        (&[0, 1][..], &[0, 3][..], 1, 0, 0, "AE386064B26105404798F75DE2EB9AF5EDA5387B064B83D049CB7C5E08879531"),
+        // an empty message and a 38-byte one
+        (&[0, 1, 2][..], &[0, 1, 2][..], 0, 1, 0, "D7D63FFD644CCDA4E62BC2BC0B1D02DD32A1DC3030E155195810231D1037D82D"),
+        (&[0, 1, 2][..], &[0, 1, 2][..], 0, 2, 0, "E184351828DA5094A97C79CABDAAA0BFB87608C32E8829A4DF5340A6F243B78C"),
+    ] {
+        let case =
+            SignCase { key_indices: keys, nonce_indices: nonces, aggnonce_index: aggnonce, msg_index: msg, signer };
+        check_partial_signature(&SIGN_PUBKEYS, case, &[], expected);
+    }
+}
+
+// tweak_vectors.json: signer 2 is the vector's secret key
+#[test]
+fn test_tweak_vectors() {
+    const PUBKEYS: [&str; 3] = [
+        "03935F972DA013F80AE011890FA89B67A27B7BE6CCB24D3274D18B2D4067F261A9",
+        "02F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "02DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+    ];
+    const TWEAKS: [&str; 4] = [
+        "E8F791FF9225A2AF0102AFFF4A9A723D9612A682A25EBE79802B263CDFCD83BB",
+        "AE2EA797CC0FE72AC5B97B97F3C6957D7E4199A167A58EB08BCAFFDA70AC0455",
+        "F52ECBC565B3D8BEA2DFD5B75A4F457E54369809322E4120831626F290FA87E0",
+        "1969AD73CC177FA0B4FCED6DF1F7BF9907E665FDE9BA196A74FED0A3CF5AEF9D",
+    ];
+    let cases: [(&[(usize, bool)], &str); 5] = [
+        (&[(0, true)], "E28A5C66E61E178C2BA19DB77B6CF9F7E2F0F56C17918CD13135E60CC848FE91"),
+        (&[(0, false)], "38B0767798252F21BF5702C48028B095428320F73A4B14DB1E25DE58543D2D2D"),
+        (&[(0, false), (1, true)], "408A0A21C4A0F5DACAF9646AD6EB6FECD7F7A11F03ED1F48DFFF2185BC2C2408"),
+        (
+            &[(0, false), (1, false), (2, true), (3, true)],
+            "45ABD206E61E3DF2EC9E264A6FEC8292141A633C28586388235541F9ADE75435",
+        ),
+        (
+            &[(0, true), (1, false), (2, true), (3, false)],
+            "B255FDCAC27B40C7CE7848E2D3B7BF5EA0ED756DA81565AC804CCCA3E1D5D239",
+        ),
+    ];
+    for (tweaks, expected) in cases {
+        let tweaks: Vec<(&str, bool)> = tweaks.iter().map(|&(i, is_xonly)| (TWEAKS[i], is_xonly)).collect();
+        let case =
+            SignCase { key_indices: &[1, 2, 0], nonce_indices: &[1, 2, 0], aggnonce_index: 0, msg_index: 0, signer: 2 };
+        check_partial_signature(&PUBKEYS, case, &tweaks, expected);
+    }
+}
+
+#[test]
+fn test_sign_rejects_mismatched_inputs() {
+    let context = key_agg(&pick(&SIGN_PUBKEYS, &[0, 1, 2])).unwrap();
+    let aggnonce = AggNonce::from_bytes(&unhex(SIGN_AGGNONCES[0])).unwrap();
+    let session = Session::new(&context, &aggnonce, &unhex(SIGN_MSGS[0]));
+
+    // the secret key is not among the signers
+    let outsider = Session::new(&key_agg(&pick(&SIGN_PUBKEYS, &[1, 2])).unwrap(), &aggnonce, &[]);
+    let secnonce = SecNonce::from_bytes(&unhex(SECNONCE)).unwrap();
+    assert_eq!(outsider.sign(secnonce, &secret_key()), Err(MuSigError::NotASigner));
+
+    // a secret nonce generated for another key
+    let other = SecretKey::<Secp256k1Curve>::random();
+    let secnonce = SecNonce::from_bytes(&unhex(SECNONCE)).unwrap();
+    assert_eq!(session.sign(secnonce, &other), Err(MuSigError::WrongSecNonce));
+
+    // zero k_1
+    let zeroed = [vec![0; 32], unhex(SECNONCE)[32..].to_vec()].concat();
+    assert_eq!(SecNonce::from_bytes(&zeroed).unwrap_err(), MuSigError::InvalidSecNonce);
+
+    // partial signatures: out of range, wrong signer, negated
+    let order = unhex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+    assert_eq!(PartialSignature::from_bytes(&order), Err(MuSigError::InvalidPartialSignature));
+    let secnonce = SecNonce::from_bytes(&unhex(SECNONCE)).unwrap();
+    let partial_signature = session.sign(secnonce, &secret_key()).unwrap();
+    let pubnonce = PubNonce::from_bytes(&unhex(SIGN_PNONCES[0])).unwrap();
+    let signer_1 = PublicKey::from_point(PointSecp256k1::from_compressed(&unhex(SIGN_PUBKEYS[1])).unwrap()).unwrap();
+    assert!(!session.verify_partial(&partial_signature, &pubnonce, &signer_1));
+    let negated =
+        PartialSignature::from_bytes(&unhex("FED54434AD4CFE953FC527DC6A5E5BE8F6234907B7C187559557CE87A0541C46"))
+            .unwrap();
+    assert!(!session.verify_partial(&negated, &pubnonce, &secret_key().public_key()));
+}
+
+#[test]
+fn test_full_signing_session() {
+    let secret_keys: Vec<SecretKey<Secp256k1Curve>> = (0..3).map(|_| SecretKey::random()).collect();
+    let mut public_keys: Vec<PublicKey<Secp256k1Curve>> = secret_keys.iter().map(|key| key.public_key()).collect();
+    key_sort(&mut public_keys);
+    let context = KeyAggContext::new(&public_keys).unwrap().with_tweak(&[7; 32], true).unwrap();
+    let message = b"spend the taproot output";
+
+    let rounds: Vec<FirstRound> = secret_keys.iter().map(|key| FirstRound::new(key, &context, Some(message))).collect();
+    let pubnonces: Vec<PubNonce> = rounds.iter().map(|round| round.pubnonce().clone()).collect();
+    let session = Session::new(&context, &AggNonce::aggregate(&pubnonces), message);
+
+    // `sign` consumes the round, so its nonce cannot be used again
+    let partial_signatures: Vec<PartialSignature> =
+        rounds.into_iter().zip(&secret_keys).map(|(round, key)| round.sign(&session, key).unwrap()).collect();
+    for ((partial_signature, pubnonce), key) in partial_signatures.iter().zip(&pubnonces).zip(&secret_keys) {
+        assert!(session.verify_partial(partial_signature, pubnonce, &key.public_key()));
+    }
+
+    let signature = session.aggregate(&partial_signatures);
+    assert!(Schnorr::verify(&context.x_only_public_key(), message, &signature));
+    assert!(!Schnorr::verify(&context.x_only_public_key(), b"other", &signature));
+    assert!(!Schnorr::verify(&context.x_only_public_key(), message, &session.aggregate(&partial_signatures[1..])));
+}
@@ -0,0 +1,134 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::{FqP256, FrP256, P256Curve, PointP256};
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::protocols::ecdsa::{Ecdsa, NonceMode};
+use crate::protocols::rfc6979::{Nonces, bits2int, hash_to_scalar};
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use sha2::digest::core_api::BlockSizeUser;
+
+fn scalar<F: PrimeField>(hex: &str) -> F {
+    F::from(BigUint::parse_bytes(hex.as_bytes(), 16).unwrap())
+}
+
+// RFC 6979 A.2.5: the P-256 key and its published signatures
+fn check_p256<D: Digest + BlockSizeUser>(message: &[u8], k: &str, r: &str, s: &str) {
+    let x: FrP256 = scalar("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+    let z = hash_to_scalar::<P256Curve>(&D::digest(message));
+
+    let nonce = Nonces::<P256Curve, D>::new(&x, &z, &[]).next().unwrap();
+    assert_eq!(nonce, scalar(k));
+    let signature = Ecdsa::<P256Curve>::signing_message_rfc6979::<D>(&x, z, &[]);
+    assert_eq!(signature.to_scalars(), (scalar(r), scalar(s)));
+
+    let public_key = P256Curve::generator().mul_scalar(&x);
+    assert!(Ecdsa::<P256Curve>::verifying_message(&public_key, z, &signature));
+}
+
+#[test]
+fn test_rfc6979_p256_vectors() {
+    let x: FrP256 = scalar("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+    let public_key = P256Curve::generator().mul_scalar(&x);
+    let expected = PointP256::new(
+        scalar::<FqP256>("60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6"),
+        scalar("7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"),
+    );
+    assert_eq!(public_key, expected);
+
+    check_p256::<Sha256>(
+        b"sample",
+        "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+        "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+    );
+    check_p256::<Sha256>(
+        b"test",
+        "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+        "F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367",
+        "019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083",
+    );
+    check_p256::<Sha384>(
+        b"sample",
+        "09F634B188CEFD98E7EC88B1AA9852D734D0BC272F7D2A47DECC6EBEB375AAD4",
+        "0EAFEA039B20E9B42309FB1D89E213057CBF973DC0CFC8F129EDDDC800EF7719",
+        "4861F0491E6998B9455193E34E7B0D284DDD7149A74B95B9261F13ABDE940954",
+    );
+    check_p256::<Sha384>(
+        b"test",
+        "16AEFFA357260B04B1DD199693960740066C1A8F3E8EDD79070AA914D361B3B8",
+        "83910E8B48BB0C74244EBDF7F07A1C5413D61472BD941EF3920E623FBCCEBEB6",
+        "8DDBEC54CF8CD5874883841D712142A56A8D0F218F5003CB0296B6B509619F2C",
+    );
+    check_p256::<Sha512>(
+        b"sample",
+        "5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5",
+        "8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00",
+        "2362AB1ADBE2B8ADF9CB9EDAB740EA6049C028114F2460F96554F61FAE3302FE",
+    );
+    check_p256::<Sha512>(
+        b"test",
+        "6915D11632ACA3C40D5D51C08DAF9C555933819548784480E93499000D9F0B7F",
+        "461D93F31B6540894788FD206C07CFA0CC35F46FA3C91816FFF1040AD1581A04",
+        "39AF9F15DE0DB8D97E72719C74820D304CE5226E32DEDAE67519E840D1194E55",
+    );
+}
+
+#[test]
+fn test_rfc6979_secp256k1() {
+    // the common vector used by Bitcoin libraries, before low-S normalization
+    let x = FrSecp256k1::from(1u64);
+    let z = hash_to_scalar::<Secp256k1Curve>(&Sha256::digest(b"Satoshi Nakamoto"));
+    let signature =
+        Ecdsa::<Secp256k1Curve>::signing_message_with_mode(&x, z, NonceMode::Deterministic);
+    assert_eq!(
+        Nonces::<Secp256k1Curve, Sha256>::new(&x, &z, &[]).next(),
+        Some(scalar("8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15"))
+    );
+    assert_eq!(
+        signature.to_scalars(),
+        (
+            scalar("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"),
+            scalar("dbbd3162d46e9f9bef7feb87c16dc13b4f6568a87f4e83f728e2443ba586675c"),
+        )
+    );
+}
+
+#[test]
+fn test_nonce_modes() {
+    let x = FrSecp256k1::from(0xc0ffeeu64);
+    let public_key = Secp256k1Curve::generator().mul_scalar(&x);
+    let z = hash_to_scalar::<Secp256k1Curve>(&Sha256::digest(b"hello world"));
+    type Signer = Ecdsa<Secp256k1Curve>;
+
+    let deterministic = Signer::signing_message_with_mode(&x, z, NonceMode::Deterministic);
+    assert_eq!(deterministic, Signer::signing_message_with_mode(&x, z, NonceMode::Deterministic));
+
+    // extra entropy changes k but stays reproducible for the same k'
+    let with_entropy = |entropy: &[u8]| {
+        Signer::signing_message_with_mode(&x, z, NonceMode::DeterministicWithEntropy(entropy))
+    };
+    assert_ne!(with_entropy(b"one"), deterministic);
+    assert_ne!(with_entropy(b"one"), with_entropy(b"two"));
+    assert_eq!(with_entropy(b"one"), with_entropy(b"one"));
+    assert_eq!(with_entropy(b""), deterministic);
+
+    let random = Signer::signing_message_with_mode(&x, z, NonceMode::Random);
+    assert_ne!(random, deterministic);
+    for signature in [deterministic, with_entropy(b"one"), random] {
+        assert!(Signer::verifying_message(&public_key, z, &signature));
+    }
+
+    // successive candidates differ
+    let mut nonces = Nonces::<Secp256k1Curve, Sha256>::new(&x, &z, &[]);
+    assert_ne!(nonces.next(), nonces.next());
+}
+
+#[test]
+fn test_bits2int_truncates() {
+    // a 512-bit hash keeps its leftmost 256 bits
+    let digest = Sha512::digest(b"sample");
+    assert_eq!(bits2int::<P256Curve>(&digest), BigUint::from_bytes_be(&digest[..32]));
+    assert_eq!(bits2int::<P256Curve>(&[0x12, 0x34]), BigUint::from(0x1234u32));
+}
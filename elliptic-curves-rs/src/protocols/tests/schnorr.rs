@@ -0,0 +1,147 @@
+use crate::core::curve::Curve;
+use crate::curves::secp256k1::secp256k1::Secp256k1Curve;
+use crate::protocols::batch::BatchError;
+use crate::protocols::keys::SecretKey;
+use crate::protocols::schnorr::{BatchItem, Keypair, Schnorr, SchnorrError, Signature, XOnlyPublicKey, tagged_hash};
+use crate::protocols::tests::unhex;
+
+use sha2::{Digest, Sha256};
+
+// https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+const VECTORS: &str = include_str!("data/bip340_test_vectors.csv");
+
+struct Vector {
+    index: usize,
+    secret_key: Vec<u8>,
+    public_key: Vec<u8>,
+    aux_rand: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    result: bool,
+}
+
+fn vectors() -> Vec<Vector> {
+    VECTORS
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.trim().splitn(8, ',').collect();
+            Vector {
+                index: fields[0].parse().unwrap(),
+                secret_key: unhex(fields[1]),
+                public_key: unhex(fields[2]),
+                aux_rand: unhex(fields[3]),
+                message: unhex(fields[4]),
+                signature: unhex(fields[5]),
+                result: fields[6] == "TRUE",
+            }
+        })
+        .collect()
+}
+
+/// 파싱에 실패하는 공개키나 서명은 검증 실패로 본다
+fn verify_bytes(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    match (XOnlyPublicKey::from_bytes(public_key), Signature::from_bytes(signature)) {
+        (Ok(public_key), Ok(signature)) => Schnorr::verify(&public_key, message, &signature),
+        _ => false,
+    }
+}
+
+#[test]
+fn test_bip340_vectors() {
+    let vectors = vectors();
+    assert_eq!(vectors.len(), 19);
+
+    for vector in &vectors {
+        if !vector.secret_key.is_empty() {
+            let keypair = Keypair::new(SecretKey::from_bytes(&vector.secret_key).unwrap());
+            assert_eq!(keypair.public_key().to_bytes().to_vec(), vector.public_key, "vector {}", vector.index);
+            let aux_rand: [u8; 32] = vector.aux_rand.as_slice().try_into().unwrap();
+            let signature = Schnorr::sign(&keypair, &vector.message, &aux_rand);
+            assert_eq!(signature.to_bytes().to_vec(), vector.signature, "vector {}", vector.index);
+        }
+        assert_eq!(
+            verify_bytes(&vector.public_key, &vector.message, &vector.signature),
+            vector.result,
+            "vector {}",
+            vector.index
+        );
+    }
+}
+
+#[test]
+fn test_bip340_parsing() {
+    let vectors = vectors();
+    // vector 5: x is below p but x^3 + 7 is not a square
+    assert_eq!(XOnlyPublicKey::from_bytes(&vectors[5].public_key), Err(SchnorrError::NotOnCurve));
+    assert_eq!(XOnlyPublicKey::from_bytes(&vectors[14].public_key), Err(SchnorrError::XOutOfRange));
+    assert_eq!(Signature::from_bytes(&vectors[12].signature), Err(SchnorrError::XOutOfRange));
+    assert_eq!(Signature::from_bytes(&vectors[13].signature), Err(SchnorrError::SOutOfRange));
+    assert_eq!(Signature::from_bytes(&[0; 63]), Err(SchnorrError::Length { expected: 64, got: 63 }));
+
+    let signature = Signature::from_bytes(&vectors[1].signature).unwrap();
+    assert_eq!(signature.to_bytes().to_vec(), vectors[1].signature);
+}
+
+#[test]
+fn test_tagged_hash() {
+    let tag = Sha256::digest(b"BIP0340/challenge");
+    let expected = Sha256::new().chain_update(tag).chain_update(tag).chain_update(b"ab").finalize();
+    assert_eq!(tagged_hash("BIP0340/challenge", &[b"a", b"b"]), <[u8; 32]>::from(expected));
+}
+
+#[test]
+fn test_odd_y_key_round_trip() {
+    // keys whose dG has odd y are negated before signing
+    let mut seen_odd = false;
+    for round in 0..8u32 {
+        let keypair = Keypair::random();
+        let point = Secp256k1Curve::generator().mul_scalar(keypair.secret_key().expose_scalar());
+        let (x_only, odd) = XOnlyPublicKey::from_point(&point).unwrap();
+        assert_eq!(&x_only, keypair.public_key());
+        seen_odd |= odd;
+
+        let message = format!("message {round}");
+        let signature = Schnorr::sign_random(&keypair, message.as_bytes());
+        assert!(Schnorr::verify(keypair.public_key(), message.as_bytes(), &signature));
+        assert!(!Schnorr::verify(keypair.public_key(), b"other", &signature));
+    }
+    assert!(seen_odd);
+}
+
+#[test]
+fn test_batch_verification() {
+    let keypairs: Vec<Keypair> = (0..6).map(|_| Keypair::random()).collect();
+    let messages: Vec<Vec<u8>> = (0..6u8).map(|i| vec![i; 1 + i as usize]).collect();
+    let mut signatures: Vec<Signature> = keypairs
+        .iter()
+        .zip(&messages)
+        .map(|(keypair, message)| Schnorr::sign_random(keypair, message))
+        .collect();
+
+    let verify_all = |signatures: &[Signature]| {
+        let batch: Vec<BatchItem> = keypairs
+            .iter()
+            .zip(&messages)
+            .zip(signatures)
+            .map(|((keypair, message), signature)| (keypair.public_key(), message.as_slice(), signature))
+            .collect();
+        Schnorr::verify_batch(&batch)
+    };
+    assert_eq!(verify_all(&signatures), Ok(()));
+    assert_eq!(Schnorr::verify_batch(&[]), Ok(()));
+
+    // signatures over the wrong messages are singled out
+    signatures[2] = Schnorr::sign_random(&keypairs[2], b"forged");
+    signatures[5] = signatures[4];
+    assert_eq!(verify_all(&signatures), Err(BatchError { failed: vec![2, 5] }));
+
+    // the BIP vectors that pass and fail individually do the same in a batch
+    for vector in vectors().iter().filter(|vector| vector.index <= 4 || (6..=11).contains(&vector.index)) {
+        let public_key = XOnlyPublicKey::from_bytes(&vector.public_key).unwrap();
+        let signature = Signature::from_bytes(&vector.signature).unwrap();
+        let result = Schnorr::verify_batch(&[(&public_key, &vector.message, &signature)]);
+        assert_eq!(result.is_ok(), vector.result, "vector {}", vector.index);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::core::curve::Curve;
+use crate::curves::p256::p256::P256Curve;
+use crate::curves::secp256k1::secp256k1::{FrSecp256k1, Secp256k1Curve};
+use crate::curves::toy::toy::{FrToy28, Toy28Curve};
+use crate::protocols::secret_sharing::{
+    Feldman, FeldmanCommitment, Pedersen, PedersenCommitment, PedersenShare, Polynomial, SecretSharingError, Shamir,
+    Share, interpolate, lagrange_coefficient,
+};
+
+use ark_ff::{One, Zero};
+use ark_std::{UniformRand, rand::thread_rng};
+
+fn scalar(value: u64) -> FrSecp256k1 {
+    FrSecp256k1::from(value)
+}
+
+fn pick<C: Curve>(shares: &[Share<C>], indices: &[usize]) -> Vec<Share<C>> {
+    indices.iter().map(|&i| shares[i].clone()).collect()
+}
+
+#[test]
+fn test_shamir_any_threshold_subset_reconstructs() {
+    let secret = FrSecp256k1::rand(&mut thread_rng());
+    let shares = Shamir::<Secp256k1Curve>::split(&secret, 3, 5).unwrap();
+    assert_eq!(shares.iter().map(|share| *share.index()).collect::<Vec<_>>(), (1..=5).map(scalar).collect::<Vec<_>>());
+
+    for subset in [&[0, 1, 2][..], &[4, 2, 0][..], &[1, 3, 4][..], &[0, 1, 2, 3, 4][..]] {
+        assert_eq!(Shamir::reconstruct(&pick(&shares, subset)), Ok(secret));
+    }
+    // two shares fit a line that misses f(0)
+    assert_ne!(Shamir::reconstruct(&pick(&shares, &[0, 1])), Ok(secret));
+
+    // a share for a new index lies on the same polynomial
+    let fresh = Shamir::interpolate_at(&pick(&shares, &[0, 2, 4]), scalar(9)).unwrap();
+    let extended = [pick(&shares, &[1, 3]), vec![Share::new(scalar(9), fresh).unwrap()]].concat();
+    assert_eq!(Shamir::reconstruct(&extended), Ok(secret));
+}
+
+#[test]
+fn test_shamir_at_arbitrary_indices() {
+    let mut rng = thread_rng();
+    let secret = FrToy28::rand(&mut rng);
+    let indices: Vec<FrToy28> = [7u64, 1_000, 123_456, 42].into_iter().map(FrToy28::from).collect();
+    let shares = Shamir::<Toy28Curve>::split_at(&secret, 2, &indices).unwrap();
+    assert_eq!(Shamir::reconstruct(&pick(&shares, &[3, 1])), Ok(secret));
+
+    assert_eq!(
+        Shamir::<Toy28Curve>::split_at(&secret, 2, &[FrToy28::from(3u64), FrToy28::zero()]).unwrap_err(),
+        SecretSharingError::ZeroIndex
+    );
+    assert_eq!(
+        Shamir::<Toy28Curve>::split_at(&secret, 2, &[FrToy28::from(3u64), FrToy28::from(3u64)]).unwrap_err(),
+        SecretSharingError::DuplicateIndex
+    );
+    assert_eq!(
+        Shamir::<Toy28Curve>::split(&secret, 4, 3).unwrap_err(),
+        SecretSharingError::InvalidThreshold { threshold: 4, shares: 3 }
+    );
+    assert_eq!(Shamir::<Toy28Curve>::reconstruct(&[]), Err(SecretSharingError::NoShares));
+}
+
+#[test]
+fn test_lagrange_interpolation_at_arbitrary_points() {
+    let mut rng = thread_rng();
+    let polynomial = Polynomial::<Secp256k1Curve>::random(FrSecp256k1::rand(&mut rng), 3);
+    let points: Vec<(FrSecp256k1, FrSecp256k1)> =
+        [2u64, 5, 11, 17].into_iter().map(|x| (scalar(x), polynomial.evaluate(scalar(x)))).collect();
+
+    for x in [scalar(0), scalar(3), scalar(5), FrSecp256k1::rand(&mut rng)] {
+        assert_eq!(interpolate(&points, x), Ok(polynomial.evaluate(x)));
+    }
+
+    // the weights at any x sum to one, and pick out x_i itself
+    let indices: Vec<FrSecp256k1> = points.iter().map(|(x, _)| *x).collect();
+    let x = scalar(1_000);
+    let sum: FrSecp256k1 = indices.iter().map(|&x_i| lagrange_coefficient(&indices, x_i, x)).sum();
+    assert!(sum.is_one());
+    assert!(lagrange_coefficient(&indices, scalar(5), scalar(5)).is_one());
+    assert!(lagrange_coefficient(&indices, scalar(5), scalar(11)).is_zero());
+
+    let duplicated = [points[0], points[0]];
+    assert_eq!(interpolate(&duplicated, x), Err(SecretSharingError::DuplicateIndex));
+}
+
+#[test]
+fn test_feldman_detects_a_cheating_dealer() {
+    let secret = FrSecp256k1::rand(&mut thread_rng());
+    let (shares, commitment) = Feldman::<Secp256k1Curve>::split(&secret, 3, 5).unwrap();
+    assert_eq!(commitment.threshold(), 3);
+    assert_eq!(commitment.public_key(), &Secp256k1Curve::generator().mul_scalar(&secret));
+    assert!(shares.iter().all(|share| commitment.verify(share)));
+
+    // a share off the committed polynomial
+    let bad = Share::new(*shares[1].index(), *shares[1].value() + FrSecp256k1::one()).unwrap();
+    assert!(!commitment.verify(&bad));
+
+    // shares from a polynomial other than the committed one
+    let (other, _) = Feldman::<Secp256k1Curve>::split(&secret, 3, 5).unwrap();
+    assert!(other.iter().all(|share| !commitment.verify(share)));
+
+    // a commitment of lower degree than the shares
+    let truncated = FeldmanCommitment::from_points(commitment.points()[..2].to_vec()).unwrap();
+    assert!(!shares.iter().all(|share| truncated.verify(share)));
+
+    // a commitment without a constant term has no public key
+    assert_eq!(FeldmanCommitment::<Secp256k1Curve>::from_points(Vec::new()), Err(SecretSharingError::EmptyCommitment));
+    assert_eq!(PedersenCommitment::<Secp256k1Curve>::from_points(Vec::new()), Err(SecretSharingError::EmptyCommitment));
+}
+
+#[test]
+fn test_pedersen_detects_a_cheating_dealer() {
+    fn check<C: Curve>() {
+        let mut rng = thread_rng();
+        let secret = C::ScalarField::rand(&mut rng);
+        let (shares, commitment) = Pedersen::<C>::split(&secret, 2, 4).unwrap();
+        assert!(shares.iter().all(|share| commitment.verify(share)));
+
+        // the commitment hides the secret
+        assert_ne!(commitment.points()[0], C::generator().mul_scalar(&secret));
+        let plain: Vec<Share<C>> = shares[1..3].iter().map(|share| share.share().clone()).collect();
+        assert_eq!(Shamir::reconstruct(&plain), Ok(secret));
+
+        // changing either the value or the blinding breaks the share
+        let share = &shares[0];
+        let bad_value = Share::new(*share.share().index(), *share.share().value() + C::ScalarField::one()).unwrap();
+        assert!(!commitment.verify(&PedersenShare::new(bad_value, *share.blinding())));
+        let bad_blinding = *share.blinding() + C::ScalarField::one();
+        assert!(!commitment.verify(&PedersenShare::new(share.share().clone(), bad_blinding)));
+    }
+    check::<Secp256k1Curve>();
+    check::<Toy28Curve>();
+}
+
+#[test]
+fn test_pedersen_generator() {
+    let h = Pedersen::<Secp256k1Curve>::generator();
+    assert_eq!(h, Pedersen::<Secp256k1Curve>::generator());
+    assert_ne!(h, Secp256k1Curve::generator());
+    assert!(Secp256k1Curve::is_on_curve(&h.inner));
+
+    let h = Pedersen::<P256Curve>::generator();
+    assert!(P256Curve::is_on_curve(&h.inner));
+    assert_ne!(h, P256Curve::generator());
+}